@@ -1,4 +1,4 @@
-use crate::{Arguments, AsIter};
+use crate::{Argument, Arguments, AsIter, Value, ValueReference, Variable};
 
 pub trait Directive<const CONST: bool> {
     type Arguments: Arguments<CONST>;
@@ -22,3 +22,62 @@ impl<T: Directives<true>> ConstDirectives for T {}
 
 pub trait VariableDirectives: Directives<false> {}
 impl<T: Directives<false>> VariableDirectives for T {}
+
+/// The outcome of evaluating the `@skip` and `@include` directives of a selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionInclusion {
+    /// No `@skip` or `@include` directive excludes the selection
+    Included,
+    /// `@skip(if: true)` or `@include(if: false)` excludes the selection
+    Excluded,
+    /// No directive excludes the selection, but the condition of at least one could not be
+    /// evaluated
+    Unknown,
+}
+
+impl SelectionInclusion {
+    pub const SKIP_DIRECTIVE_NAME: &'static str = "skip";
+    pub const INCLUDE_DIRECTIVE_NAME: &'static str = "include";
+    const CONDITION_ARGUMENT_NAME: &'static str = "if";
+
+    /// Evaluates the `@skip` and `@include` directives among `directives`, calling
+    /// `evaluate_variable` with the name of any variable used as a condition
+    pub fn evaluate<D: VariableDirectives>(
+        directives: Option<&D>,
+        evaluate_variable: impl Fn(&str) -> Option<bool>,
+    ) -> Self {
+        let mut inclusion = Self::Included;
+        for directive in directives
+            .into_iter()
+            .flat_map(|directives| directives.iter())
+        {
+            let excluded_when = match directive.name() {
+                Self::SKIP_DIRECTIVE_NAME => true,
+                Self::INCLUDE_DIRECTIVE_NAME => false,
+                _ => continue,
+            };
+            let condition = directive
+                .arguments()
+                .and_then(|arguments| {
+                    arguments
+                        .iter()
+                        .find(|argument| argument.name() == Self::CONDITION_ARGUMENT_NAME)
+                })
+                .and_then(|argument| match argument.value().as_ref() {
+                    ValueReference::Boolean(value) => Some(value),
+                    ValueReference::Variable(variable) => evaluate_variable(variable.name()),
+                    _ => None,
+                });
+            match condition {
+                Some(condition) if condition == excluded_when => return Self::Excluded,
+                Some(_) => {}
+                None => inclusion = Self::Unknown,
+            }
+        }
+        inclusion
+    }
+
+    pub fn is_excluded(&self) -> bool {
+        matches!(self, Self::Excluded)
+    }
+}
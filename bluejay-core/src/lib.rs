@@ -14,7 +14,8 @@ pub use argument::{
 pub use as_iter::AsIter;
 pub use builtin_scalar_definition::BuiltinScalarDefinition;
 pub use directive::{
-    ConstDirective, ConstDirectives, Directive, Directives, VariableDirective, VariableDirectives,
+    ConstDirective, ConstDirectives, Directive, Directives, SelectionInclusion, VariableDirective,
+    VariableDirectives,
 };
 pub use indexable::{Indexable, Indexed};
 pub use operation_type::OperationType;
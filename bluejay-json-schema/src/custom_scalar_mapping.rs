@@ -0,0 +1,28 @@
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// Provides the JSON Schema used for values of a custom scalar.
+/// Custom scalars without a mapping accept any JSON value.
+pub trait CustomScalarMapping {
+    fn json_schema(&self, scalar_name: &str) -> Option<Value>;
+}
+
+impl CustomScalarMapping for () {
+    fn json_schema(&self, _: &str) -> Option<Value> {
+        None
+    }
+}
+
+impl<K: std::borrow::Borrow<str> + std::hash::Hash + Eq, S: std::hash::BuildHasher>
+    CustomScalarMapping for HashMap<K, Value, S>
+{
+    fn json_schema(&self, scalar_name: &str) -> Option<Value> {
+        self.get(scalar_name).cloned()
+    }
+}
+
+impl<K: std::borrow::Borrow<str> + Ord> CustomScalarMapping for BTreeMap<K, Value> {
+    fn json_schema(&self, scalar_name: &str) -> Option<Value> {
+        self.get(scalar_name).cloned()
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{value::to_json, CustomScalarMapping, DRAFT_2020_12};
+use bluejay_core::definition::{
+    BaseInputTypeReference, EnumTypeDefinition, EnumValueDefinition, HasDirectives,
+    InputObjectTypeDefinition, InputType, InputTypeReference, InputValueDefinition,
+    ScalarTypeDefinition, SchemaDefinition, TypeDefinitionReference,
+};
+use bluejay_core::executable::{
+    OperationDefinition, VariableDefinition, VariableType, VariableTypeReference,
+};
+use bluejay_core::{AsIter, BuiltinScalarDefinition, Directive};
+use serde_json::{json, Map, Value};
+
+/// Generates JSON Schema documents describing the JSON representation of GraphQL input
+/// values, as accepted in the `variables` of a request.
+///
+/// Enums and input objects are emitted once under `$defs` and referenced with `$ref`.
+/// Lists also accept a single item, mirroring the input coercion rules for lists.
+pub struct JsonSchemaGenerator<'a, S: SchemaDefinition, M: CustomScalarMapping> {
+    schema_definition: &'a S,
+    custom_scalar_mapping: &'a M,
+}
+
+impl<'a, S: SchemaDefinition, M: CustomScalarMapping> JsonSchemaGenerator<'a, S, M> {
+    const DEPRECATED_DIRECTIVE_NAME: &'static str = "deprecated";
+    const ONE_OF_DIRECTIVE_NAME: &'static str = "oneOf";
+
+    pub fn new(schema_definition: &'a S, custom_scalar_mapping: &'a M) -> Self {
+        Self {
+            schema_definition,
+            custom_scalar_mapping,
+        }
+    }
+
+    /// Returns a JSON Schema document for values of the input type named `name`,
+    /// or `None` if the schema has no input type with that name.
+    pub fn input_type_schema(&self, name: &str) -> Option<Value> {
+        let type_definition = self.schema_definition.get_type_definition(name)?;
+        let mut definitions = Map::new();
+        let schema = self.type_definition_schema(type_definition, &mut definitions)?;
+        Some(Self::document(schema, definitions))
+    }
+
+    /// Returns a JSON Schema document for the variables object of `operation_definition`.
+    /// Variables of unknown types accept any value.
+    pub fn variables_schema<O: OperationDefinition>(&self, operation_definition: &O) -> Value {
+        let mut definitions = Map::new();
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+
+        operation_definition
+            .as_ref()
+            .variable_definitions()
+            .into_iter()
+            .flat_map(|variable_definitions| variable_definitions.iter())
+            .for_each(|variable_definition| {
+                let name = variable_definition.variable();
+                let (schema, is_required) = self
+                    .variable_type_schema(variable_definition.r#type().as_ref(), &mut definitions);
+                let mut schema = Self::nullable_unless(schema, is_required);
+                if let Some(default_value) = variable_definition.default_value() {
+                    schema = Self::with_keyword(schema, "default", to_json(default_value));
+                } else if is_required {
+                    required.push(Value::from(name));
+                }
+                properties.insert(name.to_owned(), schema);
+            });
+
+        let mut schema = Map::new();
+        schema.insert("type".to_owned(), Value::from("object"));
+        schema.insert("properties".to_owned(), Value::Object(properties));
+        if !required.is_empty() {
+            schema.insert("required".to_owned(), Value::Array(required));
+        }
+        schema.insert("additionalProperties".to_owned(), Value::Bool(false));
+
+        Self::document(Value::Object(schema), definitions)
+    }
+
+    /// Returns the schemas of all enums and input objects defined in the schema, keyed by
+    /// type name, suitable for use as `$defs`.
+    pub fn definitions(&self) -> Map<String, Value> {
+        let mut definitions = Map::new();
+        self.schema_definition
+            .type_definitions()
+            .filter(|type_definition| !type_definition.is_builtin())
+            .for_each(|type_definition| match type_definition {
+                TypeDefinitionReference::Enum(etd) => {
+                    self.enum_type_schema(etd, &mut definitions);
+                }
+                TypeDefinitionReference::InputObject(iotd) => {
+                    self.input_object_type_schema(iotd, &mut definitions);
+                }
+                _ => {}
+            });
+        definitions
+    }
+
+    fn document(schema: Value, definitions: Map<String, Value>) -> Value {
+        let mut document = Self::into_object(schema);
+        document.insert("$schema".to_owned(), Value::from(DRAFT_2020_12));
+        if !definitions.is_empty() {
+            document.insert("$defs".to_owned(), Value::Object(definitions));
+        }
+        Value::Object(document)
+    }
+
+    fn type_definition_schema(
+        &self,
+        type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
+        definitions: &mut Map<String, Value>,
+    ) -> Option<Value> {
+        match type_definition {
+            TypeDefinitionReference::BuiltinScalar(bstd) => Some(Self::builtin_scalar_schema(bstd)),
+            TypeDefinitionReference::CustomScalar(cstd) => Some(self.custom_scalar_schema(cstd)),
+            TypeDefinitionReference::Enum(etd) => Some(self.enum_type_schema(etd, definitions)),
+            TypeDefinitionReference::InputObject(iotd) => {
+                Some(self.input_object_type_schema(iotd, definitions))
+            }
+            TypeDefinitionReference::Object(_)
+            | TypeDefinitionReference::Interface(_)
+            | TypeDefinitionReference::Union(_) => None,
+        }
+    }
+
+    fn base_input_type_schema(
+        &self,
+        base: BaseInputTypeReference<'a, S::InputType>,
+        definitions: &mut Map<String, Value>,
+    ) -> Value {
+        match base {
+            BaseInputTypeReference::BuiltinScalar(bstd) => Self::builtin_scalar_schema(bstd),
+            BaseInputTypeReference::CustomScalar(cstd) => self.custom_scalar_schema(cstd),
+            BaseInputTypeReference::Enum(etd) => self.enum_type_schema(etd, definitions),
+            BaseInputTypeReference::InputObject(iotd) => {
+                self.input_object_type_schema(iotd, definitions)
+            }
+        }
+    }
+
+    /// Returns the schema for non-null values of `input_type`, and whether it is required
+    fn input_type_schema_parts(
+        &self,
+        input_type: &'a S::InputType,
+        definitions: &mut Map<String, Value>,
+    ) -> (Value, bool) {
+        match input_type.as_ref(self.schema_definition) {
+            InputTypeReference::Base(base, required) => {
+                (self.base_input_type_schema(base, definitions), required)
+            }
+            InputTypeReference::List(inner, required) => (
+                Self::list_schema(self.input_type_schema_parts(inner, definitions)),
+                required,
+            ),
+        }
+    }
+
+    fn variable_type_schema<T: VariableType>(
+        &self,
+        variable_type: VariableTypeReference<'_, T>,
+        definitions: &mut Map<String, Value>,
+    ) -> (Value, bool) {
+        match variable_type {
+            VariableTypeReference::Named(name, required) => (
+                self.schema_definition
+                    .get_type_definition(name)
+                    .and_then(|type_definition| {
+                        self.type_definition_schema(type_definition, definitions)
+                    })
+                    .unwrap_or_else(|| json!({})),
+                required,
+            ),
+            VariableTypeReference::List(inner, required) => (
+                Self::list_schema(self.variable_type_schema(inner.as_ref(), definitions)),
+                required,
+            ),
+        }
+    }
+
+    fn list_schema((item_schema, item_required): (Value, bool)) -> Value {
+        json!({
+            "anyOf": [
+                {
+                    "type": "array",
+                    "items": Self::nullable_unless(item_schema.clone(), item_required),
+                },
+                item_schema,
+            ]
+        })
+    }
+
+    fn nullable_unless(schema: Value, required: bool) -> Value {
+        if required {
+            schema
+        } else {
+            json!({ "anyOf": [schema, { "type": "null" }] })
+        }
+    }
+
+    fn builtin_scalar_schema(bstd: BuiltinScalarDefinition) -> Value {
+        match bstd {
+            BuiltinScalarDefinition::Boolean => json!({ "type": "boolean" }),
+            BuiltinScalarDefinition::Float => json!({ "type": "number" }),
+            BuiltinScalarDefinition::ID => json!({ "type": ["string", "integer"] }),
+            BuiltinScalarDefinition::Int => json!({
+                "type": "integer",
+                "minimum": i32::MIN,
+                "maximum": i32::MAX,
+            }),
+            BuiltinScalarDefinition::String => json!({ "type": "string" }),
+        }
+    }
+
+    fn custom_scalar_schema(&self, cstd: &S::CustomScalarTypeDefinition) -> Value {
+        self.custom_scalar_mapping
+            .json_schema(cstd.name())
+            .unwrap_or_else(|| json!({}))
+    }
+
+    fn enum_type_schema(
+        &self,
+        etd: &'a S::EnumTypeDefinition,
+        definitions: &mut Map<String, Value>,
+    ) -> Value {
+        let name = etd.name();
+        if !definitions.contains_key(name) {
+            let mut schema = Map::new();
+            schema.insert("type".to_owned(), Value::from("string"));
+            schema.insert(
+                "enum".to_owned(),
+                etd.enum_value_definitions()
+                    .iter()
+                    .map(|evd| Value::from(evd.name()))
+                    .collect(),
+            );
+            if let Some(description) = etd.description() {
+                schema.insert("description".to_owned(), Value::from(description));
+            }
+            definitions.insert(name.to_owned(), Value::Object(schema));
+        }
+        Self::reference(name)
+    }
+
+    fn input_object_type_schema(
+        &self,
+        iotd: &'a S::InputObjectTypeDefinition,
+        definitions: &mut Map<String, Value>,
+    ) -> Value {
+        let name = iotd.name();
+        if definitions.contains_key(name) {
+            return Self::reference(name);
+        }
+        // reserve the entry so that recursive input objects terminate
+        definitions.insert(name.to_owned(), Value::Bool(true));
+
+        let is_one_of = Self::has_directive(iotd, Self::ONE_OF_DIRECTIVE_NAME);
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+
+        iotd.input_field_definitions().iter().for_each(|ivd| {
+            let (schema, is_required) = self.input_type_schema_parts(ivd.r#type(), definitions);
+            // exactly one field of a oneOf input object is set, and it must be non-null
+            let mut schema = if is_one_of {
+                schema
+            } else {
+                Self::nullable_unless(schema, is_required)
+            };
+            if let Some(description) = ivd.description() {
+                schema = Self::with_keyword(schema, "description", Value::from(description));
+            }
+            if let Some(default_value) = ivd.default_value() {
+                schema = Self::with_keyword(schema, "default", to_json(default_value));
+            }
+            if Self::has_directive(ivd, Self::DEPRECATED_DIRECTIVE_NAME) {
+                schema = Self::with_keyword(schema, "deprecated", Value::Bool(true));
+            }
+            if !is_one_of && ivd.is_required() {
+                required.push(Value::from(ivd.name()));
+            }
+            properties.insert(ivd.name().to_owned(), schema);
+        });
+
+        let mut schema = Map::new();
+        schema.insert("type".to_owned(), Value::from("object"));
+        if let Some(description) = iotd.description() {
+            schema.insert("description".to_owned(), Value::from(description));
+        }
+        if is_one_of {
+            schema.insert(
+                "oneOf".to_owned(),
+                properties
+                    .keys()
+                    .map(|field_name| json!({ "required": [field_name] }))
+                    .collect(),
+            );
+        }
+        schema.insert("properties".to_owned(), Value::Object(properties));
+        if !required.is_empty() {
+            schema.insert("required".to_owned(), Value::Array(required));
+        }
+        schema.insert("additionalProperties".to_owned(), Value::Bool(false));
+
+        definitions.insert(name.to_owned(), Value::Object(schema));
+        Self::reference(name)
+    }
+
+    fn has_directive(has_directives: &impl HasDirectives, directive_name: &str) -> bool {
+        has_directives.directives().is_some_and(|directives| {
+            directives
+                .iter()
+                .any(|directive| directive.name() == directive_name)
+        })
+    }
+
+    fn reference(name: &str) -> Value {
+        json!({ "$ref": format!("#/$defs/{name}") })
+    }
+
+    fn with_keyword(schema: Value, keyword: &str, value: Value) -> Value {
+        let mut schema = Self::into_object(schema);
+        schema.insert(keyword.to_owned(), value);
+        Value::Object(schema)
+    }
+
+    fn into_object(schema: Value) -> Map<String, Value> {
+        match schema {
+            Value::Object(schema) => schema,
+            boolean_schema => {
+                let mut schema = Map::new();
+                schema.insert("allOf".to_owned(), Value::Array(vec![boolean_schema]));
+                schema
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod custom_scalar_mapping;
+mod generator;
+mod value;
+
+pub use custom_scalar_mapping::CustomScalarMapping;
+pub use generator::JsonSchemaGenerator;
+
+/// The `$schema` URI of the JSON Schema dialect that is generated
+pub const DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
@@ -0,0 +1,19 @@
+use bluejay_core::{AsIter, ObjectValue, Value, ValueReference};
+use serde_json::{Map, Number, Value as JsonValue};
+
+pub(crate) fn to_json<const CONST: bool, V: Value<CONST>>(value: &V) -> JsonValue {
+    match value.as_ref() {
+        ValueReference::Boolean(b) => JsonValue::Bool(b),
+        ValueReference::Enum(e) => JsonValue::String(e.to_owned()),
+        ValueReference::Float(f) => Number::from_f64(f).map_or(JsonValue::Null, JsonValue::Number),
+        ValueReference::Integer(i) => JsonValue::Number(i.into()),
+        ValueReference::List(l) => JsonValue::Array(l.iter().map(to_json).collect()),
+        ValueReference::Null | ValueReference::Variable(_) => JsonValue::Null,
+        ValueReference::Object(o) => JsonValue::Object(
+            o.iter()
+                .map(|(key, value)| (key.as_ref().to_owned(), to_json(value)))
+                .collect::<Map<_, _>>(),
+        ),
+        ValueReference::String(s) => JsonValue::String(s.to_owned()),
+    }
+}
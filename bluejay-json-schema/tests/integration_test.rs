@@ -0,0 +1,179 @@
+use bluejay_json_schema::{JsonSchemaGenerator, DRAFT_2020_12};
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+const SCHEMA: &str = r#"
+scalar DateTime
+
+"Sort direction"
+enum Direction {
+  ASC
+  DESC
+}
+
+input Filter {
+  "Maximum number of results"
+  first: Int = 10
+  after: DateTime
+  ids: [ID!]!
+  direction: Direction @deprecated
+  and: Filter
+}
+
+input Lookup @oneOf {
+  id: ID
+  handle: String
+}
+
+type Query {
+  search(filter: Filter!): [String!]!
+  lookup(by: Lookup!): String
+}
+"#;
+
+fn with_schema(f: impl FnOnce(&SchemaDefinition)) {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA).result.unwrap();
+    let schema_definition = SchemaDefinition::try_from(&definition_document).unwrap();
+    f(&schema_definition);
+}
+
+#[test]
+fn test_input_object_schema() {
+    with_schema(|schema_definition| {
+        let mapping = HashMap::from([(
+            "DateTime".to_owned(),
+            json!({ "type": "string", "format": "date-time" }),
+        )]);
+        let generator = JsonSchemaGenerator::new(schema_definition, &mapping);
+
+        assert_eq!(
+            Some(json!({
+                "$schema": DRAFT_2020_12,
+                "$ref": "#/$defs/Filter",
+                "$defs": {
+                    "Direction": {
+                        "type": "string",
+                        "enum": ["ASC", "DESC"],
+                        "description": "Sort direction",
+                    },
+                    "Filter": {
+                        "type": "object",
+                        "properties": {
+                            "first": {
+                                "anyOf": [
+                                    { "type": "integer", "minimum": i32::MIN, "maximum": i32::MAX },
+                                    { "type": "null" },
+                                ],
+                                "description": "Maximum number of results",
+                                "default": 10,
+                            },
+                            "after": {
+                                "anyOf": [
+                                    { "type": "string", "format": "date-time" },
+                                    { "type": "null" },
+                                ],
+                            },
+                            "ids": {
+                                "anyOf": [
+                                    {
+                                        "type": "array",
+                                        "items": { "type": ["string", "integer"] },
+                                    },
+                                    { "type": ["string", "integer"] },
+                                ],
+                            },
+                            "direction": {
+                                "anyOf": [{ "$ref": "#/$defs/Direction" }, { "type": "null" }],
+                                "deprecated": true,
+                            },
+                            "and": {
+                                "anyOf": [{ "$ref": "#/$defs/Filter" }, { "type": "null" }],
+                            },
+                        },
+                        "required": ["ids"],
+                        "additionalProperties": false,
+                    },
+                },
+            })),
+            generator.input_type_schema("Filter"),
+        );
+        assert_eq!(None, generator.input_type_schema("Query"));
+    });
+}
+
+#[test]
+fn test_one_of_input_object_schema() {
+    with_schema(|schema_definition| {
+        let generator = JsonSchemaGenerator::new(schema_definition, &());
+
+        assert_eq!(
+            Some(json!({
+                "type": "object",
+                "oneOf": [{ "required": ["handle"] }, { "required": ["id"] }],
+                "properties": {
+                    "id": { "type": ["string", "integer"] },
+                    "handle": { "type": "string" },
+                },
+                "additionalProperties": false,
+            })),
+            generator.definitions().get("Lookup").cloned(),
+        );
+    });
+}
+
+#[test]
+fn test_variables_schema() {
+    with_schema(|schema_definition| {
+        let executable_document = ExecutableDocument::parse(
+            "query($by: Lookup!, $direction: Direction = ASC, $tags: [String], $unknown: Unknown) { lookup(by: $by) }",
+        )
+        .result
+        .unwrap();
+        let generator = JsonSchemaGenerator::new(schema_definition, &());
+        let schema = generator
+            .variables_schema(executable_document.operation_definitions().first().unwrap());
+
+        assert_eq!(
+            json!({
+                "$schema": DRAFT_2020_12,
+                "type": "object",
+                "properties": {
+                    "by": { "$ref": "#/$defs/Lookup" },
+                    "direction": {
+                        "anyOf": [{ "$ref": "#/$defs/Direction" }, { "type": "null" }],
+                        "default": "ASC",
+                    },
+                    "tags": {
+                        "anyOf": [
+                            {
+                                "anyOf": [
+                                    {
+                                        "type": "array",
+                                        "items": {
+                                            "anyOf": [{ "type": "string" }, { "type": "null" }],
+                                        },
+                                    },
+                                    { "type": "string" },
+                                ],
+                            },
+                            { "type": "null" },
+                        ],
+                    },
+                    "unknown": { "anyOf": [{}, { "type": "null" }] },
+                },
+                "required": ["by"],
+                "additionalProperties": false,
+                "$defs": {
+                    "Direction": schema["$defs"]["Direction"].clone(),
+                    "Lookup": schema["$defs"]["Lookup"].clone(),
+                },
+            }),
+            schema,
+        );
+    });
+}
@@ -7,6 +7,12 @@ pub trait Context: std::fmt::Debug + Sized {
         cstd: &CustomScalarTypeDefinition<Self>,
         value: &impl Value<CONST>,
     ) -> Result<(), Cow<'static, str>>;
+
+    /// Whether the `@defer` and `@stream` directives from the incremental delivery proposal
+    /// should be included as builtin directive definitions. Defaults to `false`.
+    fn incremental_delivery_directives() -> bool {
+        false
+    }
 }
 
 #[derive(Debug)]
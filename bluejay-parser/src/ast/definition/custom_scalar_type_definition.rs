@@ -19,6 +19,10 @@ pub struct CustomScalarTypeDefinition<'a, C: Context> {
 }
 
 impl<'a, C: Context> CustomScalarTypeDefinition<'a, C> {
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
     pub(crate) fn name(&self) -> &Name<'a> {
         &self.name
     }
@@ -218,6 +218,7 @@ impl<'a, C: Context> DefinitionDocument<'a, C> {
         let mut builtin_directive_definitions_by_name: HashMap<&str, BuiltinDirectiveDefinition> =
             HashMap::from_iter(
                 BuiltinDirectiveDefinition::iter()
+                    .filter(BuiltinDirectiveDefinition::is_enabled::<C>)
                     .map(|bdd: BuiltinDirectiveDefinition| (bdd.into(), bdd)),
             );
 
@@ -766,14 +767,16 @@ mod tests {
 
     use bluejay_core::{
         definition::{
-            FieldDefinition as CoreFieldDefinition,
+            DirectiveDefinition as CoreDirectiveDefinition, FieldDefinition as CoreFieldDefinition,
             ObjectTypeDefinition as CoreObjectTypeDefinition,
             SchemaDefinition as CoreSchemaDefinition,
         },
         AsIter,
     };
 
-    use super::{DefinitionDocument, Parse, SchemaDefinition};
+    use super::{Context, CustomScalarTypeDefinition, DefinitionDocument, Parse, SchemaDefinition};
+    use bluejay_core::Value;
+    use std::borrow::Cow;
 
     #[test]
     fn test_can_be_used_owned_with_self_cell() {
@@ -889,6 +892,8 @@ mod tests {
             HashSet::from(["include", "skip", "deprecated", "specifiedBy", "oneOf"])
                 .is_subset(&directives)
         );
+        assert!(!directives.contains("defer"));
+        assert!(!directives.contains("stream"));
 
         let builtin_types: HashSet<&str> = schema_definition
             .type_definitions()
@@ -914,4 +919,43 @@ mod tests {
             builtin_types,
         );
     }
+
+    #[test]
+    fn incremental_delivery_directives_test() {
+        #[derive(Debug)]
+        struct IncrementalDeliveryContext;
+
+        impl Context for IncrementalDeliveryContext {
+            fn coerce_custom_scalar_input<const CONST: bool>(
+                _cstd: &CustomScalarTypeDefinition<Self>,
+                _value: &impl Value<CONST>,
+            ) -> Result<(), Cow<'static, str>> {
+                Ok(())
+            }
+
+            fn incremental_delivery_directives() -> bool {
+                true
+            }
+        }
+
+        let s = r#"
+        type Query {
+            foo: [String!]!
+        }
+        "#;
+
+        let document: DefinitionDocument<IncrementalDeliveryContext> = DefinitionDocument::parse(s)
+            .result
+            .expect("Document had parse errors");
+
+        let schema_definition = SchemaDefinition::try_from(&document)
+            .expect("Could not convert document to schema definition");
+
+        let builtin_directives: HashSet<&str> = schema_definition
+            .directive_definitions()
+            .filter_map(|dd| dd.is_builtin().then_some(dd.name()))
+            .collect();
+
+        assert!(HashSet::from(["defer", "stream"]).is_subset(&builtin_directives));
+    }
 }
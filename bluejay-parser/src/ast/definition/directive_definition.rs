@@ -12,11 +12,13 @@ use strum::{EnumIter, IntoStaticStr};
 #[derive(IntoStaticStr, EnumIter, Clone, Copy, Debug, PartialEq)]
 #[strum(serialize_all = "camelCase")]
 pub enum BuiltinDirectiveDefinition {
+    Defer,
     Deprecated,
     Include,
     OneOf,
     Skip,
     SpecifiedBy,
+    Stream,
 }
 
 impl BuiltinDirectiveDefinition {
@@ -27,14 +29,29 @@ impl BuiltinDirectiveDefinition {
     const DEPRECATED_DEFINITION: &'static str = "directive @deprecated(reason: String = \"No longer supported\") on FIELD_DEFINITION | ARGUMENT_DEFINITION | INPUT_FIELD_DEFINITION | ENUM_VALUE";
     const SPECIFIED_BY_DEFINITION: &'static str = "directive @specifiedBy(url: String!) on SCALAR";
     const ONE_OF_DEFINITION: &'static str = "directive @oneOf on INPUT_OBJECT";
+    const DEFER_DEFINITION: &'static str =
+        "directive @defer(label: String, if: Boolean! = true) on FRAGMENT_SPREAD | INLINE_FRAGMENT";
+    const STREAM_DEFINITION: &'static str =
+        "directive @stream(label: String, if: Boolean! = true, initialCount: Int = 0) on FIELD";
 
     fn definition(&self) -> &'static str {
         match self {
+            Self::Defer => Self::DEFER_DEFINITION,
             Self::Deprecated => Self::DEPRECATED_DEFINITION,
             Self::Include => Self::INCLUDE_DEFINITION,
             Self::OneOf => Self::ONE_OF_DEFINITION,
             Self::Skip => Self::SKIP_DEFINITION,
             Self::SpecifiedBy => Self::SPECIFIED_BY_DEFINITION,
+            Self::Stream => Self::STREAM_DEFINITION,
+        }
+    }
+
+    /// Whether the directive is only a builtin when opted into by the [`Context`],
+    /// as is the case for the incremental delivery directives `@defer` and `@stream`
+    pub(crate) fn is_enabled<C: Context>(&self) -> bool {
+        match self {
+            Self::Defer | Self::Stream => C::incremental_delivery_directives(),
+            _ => true,
         }
     }
 }
@@ -94,7 +111,7 @@ impl<'a, C: Context> DirectiveDefinition<'a, C> {
     const REPEATABLE_IDENTIFIER: &'static str = "repeatable";
     const ON_IDENTIFIER: &'static str = "on";
 
-    pub(crate) fn name_token(&self) -> &Name<'a> {
+    pub fn name_token(&self) -> &Name<'a> {
         &self.name
     }
 
@@ -68,6 +68,10 @@ impl<'a, C: Context> EnumTypeDefinition<'a, C> {
         INPUT_FIELD_DEFINITION
     }";
 
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
     pub(crate) fn name(&self) -> &Name<'a> {
         &self.name
     }
@@ -39,6 +39,12 @@ impl<C: Context> FieldDefinition<'_, C> {
     }
 }
 
+impl<'a, C: Context> FieldDefinition<'a, C> {
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+}
+
 impl<'a, C: Context> CoreFieldDefinition for FieldDefinition<'a, C> {
     type ArgumentsDefinition = ArgumentsDefinition<'a, C>;
     type OutputType = OutputType<'a, C>;
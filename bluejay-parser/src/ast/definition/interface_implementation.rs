@@ -37,6 +37,10 @@ impl<'a, C: Context> CoreInterfaceImplementation for InterfaceImplementation<'a,
 }
 
 impl<'a, C: Context> InterfaceImplementation<'a, C> {
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
     pub(crate) fn interface_name(&self) -> &Name<'a> {
         &self.name
     }
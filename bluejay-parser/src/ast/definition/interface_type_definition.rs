@@ -38,6 +38,10 @@ impl<'a, C: Context> CoreInterfaceTypeDefinition for InterfaceTypeDefinition<'a,
 impl<'a, C: Context> InterfaceTypeDefinition<'a, C> {
     pub(crate) const INTERFACE_IDENTIFIER: &'static str = "interface";
 
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
     pub(crate) fn name(&self) -> &Name<'a> {
         &self.name
     }
@@ -97,6 +97,10 @@ impl<'a, C: Context> ObjectTypeDefinition<'a, C> {
         isRepeatable: Boolean!
     }";
 
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
     pub(crate) fn name(&self) -> &Name<'a> {
         &self.name
     }
@@ -131,3 +131,12 @@ impl<'a, C: Context + 'a> FromTokens<'a> for OutputType<'a, C> {
         }
     }
 }
+
+impl<C: Context> HasSpan for OutputType<'_, C> {
+    fn span(&self) -> &Span {
+        match self {
+            Self::Base(_, _, span) => span,
+            Self::List(_, _, span) => span,
+        }
+    }
+}
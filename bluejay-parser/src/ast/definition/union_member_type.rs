@@ -31,6 +31,10 @@ impl<'a, C: Context + 'a> CoreUnionMemberType for UnionMemberType<'a, C> {
 }
 
 impl<'a, C: Context> UnionMemberType<'a, C> {
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
     pub(crate) fn name(&self) -> &Name<'a> {
         &self.name
     }
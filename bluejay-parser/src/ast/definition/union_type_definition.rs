@@ -36,6 +36,10 @@ impl<'a, C: Context> CoreUnionTypeDefinition for UnionTypeDefinition<'a, C> {
 impl<'a, C: Context> UnionTypeDefinition<'a, C> {
     pub(crate) const UNION_IDENTIFIER: &'static str = "union";
 
+    pub fn name_token(&self) -> &Name<'a> {
+        &self.name
+    }
+
     pub(crate) fn name(&self) -> &Name<'a> {
         &self.name
     }
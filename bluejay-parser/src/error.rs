@@ -4,6 +4,7 @@ use itertools::Either;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 
 mod annotation;
 mod format_errors;
@@ -16,6 +17,55 @@ pub struct Error {
     message: Cow<'static, str>,
     primary_annotation: Option<Annotation>,
     secondary_annotations: Vec<Annotation>,
+    severity: Severity,
+    code: Option<&'static str>,
+    spec_section: Option<&'static str>,
+    extensions: Vec<(&'static str, ExtensionValue)>,
+}
+
+/// The value of a structured detail of an [`Error`], either a string or a list of strings such
+/// as a response path
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize), serde(untagged))]
+pub enum ExtensionValue {
+    String(String),
+    List(Vec<String>),
+}
+
+impl ExtensionValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            Self::List(_) => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[String]> {
+        match self {
+            Self::String(_) => None,
+            Self::List(values) => Some(values),
+        }
+    }
+}
+
+impl From<String> for ExtensionValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Vec<String>> for ExtensionValue {
+    fn from(values: Vec<String>) -> Self {
+        Self::List(values)
+    }
+}
+
+/// How an [`Error`] is reported when formatted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -26,11 +76,33 @@ pub struct Location {
 }
 
 /// A [spec compliant GraphQL Error](https://spec.graphql.org/draft/#sec-Errors.Error-Result-Format)
+#[non_exhaustive]
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub struct GraphQLError {
     pub message: Cow<'static, str>,
     pub locations: Vec<Location>,
+    /// Contains the `code` and `specSection` of the error, if known, along with
+    /// any structured details such as the names of the types or fields involved
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub extensions: BTreeMap<String, ExtensionValue>,
+}
+
+impl GraphQLError {
+    pub fn new(message: impl Into<Cow<'static, str>>, locations: Vec<Location>) -> Self {
+        Self {
+            message: message.into(),
+            locations,
+            extensions: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_extensions(self, extensions: BTreeMap<String, ExtensionValue>) -> Self {
+        Self { extensions, ..self }
+    }
 }
 
 impl Error {
@@ -43,9 +115,47 @@ impl Error {
             message: message.into(),
             primary_annotation,
             secondary_annotations,
+            severity: Severity::Error,
+            code: None,
+            spec_section: None,
+            extensions: Vec::new(),
         }
     }
 
+    /// Sets a stable, machine-readable code identifying the kind of error, e.g. `FIELD_NOT_DEFINED`
+    pub fn with_code(self, code: &'static str) -> Self {
+        Self {
+            code: Some(code),
+            ..self
+        }
+    }
+
+    /// Sets a link to the section of the GraphQL specification that the error comes from
+    pub fn with_spec_section(self, spec_section: &'static str) -> Self {
+        Self {
+            spec_section: Some(spec_section),
+            ..self
+        }
+    }
+
+    /// Sets structured details about the error, e.g. `("fieldName", "name")`
+    pub fn with_extensions<V: Into<ExtensionValue>>(
+        self,
+        extensions: impl IntoIterator<Item = (&'static str, V)>,
+    ) -> Self {
+        Self {
+            extensions: extensions
+                .into_iter()
+                .map(|(key, value)| (key, value.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    pub fn with_severity(self, severity: Severity) -> Self {
+        Self { severity, ..self }
+    }
+
     pub fn into_graphql_errors<E: Into<Error>>(
         document: &str,
         errors: impl IntoIterator<Item = E>,
@@ -55,6 +165,7 @@ impl Error {
             .into_iter()
             .flat_map(|err| {
                 let err: Error = err.into();
+                let extensions = err.graphql_extensions();
                 if let Some(primary_annotation) = err.primary_annotation {
                     let (line, col) = converter
                         .borrow_mut()
@@ -63,10 +174,12 @@ impl Error {
                     Either::Left(std::iter::once(GraphQLError {
                         message: primary_annotation.message,
                         locations: vec![Location { line, col }],
+                        extensions,
                     }))
                 } else if !err.secondary_annotations.is_empty() {
+                    let converter = &converter;
                     Either::Right(err.secondary_annotations.into_iter().map(
-                        |secondary_annotation| {
+                        move |secondary_annotation| {
                             let (line, col) = converter
                                 .borrow_mut()
                                 .convert(secondary_annotation.span())
@@ -74,6 +187,7 @@ impl Error {
                             GraphQLError {
                                 message: secondary_annotation.message,
                                 locations: vec![Location { line, col }],
+                                extensions: extensions.clone(),
                             }
                         },
                     ))
@@ -81,6 +195,7 @@ impl Error {
                     Either::Left(std::iter::once(GraphQLError {
                         message: err.message,
                         locations: vec![],
+                        extensions,
                     }))
                 }
             })
@@ -107,7 +222,10 @@ impl Error {
                     buf.extend("\n".as_bytes());
                 }
                 Report::<(&str, logos::Span)>::build(
-                    ReportKind::Error,
+                    match error.severity {
+                        Severity::Error => ReportKind::Error,
+                        Severity::Warning => ReportKind::Warning,
+                    },
                     (
                         filename,
                         error
@@ -148,4 +266,33 @@ impl Error {
     pub fn message(&self) -> &str {
         self.message.as_ref()
     }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn code(&self) -> Option<&'static str> {
+        self.code
+    }
+
+    pub fn spec_section(&self) -> Option<&'static str> {
+        self.spec_section
+    }
+
+    pub fn extensions(&self) -> &[(&'static str, ExtensionValue)] {
+        &self.extensions
+    }
+
+    fn graphql_extensions(&self) -> BTreeMap<String, ExtensionValue> {
+        self.code
+            .map(|code| ("code", ExtensionValue::from(code.to_owned())))
+            .into_iter()
+            .chain(
+                self.spec_section
+                    .map(|spec_section| ("specSection", spec_section.to_owned().into())),
+            )
+            .chain(self.extensions.iter().cloned())
+            .map(|(key, value)| (key.to_owned(), value))
+            .collect()
+    }
 }
@@ -16,9 +16,9 @@ fn test_parser_errors() {
     assert!(document.result.is_err());
     let document_errors = document.result.unwrap_err();
     let graphql_errors = Error::into_graphql_errors(source, document_errors);
-    let expected: Vec<GraphQLError> = vec![GraphQLError {
-        message: Cow::from("Expected a name"),
-        locations: vec![Location { line: 2, col: 15 }],
-    }];
+    let expected: Vec<GraphQLError> = vec![GraphQLError::new(
+        Cow::from("Expected a name"),
+        vec![Location { line: 2, col: 15 }],
+    )];
     assert_eq!(expected, graphql_errors);
 }
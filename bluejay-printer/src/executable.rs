@@ -1,5 +1,6 @@
 mod executable_document;
 mod field;
+mod flattened_operation;
 mod fragment_definition;
 mod fragment_spread;
 mod inline_fragment;
@@ -10,10 +11,11 @@ mod variable_definition;
 
 pub use executable_document::ExecutableDocumentPrinter;
 use field::FieldPrinter;
+pub use flattened_operation::FlattenedOperationPrinter;
 use fragment_definition::FragmentDefinitionPrinter;
 use fragment_spread::FragmentSpreadPrinter;
 use inline_fragment::InlineFragmentPrinter;
 use operation_definition::OperationDefinitionPrinter;
 use selection::SelectionPrinter;
 use selection_set::SelectionSetPrinter;
-use variable_definition::VariableDefinitionsPrinter;
+use variable_definition::{VariableDefinitionPrinter, VariableDefinitionsPrinter};
@@ -0,0 +1,543 @@
+use crate::{
+    argument::ArgumentsPrinter, directive::DirectivePrinter, executable::VariableDefinitionPrinter,
+    write_indent, INDENTATION_SIZE,
+};
+use bluejay_core::definition::{
+    FieldDefinition, FieldsDefinition, ObjectTypeDefinition, OutputType, SchemaDefinition,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment,
+    OperationDefinition, Selection, SelectionReference, VariableDefinition,
+};
+use bluejay_core::{
+    Argument, Arguments, AsIter, Directive, ObjectValue, OperationType, SelectionInclusion, Value,
+    ValueReference, Variable,
+};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter, Result, Write};
+
+/// Prints a single operation the way it is seen during execution: fragment spreads are
+/// inlined as inline fragments, selections excluded by `@skip` or `@include` given the
+/// variable values are dropped, and inline fragments whose type condition is the parent
+/// type are merged into the enclosing selection set.
+///
+/// Directives on fragment definitions are not carried over to the inlined fragments, and
+/// variable definitions that are no longer used by the printed operation are dropped.
+pub struct FlattenedOperationPrinter<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    V: ObjectValue<true>,
+> {
+    fragment_definitions: HashMap<&'a str, &'a E::FragmentDefinition>,
+    schema_definition: &'a S,
+    operation_definition: &'a E::OperationDefinition,
+    variable_values: &'a V,
+}
+
+/// The state threaded through the selections while printing them
+#[derive(Default)]
+struct FlattenState<'a> {
+    /// The fragments being inlined, to stop at cyclic spreads
+    spread_fragments: Vec<&'a str>,
+    /// The variables used by the printed selections
+    used_variables: HashSet<&'a str>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, V: ObjectValue<true>>
+    FlattenedOperationPrinter<'a, E, S, V>
+{
+    pub fn new(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        operation_definition: &'a E::OperationDefinition,
+        variable_values: &'a V,
+    ) -> Self {
+        Self {
+            fragment_definitions: executable_document
+                .fragment_definitions()
+                .map(|fragment_definition| (fragment_definition.name(), fragment_definition))
+                .collect(),
+            schema_definition,
+            operation_definition,
+            variable_values,
+        }
+    }
+
+    pub fn to_string(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        operation_definition: &'a E::OperationDefinition,
+        variable_values: &'a V,
+    ) -> String {
+        Self::new(
+            executable_document,
+            schema_definition,
+            operation_definition,
+            variable_values,
+        )
+        .to_string()
+    }
+
+    fn root_type_name(&self, operation_type: OperationType) -> Option<&'a str> {
+        match operation_type {
+            OperationType::Query => Some(self.schema_definition.query().name()),
+            OperationType::Mutation => self.schema_definition.mutation().map(|otd| otd.name()),
+            OperationType::Subscription => {
+                self.schema_definition.subscription().map(|otd| otd.name())
+            }
+        }
+    }
+
+    fn field_type_name(&self, parent_type_name: Option<&str>, field_name: &str) -> Option<&'a str> {
+        self.schema_definition
+            .get_type_definition(parent_type_name?)?
+            .fields_definition()?
+            .get(field_name)
+            .map(|field_definition| field_definition.r#type().base_name())
+    }
+
+    fn evaluate_variable(&self, name: &str) -> Option<bool> {
+        if let Some((_, value)) = self
+            .variable_values
+            .iter()
+            .find(|(key, _)| key.as_ref() == name)
+        {
+            return value.as_ref().as_boolean().copied();
+        }
+
+        self.operation_definition
+            .as_ref()
+            .variable_definitions()?
+            .iter()
+            .find(|variable_definition| variable_definition.variable() == name)?
+            .default_value()
+            .and_then(|value| value.as_ref().as_boolean().copied())
+    }
+
+    /// Evaluates the `@skip`/`@include` directives of a selection. When they are fully
+    /// resolved, they are omitted from the output.
+    fn inclusion(&self, directives: Option<&'a E::Directives<false>>) -> SelectionInclusion {
+        SelectionInclusion::evaluate(directives, |variable| self.evaluate_variable(variable))
+    }
+
+    fn retained_directives(
+        &self,
+        directives: Option<&'a E::Directives<false>>,
+        inclusion: SelectionInclusion,
+    ) -> Vec<&'a E::Directive<false>> {
+        directives
+            .into_iter()
+            .flat_map(|directives| directives.iter())
+            .filter(|directive| {
+                matches!(inclusion, SelectionInclusion::Unknown)
+                    || !matches!(
+                        directive.name(),
+                        SelectionInclusion::SKIP_DIRECTIVE_NAME
+                            | SelectionInclusion::INCLUDE_DIRECTIVE_NAME
+                    )
+            })
+            .collect()
+    }
+
+    fn fmt_retained_directives(
+        &self,
+        f: &mut impl Write,
+        directives: Vec<&'a E::Directive<false>>,
+        state: &mut FlattenState<'a>,
+    ) -> Result {
+        directives.into_iter().try_for_each(|directive| {
+            if let Some(arguments) = directive.arguments() {
+                record_variables(arguments, &mut state.used_variables);
+            }
+            write!(f, " {}", DirectivePrinter::new(directive))
+        })
+    }
+
+    fn fmt_selection_set(
+        &self,
+        f: &mut impl Write,
+        selection_set: &'a E::SelectionSet,
+        parent_type_name: Option<&'a str>,
+        indentation: usize,
+        state: &mut FlattenState<'a>,
+    ) -> Result {
+        writeln!(f, "{{")?;
+        self.fmt_selections(
+            f,
+            selection_set,
+            parent_type_name,
+            indentation + INDENTATION_SIZE,
+            state,
+        )?;
+        write_indent(f, indentation)?;
+        write!(f, "}}")
+    }
+
+    fn fmt_selections(
+        &self,
+        f: &mut impl Write,
+        selection_set: &'a E::SelectionSet,
+        parent_type_name: Option<&'a str>,
+        indentation: usize,
+        state: &mut FlattenState<'a>,
+    ) -> Result {
+        selection_set
+            .iter()
+            .try_for_each(|selection| match selection.as_ref() {
+                SelectionReference::Field(field) => {
+                    self.fmt_field(f, field, parent_type_name, indentation, state)
+                }
+                SelectionReference::InlineFragment(inline_fragment) => self.fmt_fragment(
+                    f,
+                    inline_fragment.type_condition(),
+                    inline_fragment.directives(),
+                    inline_fragment.selection_set(),
+                    parent_type_name,
+                    indentation,
+                    state,
+                ),
+                SelectionReference::FragmentSpread(fragment_spread) => {
+                    let name = fragment_spread.name();
+                    if state.spread_fragments.contains(&name) {
+                        return Ok(());
+                    }
+                    let Some(fragment_definition) = self.fragment_definitions.get(name) else {
+                        return Ok(());
+                    };
+                    state.spread_fragments.push(name);
+                    let result = self.fmt_fragment(
+                        f,
+                        Some(fragment_definition.type_condition()),
+                        fragment_spread.directives(),
+                        fragment_definition.selection_set(),
+                        parent_type_name,
+                        indentation,
+                        state,
+                    );
+                    state.spread_fragments.pop();
+                    result
+                }
+            })
+    }
+
+    fn fmt_field(
+        &self,
+        f: &mut impl Write,
+        field: &'a E::Field,
+        parent_type_name: Option<&'a str>,
+        indentation: usize,
+        state: &mut FlattenState<'a>,
+    ) -> Result {
+        let inclusion = self.inclusion(field.directives());
+        if inclusion.is_excluded() {
+            return Ok(());
+        }
+        write_indent(f, indentation)?;
+        if let Some(alias) = field.alias() {
+            write!(f, "{}: ", alias)?;
+        }
+        write!(f, "{}", field.name())?;
+        if let Some(arguments) = field.arguments() {
+            record_variables(arguments, &mut state.used_variables);
+            write!(f, "{}", ArgumentsPrinter::new(arguments))?;
+        }
+        self.fmt_retained_directives(
+            f,
+            self.retained_directives(field.directives(), inclusion),
+            state,
+        )?;
+        if let Some(selection_set) = field.selection_set() {
+            write!(f, " ")?;
+            self.fmt_selection_set(
+                f,
+                selection_set,
+                self.field_type_name(parent_type_name, field.name()),
+                indentation,
+                state,
+            )?;
+        }
+        writeln!(f)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn fmt_fragment(
+        &self,
+        f: &mut impl Write,
+        type_condition: Option<&'a str>,
+        directives: Option<&'a E::Directives<false>>,
+        selection_set: &'a E::SelectionSet,
+        parent_type_name: Option<&'a str>,
+        indentation: usize,
+        state: &mut FlattenState<'a>,
+    ) -> Result {
+        let inclusion = self.inclusion(directives);
+        if inclusion.is_excluded() {
+            return Ok(());
+        }
+        let retained_directives = self.retained_directives(directives, inclusion);
+        let is_redundant_type_condition = match type_condition {
+            Some(type_condition) => parent_type_name == Some(type_condition),
+            None => true,
+        };
+
+        if is_redundant_type_condition && retained_directives.is_empty() {
+            return self.fmt_selections(f, selection_set, parent_type_name, indentation, state);
+        }
+
+        write_indent(f, indentation)?;
+        write!(f, "...")?;
+        if let Some(type_condition) = type_condition {
+            write!(f, " on {}", type_condition)?;
+        }
+        self.fmt_retained_directives(f, retained_directives, state)?;
+        write!(f, " ")?;
+        self.fmt_selection_set(
+            f,
+            selection_set,
+            type_condition.or(parent_type_name),
+            indentation,
+            state,
+        )?;
+        writeln!(f)
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, V: ObjectValue<true>> Display
+    for FlattenedOperationPrinter<'a, E, S, V>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let operation_definition_reference = self.operation_definition.as_ref();
+        let operation_type = operation_definition_reference.operation_type();
+
+        // the selection set is printed first to know which variables are still used
+        let mut state = FlattenState::default();
+        let mut selection_set = String::new();
+        self.fmt_selection_set(
+            &mut selection_set,
+            operation_definition_reference.selection_set(),
+            self.root_type_name(operation_type),
+            0,
+            &mut state,
+        )?;
+        if let Some(directives) = operation_definition_reference.directives() {
+            directives
+                .iter()
+                .filter_map(Directive::arguments)
+                .for_each(|arguments| record_variables(arguments, &mut state.used_variables));
+        }
+
+        write!(f, "{}", operation_type)?;
+        if let Some(name) = operation_definition_reference.name() {
+            write!(f, " {}", name)?;
+        }
+        let used_variable_definitions: Vec<_> = operation_definition_reference
+            .variable_definitions()
+            .into_iter()
+            .flat_map(|variable_definitions| variable_definitions.iter())
+            .filter(|variable_definition| {
+                state
+                    .used_variables
+                    .contains(variable_definition.variable())
+            })
+            .collect();
+        if !used_variable_definitions.is_empty() {
+            write!(f, "(")?;
+            used_variable_definitions
+                .into_iter()
+                .enumerate()
+                .try_for_each(|(idx, variable_definition)| {
+                    if idx != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", VariableDefinitionPrinter::new(variable_definition))
+                })?;
+            write!(f, ")")?;
+        }
+        if let Some(directives) = operation_definition_reference.directives() {
+            directives
+                .iter()
+                .try_for_each(|directive| write!(f, " {}", DirectivePrinter::new(directive)))?;
+        }
+        writeln!(f, " {selection_set}")
+    }
+}
+
+/// Adds the names of the variables used in the values of `arguments` to `used_variables`
+fn record_variables<'a, A: Arguments<false>>(
+    arguments: &'a A,
+    used_variables: &mut HashSet<&'a str>,
+) {
+    arguments
+        .iter()
+        .for_each(|argument| record_value_variables(argument.value(), used_variables));
+}
+
+fn record_value_variables<'a, V: Value<false>>(
+    value: &'a V,
+    used_variables: &mut HashSet<&'a str>,
+) {
+    match value.as_ref() {
+        ValueReference::Variable(variable) => {
+            used_variables.insert(variable.name());
+        }
+        ValueReference::List(list) => list
+            .iter()
+            .for_each(|value| record_value_variables(value, used_variables)),
+        ValueReference::Object(object) => object
+            .iter()
+            .for_each(|(_, value)| record_value_variables(value, used_variables)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlattenedOperationPrinter;
+    use bluejay_core::Value as _;
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        ConstValue, Parse,
+    };
+
+    const SCHEMA: &str = r#"
+        interface Node {
+          id: ID!
+        }
+
+        type User implements Node {
+          id: ID!
+          name: String!
+          friends: [User!]!
+        }
+
+        type Query {
+          node(id: ID!): Node
+          viewer: User
+        }
+    "#;
+
+    fn print_flattened(query: &str, variables: &str) -> String {
+        let definition_document: DefinitionDocument =
+            DefinitionDocument::parse(SCHEMA).result.unwrap();
+        let schema_definition = SchemaDefinition::try_from(&definition_document).unwrap();
+        let executable_document = ExecutableDocument::parse(query).result.unwrap();
+        let variables = ConstValue::parse(variables).result.unwrap();
+        FlattenedOperationPrinter::to_string(
+            &executable_document,
+            &schema_definition,
+            executable_document.operation_definitions().first().unwrap(),
+            *variables.as_ref().as_object().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_inlines_fragment_spreads() {
+        let query = r#"
+            query Q($id: ID!) {
+              node(id: $id) { ...NodeFields }
+              viewer { ...UserFields }
+            }
+
+            fragment NodeFields on Node {
+              id
+              ...UserFields
+            }
+
+            fragment UserFields on User {
+              name
+            }
+        "#;
+        let expected = r#"query Q($id: ID!) {
+  node(id: $id) {
+    id
+    ... on User {
+      name
+    }
+  }
+  viewer {
+    name
+  }
+}
+"#;
+        similar_asserts::assert_eq!(expected, print_flattened(query, "{}"));
+    }
+
+    #[test]
+    fn test_prunes_skip_and_include() {
+        let query = r#"
+            query Q($skipName: Boolean!, $includeFriends: Boolean = false, $unknown: Boolean!) {
+              viewer {
+                id @skip(if: false)
+                name @skip(if: $skipName)
+                friends @include(if: $includeFriends) { id }
+                ... on User @skip(if: $unknown) { id }
+              }
+            }
+        "#;
+        let expected = r#"query Q($unknown: Boolean!) {
+  viewer {
+    id
+    ... on User @skip(if: $unknown) {
+      id
+    }
+  }
+}
+"#;
+        similar_asserts::assert_eq!(expected, print_flattened(query, "{ skipName: true }"));
+    }
+
+    #[test]
+    fn test_drops_unused_variable_definitions() {
+        let query = r#"
+            query Q($id: ID!, $otherId: ID!, $skipNode: Boolean!, $unused: Int, $ids: [ID!]!) @dir(ids: [$ids]) {
+              node(id: $id) { ...NodeFields @skip(if: $skipNode) }
+              ...OtherNode @include(if: false)
+            }
+
+            fragment NodeFields on Node {
+              id
+            }
+
+            fragment OtherNode on Query {
+              node(id: $otherId) { id }
+            }
+        "#;
+        let expected = r#"query Q($id: ID!, $skipNode: Boolean!, $ids: [ID!]!) @dir(ids: [$ids]) {
+  node(id: $id) {
+    ... on Node @skip(if: $skipNode) {
+      id
+    }
+  }
+}
+"#;
+        similar_asserts::assert_eq!(expected, print_flattened(query, "{}"));
+    }
+
+    #[test]
+    fn test_removes_redundant_inline_fragments() {
+        let query = r#"
+            {
+              viewer {
+                ... on User {
+                  id
+                  ... @include(if: true) { name }
+                }
+                friends { ... on User { name } }
+              }
+            }
+        "#;
+        let expected = r#"query {
+  viewer {
+    id
+    name
+    friends {
+      name
+    }
+  }
+}
+"#;
+        similar_asserts::assert_eq!(expected, print_flattened(query, "{}"));
+    }
+}
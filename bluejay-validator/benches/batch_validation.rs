@@ -0,0 +1,142 @@
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use bluejay_validator::executable::{
+    document::{BuiltinRules, BuiltinRulesValidator, DocumentBatch},
+    Cache, SchemaCache,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use once_cell::sync::Lazy;
+
+const SCHEMA: &str = r#"
+enum DogCommand { SIT, DOWN, HEEL }
+
+interface Pet {
+  name: String!
+}
+
+type Dog implements Pet {
+  name: String!
+  nickname: String
+  barkVolume: Int
+  doesKnowCommand(dogCommand: DogCommand!): Boolean!
+  owner: Human
+}
+
+type Cat implements Pet {
+  name: String!
+  meowVolume: Int
+}
+
+type Human {
+  name: String!
+  dogs(first: Int = 10): [Dog!]
+  pets: [Pet!]
+}
+
+type Query {
+  dog(name: String): Dog
+  human: Human
+}
+"#;
+
+const DOCUMENT_COUNT: usize = 1000;
+
+static DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> = Lazy::new(|| {
+    DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors")
+});
+static SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+    Lazy::new(|| SchemaDefinition::try_from(&*DEFINITION_DOCUMENT).expect("Schema had errors"));
+
+/// Persisted-query-like documents that differ from one another by their names and arguments
+static SOURCES: Lazy<Vec<String>> = Lazy::new(|| {
+    (0..DOCUMENT_COUNT)
+        .map(|i| {
+            format!(
+                r#"
+                query Dogs{i}($command: DogCommand!, $first: Int = {i}) {{
+                  dog(name: "dog{i}") {{
+                    ...dogFields{i}
+                    doesKnowCommand(dogCommand: $command)
+                    owner {{ name dogs(first: $first) {{ ...dogFields{i} }} }}
+                  }}
+                  human {{
+                    pets {{ name ... on Dog {{ barkVolume }} ... on Cat {{ meowVolume }} }}
+                  }}
+                }}
+
+                fragment dogFields{i} on Dog {{
+                  name
+                  nickname
+                  barkVolume
+                  ... on Pet {{ name }}
+                }}
+                "#
+            )
+        })
+        .collect()
+});
+static EXECUTABLE_DOCUMENTS: Lazy<Vec<ExecutableDocument<'static>>> = Lazy::new(|| {
+    SOURCES
+        .iter()
+        .map(|source| {
+            ExecutableDocument::parse(source)
+                .result
+                .expect("Document had parse errors")
+        })
+        .collect()
+});
+
+fn batch_validation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_validation");
+    let executable_documents = EXECUTABLE_DOCUMENTS.as_slice();
+    let schema_definition = &*SCHEMA_DEFINITION;
+
+    group.bench_function(BenchmarkId::new("sequential", DOCUMENT_COUNT), |b| {
+        b.iter(|| {
+            executable_documents
+                .iter()
+                .map(|executable_document| {
+                    let cache = Cache::new(executable_document, schema_definition);
+                    BuiltinRulesValidator::validate(executable_document, schema_definition, &cache)
+                        .count()
+                })
+                .sum::<usize>()
+        });
+    });
+
+    let schema_cache = SchemaCache::new(schema_definition);
+    group.bench_function(BenchmarkId::new("document_batch", DOCUMENT_COUNT), |b| {
+        b.iter(|| {
+            let batch = DocumentBatch::new(executable_documents, schema_definition, &schema_cache);
+            batch
+                .validate::<BuiltinRules<_, _>>()
+                .iter()
+                .map(Vec::len)
+                .sum::<usize>()
+        });
+    });
+
+    group.bench_function(
+        BenchmarkId::new("parallel_document_batch", DOCUMENT_COUNT),
+        |b| {
+            b.iter(|| {
+                let batch =
+                    DocumentBatch::par_new(executable_documents, schema_definition, &schema_cache);
+                batch
+                    .par_validate::<BuiltinRules<_, _>>()
+                    .iter()
+                    .map(Vec::len)
+                    .sum::<usize>()
+            });
+        },
+    );
+    group.finish();
+}
+
+criterion_group!(benches, batch_validation);
+criterion_main!(benches);
@@ -0,0 +1,107 @@
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use bluejay_validator::executable::{
+    document::{BuiltinRule, BuiltinRulesValidator, RuleSet},
+    Cache,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use once_cell::sync::Lazy;
+
+const SCHEMA: &str = r#"
+enum DogCommand { SIT, DOWN, HEEL }
+
+type Dog {
+  name: String!
+  nickname: String
+  barkVolume: Int
+  doesKnowCommand(dogCommand: DogCommand!): Boolean!
+  owner: Human
+}
+
+type Human {
+  name: String!
+  dogs(first: Int = 10): [Dog!]
+}
+
+type Query {
+  dog(name: String): Dog
+  human: Human
+}
+"#;
+
+const QUERY: &str = r#"
+query Dogs($name: String, $command: DogCommand!, $first: Int) {
+  dog(name: $name) {
+    ...dogFields
+    doesKnowCommand(dogCommand: $command)
+    owner @include(if: true) {
+      name
+      dogs(first: $first) { ...dogFields }
+    }
+  }
+  human { name dogs { nickname } }
+}
+
+fragment dogFields on Dog {
+  name
+  nickname
+  barkVolume
+  ... on Dog { owner { name } }
+}
+"#;
+
+static DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> = Lazy::new(|| {
+    DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors")
+});
+static SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+    Lazy::new(|| SchemaDefinition::try_from(&*DEFINITION_DOCUMENT).expect("Schema had errors"));
+static EXECUTABLE_DOCUMENT: Lazy<ExecutableDocument<'static>> = Lazy::new(|| {
+    ExecutableDocument::parse(QUERY)
+        .result
+        .expect("Document had parse errors")
+});
+
+fn dynamic_rules(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dynamic_rules");
+    let executable_document = &*EXECUTABLE_DOCUMENT;
+    let schema_definition = &*SCHEMA_DEFINITION;
+
+    group.bench_function("static_builtin_rules", |b| {
+        b.iter(|| {
+            let cache = Cache::new(executable_document, schema_definition);
+            let _ = BuiltinRulesValidator::validate(executable_document, schema_definition, &cache)
+                .collect::<Vec<_>>();
+        });
+    });
+
+    let rule_sets = [
+        ("all", RuleSet::all()),
+        (
+            "without_field_selection_merging",
+            RuleSet::all().without_rule(BuiltinRule::FieldSelectionMerging),
+        ),
+    ];
+    for (name, rule_set) in &rule_sets {
+        group.bench_with_input(
+            BenchmarkId::new("rule_set", name),
+            rule_set,
+            |b, rule_set| {
+                b.iter(|| {
+                    let cache = Cache::new(executable_document, schema_definition);
+                    let _ = rule_set
+                        .validate(executable_document, schema_definition, &cache)
+                        .collect::<Vec<_>>();
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, dynamic_rules);
+criterion_main!(benches);
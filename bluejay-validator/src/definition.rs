@@ -1,4 +1,5 @@
 mod error;
+pub mod lint;
 mod rule;
 mod rules;
 mod validator;
@@ -8,4 +9,4 @@ pub use error::Error;
 pub use rule::Rule;
 pub use rules::BuiltinRules;
 pub use validator::{BuiltinRulesValidator, Validator};
-pub use visitor::Visitor;
+pub use visitor::{ArgumentDefinitionParent, Visitor};
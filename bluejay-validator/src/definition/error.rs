@@ -1,4 +1,10 @@
-use bluejay_core::definition::{InputObjectTypeDefinition, SchemaDefinition};
+use crate::value::input_coercion::Error as InputCoercionError;
+use bluejay_core::definition::{
+    DirectiveDefinition, EnumValueDefinition, FieldDefinition, InputObjectTypeDefinition,
+    InputType, InputValueDefinition, InterfaceImplementation, InterfaceTypeDefinition, OutputType,
+    SchemaDefinition, TypeDefinitionReference, UnionTypeDefinition,
+};
+use bluejay_core::Directive;
 
 #[cfg(feature = "parser-integration")]
 use bluejay_parser::{
@@ -20,8 +26,89 @@ pub enum Error<'a, S: SchemaDefinition> {
         input_object_type_definition: &'a S::InputObjectTypeDefinition,
         circular_references: Vec<&'a S::InputType>,
     },
+    NonUniqueFieldDefinitionNames {
+        name: &'a str,
+        field_definitions: Vec<&'a S::FieldDefinition>,
+    },
+    NonUniqueArgumentDefinitionNames {
+        name: &'a str,
+        argument_definitions: Vec<&'a S::InputValueDefinition>,
+    },
+    NonUniqueUnionMemberTypes {
+        name: &'a str,
+        union_type_definition: &'a S::UnionTypeDefinition,
+        union_member_types: Vec<&'a S::UnionMemberType>,
+    },
+    NonUniqueInterfaceImplementations {
+        name: &'a str,
+        interface_implementations: Vec<&'a S::InterfaceImplementation>,
+    },
+    InterfaceImplementationSelfReference {
+        interface_type_definition: &'a S::InterfaceTypeDefinition,
+        interface_implementation: &'a S::InterfaceImplementation,
+    },
+    InterfaceImplementationMissingTransitiveInterface {
+        implementor_name: &'a str,
+        interface_implementation: &'a S::InterfaceImplementation,
+        missing_interface_name: &'a str,
+    },
+    InterfaceImplementationMissingField {
+        implementor_name: &'a str,
+        interface_implementation: &'a S::InterfaceImplementation,
+        interface_field_definition: &'a S::FieldDefinition,
+    },
+    InterfaceImplementationFieldTypeNotCovariant {
+        implementor_name: &'a str,
+        interface_implementation: &'a S::InterfaceImplementation,
+        field_definition: &'a S::FieldDefinition,
+        interface_field_definition: &'a S::FieldDefinition,
+    },
+    InterfaceImplementationMissingArgument {
+        implementor_name: &'a str,
+        interface_implementation: &'a S::InterfaceImplementation,
+        field_definition: &'a S::FieldDefinition,
+        interface_argument_definition: &'a S::InputValueDefinition,
+    },
+    InterfaceImplementationArgumentTypeMismatch {
+        implementor_name: &'a str,
+        interface_implementation: &'a S::InterfaceImplementation,
+        field_definition: &'a S::FieldDefinition,
+        argument_definition: &'a S::InputValueDefinition,
+        interface_argument_definition: &'a S::InputValueDefinition,
+    },
+    InterfaceImplementationAdditionalArgumentRequired {
+        implementor_name: &'a str,
+        interface_implementation: &'a S::InterfaceImplementation,
+        field_definition: &'a S::FieldDefinition,
+        argument_definition: &'a S::InputValueDefinition,
+    },
+    ReservedTypeDefinitionName {
+        type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
+    },
+    ReservedFieldDefinitionName {
+        field_definition: &'a S::FieldDefinition,
+    },
+    ReservedInputValueDefinitionName {
+        input_value_definition: &'a S::InputValueDefinition,
+    },
+    ReservedEnumValueDefinitionName {
+        enum_value_definition: &'a S::EnumValueDefinition,
+    },
+    ReservedDirectiveDefinitionName {
+        directive_definition: &'a S::DirectiveDefinition,
+    },
+    DirectiveDefinitionCircularReferences {
+        directive_definition: &'a S::DirectiveDefinition,
+        circular_references: Vec<&'a S::Directive>,
+    },
+    InvalidDefaultValue(
+        InputCoercionError<'a, true, <S::InputValueDefinition as InputValueDefinition>::Value>,
+    ),
 }
 
+#[cfg(feature = "parser-integration")]
+const RESERVED_NAME_ANNOTATION: &str = "Names beginning with `__` are reserved for introspection";
+
 #[cfg(feature = "parser-integration")]
 impl<'a> From<Error<'a, ParserSchemaDefinition<'a>>> for ParserError {
     fn from(value: Error<'a, ParserSchemaDefinition<'a>>) -> Self {
@@ -82,6 +169,307 @@ impl<'a> From<Error<'a, ParserSchemaDefinition<'a>>> for ParserError {
                     .collect(),
             )
             }
+            Error::NonUniqueFieldDefinitionNames {
+                name,
+                field_definitions,
+            } => Self::new(
+                format!("Multiple field definitions named `{name}`"),
+                None,
+                field_definitions
+                    .into_iter()
+                    .map(|fd| {
+                        Annotation::new(
+                            format!("Field definition with name `{name}`"),
+                            fd.name_token().span().clone(),
+                        )
+                    })
+                    .collect(),
+            ),
+            Error::NonUniqueArgumentDefinitionNames {
+                name,
+                argument_definitions,
+            } => Self::new(
+                format!("Multiple argument definitions named `{name}`"),
+                None,
+                argument_definitions
+                    .into_iter()
+                    .map(|ivd| {
+                        Annotation::new(
+                            format!("Argument definition with name `{name}`"),
+                            ivd.name_token().span().clone(),
+                        )
+                    })
+                    .collect(),
+            ),
+            Error::NonUniqueUnionMemberTypes {
+                name,
+                union_type_definition,
+                union_member_types,
+            } => Self::new(
+                format!(
+                    "Union type definition `{}` includes member type `{name}` multiple times",
+                    union_type_definition.name()
+                ),
+                None,
+                union_member_types
+                    .into_iter()
+                    .map(|umt| {
+                        Annotation::new(
+                            format!("Member type `{name}`"),
+                            umt.name_token().span().clone(),
+                        )
+                    })
+                    .collect(),
+            ),
+            Error::NonUniqueInterfaceImplementations {
+                name,
+                interface_implementations,
+            } => Self::new(
+                format!("Interface `{name}` is implemented multiple times"),
+                None,
+                interface_implementations
+                    .into_iter()
+                    .map(|ii| {
+                        Annotation::new(
+                            format!("Implementation of interface `{name}`"),
+                            ii.name_token().span().clone(),
+                        )
+                    })
+                    .collect(),
+            ),
+            Error::InterfaceImplementationSelfReference {
+                interface_type_definition,
+                interface_implementation,
+            } => Self::new(
+                format!(
+                    "Interface type definition `{}` cannot implement itself",
+                    interface_type_definition.name()
+                ),
+                Some(Annotation::new(
+                    "Implementation of the interface being defined",
+                    interface_implementation.name_token().span().clone(),
+                )),
+                Vec::new(),
+            ),
+            Error::InterfaceImplementationMissingTransitiveInterface {
+                implementor_name,
+                interface_implementation,
+                missing_interface_name,
+            } => Self::new(
+                format!(
+                    "`{implementor_name}` must implement `{missing_interface_name}` because it is implemented by `{}`",
+                    interface_implementation.name()
+                ),
+                Some(Annotation::new(
+                    format!(
+                        "`{}` implements `{missing_interface_name}`",
+                        interface_implementation.name()
+                    ),
+                    interface_implementation.name_token().span().clone(),
+                )),
+                Vec::new(),
+            ),
+            Error::InterfaceImplementationMissingField {
+                implementor_name,
+                interface_implementation,
+                interface_field_definition,
+            } => Self::new(
+                format!(
+                    "`{implementor_name}` does not define field `{}` required by interface `{}`",
+                    interface_field_definition.name(),
+                    interface_implementation.name()
+                ),
+                Some(Annotation::new(
+                    format!("Missing field `{}`", interface_field_definition.name()),
+                    interface_implementation.name_token().span().clone(),
+                )),
+                vec![Annotation::new(
+                    "Field defined on interface",
+                    interface_field_definition.name_token().span().clone(),
+                )],
+            ),
+            Error::InterfaceImplementationFieldTypeNotCovariant {
+                implementor_name,
+                interface_implementation,
+                field_definition,
+                interface_field_definition,
+            } => Self::new(
+                format!(
+                    "Type `{}` of field `{implementor_name}.{}` is not a valid implementation of type `{}` of field `{}.{}`",
+                    field_definition.r#type().display_name(),
+                    field_definition.name(),
+                    interface_field_definition.r#type().display_name(),
+                    interface_implementation.name(),
+                    interface_field_definition.name(),
+                ),
+                Some(Annotation::new(
+                    "Field type is not equal to or a subtype of the interface field type",
+                    field_definition.r#type().span().clone(),
+                )),
+                vec![Annotation::new(
+                    "Interface field type",
+                    interface_field_definition.r#type().span().clone(),
+                )],
+            ),
+            Error::InterfaceImplementationMissingArgument {
+                implementor_name,
+                interface_implementation,
+                field_definition,
+                interface_argument_definition,
+            } => Self::new(
+                format!(
+                    "Field `{implementor_name}.{}` does not define argument `{}` required by interface `{}`",
+                    field_definition.name(),
+                    interface_argument_definition.name(),
+                    interface_implementation.name(),
+                ),
+                Some(Annotation::new(
+                    format!("Missing argument `{}`", interface_argument_definition.name()),
+                    field_definition.name_token().span().clone(),
+                )),
+                vec![Annotation::new(
+                    "Argument defined on interface field",
+                    interface_argument_definition.name_token().span().clone(),
+                )],
+            ),
+            Error::InterfaceImplementationArgumentTypeMismatch {
+                implementor_name,
+                interface_implementation,
+                field_definition,
+                argument_definition,
+                interface_argument_definition,
+            } => Self::new(
+                format!(
+                    "Type `{}` of argument `{}` on field `{implementor_name}.{}` does not match type `{}` defined by interface `{}`",
+                    argument_definition.r#type().display_name(),
+                    argument_definition.name(),
+                    field_definition.name(),
+                    interface_argument_definition.r#type().display_name(),
+                    interface_implementation.name(),
+                ),
+                Some(Annotation::new(
+                    "Argument type must be identical to the interface argument type",
+                    argument_definition.r#type().span().clone(),
+                )),
+                vec![Annotation::new(
+                    "Interface argument type",
+                    interface_argument_definition.r#type().span().clone(),
+                )],
+            ),
+            Error::InterfaceImplementationAdditionalArgumentRequired {
+                implementor_name,
+                interface_implementation,
+                field_definition,
+                argument_definition,
+            } => Self::new(
+                format!(
+                    "Argument `{}` on field `{implementor_name}.{}` must not be required because it is not defined by interface `{}`",
+                    argument_definition.name(),
+                    field_definition.name(),
+                    interface_implementation.name(),
+                ),
+                Some(Annotation::new(
+                    "Additional argument is required",
+                    argument_definition.name_token().span().clone(),
+                )),
+                Vec::new(),
+            ),
+            Error::ReservedTypeDefinitionName { type_definition } => Self::new(
+                format!(
+                    "Type definition name `{}` is reserved for introspection",
+                    type_definition.name()
+                ),
+                match type_definition {
+                    TypeDefinitionReference::BuiltinScalar(_) => None,
+                    TypeDefinitionReference::CustomScalar(cstd) => {
+                        Some(cstd.name_token().span().clone())
+                    }
+                    TypeDefinitionReference::Object(otd) => Some(otd.name_token().span().clone()),
+                    TypeDefinitionReference::InputObject(iotd) => {
+                        Some(iotd.name_token().span().clone())
+                    }
+                    TypeDefinitionReference::Enum(etd) => Some(etd.name_token().span().clone()),
+                    TypeDefinitionReference::Union(utd) => Some(utd.name_token().span().clone()),
+                    TypeDefinitionReference::Interface(itd) => {
+                        Some(itd.name_token().span().clone())
+                    }
+                }
+                .map(|span| Annotation::new(RESERVED_NAME_ANNOTATION, span)),
+                Vec::new(),
+            ),
+            Error::ReservedFieldDefinitionName { field_definition } => Self::new(
+                format!(
+                    "Field definition name `{}` is reserved for introspection",
+                    field_definition.name()
+                ),
+                Some(Annotation::new(
+                    RESERVED_NAME_ANNOTATION,
+                    field_definition.name_token().span().clone(),
+                )),
+                Vec::new(),
+            ),
+            Error::ReservedInputValueDefinitionName {
+                input_value_definition,
+            } => Self::new(
+                format!(
+                    "Input value definition name `{}` is reserved for introspection",
+                    input_value_definition.name()
+                ),
+                Some(Annotation::new(
+                    RESERVED_NAME_ANNOTATION,
+                    input_value_definition.name_token().span().clone(),
+                )),
+                Vec::new(),
+            ),
+            Error::ReservedEnumValueDefinitionName {
+                enum_value_definition,
+            } => Self::new(
+                format!(
+                    "Enum value definition name `{}` is reserved for introspection",
+                    enum_value_definition.name()
+                ),
+                Some(Annotation::new(
+                    RESERVED_NAME_ANNOTATION,
+                    enum_value_definition.name_token().span().clone(),
+                )),
+                Vec::new(),
+            ),
+            Error::ReservedDirectiveDefinitionName {
+                directive_definition,
+            } => Self::new(
+                format!(
+                    "Directive definition name `{}` is reserved for introspection",
+                    directive_definition.name()
+                ),
+                Some(Annotation::new(
+                    RESERVED_NAME_ANNOTATION,
+                    directive_definition.name_token().span().clone(),
+                )),
+                Vec::new(),
+            ),
+            Error::DirectiveDefinitionCircularReferences {
+                directive_definition,
+                circular_references,
+            } => Self::new(
+                format!(
+                    "Directive definition `{}` contains disallowed circular reference(s)",
+                    directive_definition.name()
+                ),
+                Some(Annotation::new(
+                    "Directive definition references itself directly or through the types and directives it references",
+                    directive_definition.name_token().span().clone(),
+                )),
+                circular_references
+                    .into_iter()
+                    .map(|directive| {
+                        Annotation::new(
+                            format!("Occurence of `@{}`", directive.name()),
+                            directive.span().clone(),
+                        )
+                    })
+                    .collect(),
+            ),
+            Error::InvalidDefaultValue(error) => Self::from(error),
         }
     }
 }
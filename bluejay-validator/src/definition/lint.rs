@@ -0,0 +1,25 @@
+//! Style linting for schema definitions, run on top of the spec [validation](super::Validator).
+//!
+//! Each lint rule has a name used to configure its [`Severity`] or disable it through a
+//! [`LintConfig`]. Violations can be suppressed inline, either by applying the suppression
+//! directive (`@lintIgnore(rules: ["naming-conventions"])` by default, which must be defined in
+//! the schema) or by adding a line starting with the suppression marker (`lint-ignore` by
+//! default, optionally followed by `: rule-a, rule-b`) to the description of a definition.
+//! Suppressions on a type also apply to its fields, input fields and enum values, and
+//! suppressions on a field or directive definition also apply to its arguments.
+//! Omitting the rule names suppresses all rules.
+
+mod config;
+mod linter;
+mod node;
+mod rules;
+mod violation;
+
+pub use config::LintConfig;
+pub use linter::{BuiltinLintRulesLinter, Linter};
+pub use node::LintNode;
+pub use rules::{
+    BuiltinLintRules, DeprecationReasons, FieldDescriptions, MutationInputAndPayload,
+    NamingConventions, RelayConnections,
+};
+pub use violation::{LintViolation, Severity};
@@ -0,0 +1,70 @@
+use crate::definition::lint::Severity;
+use std::collections::HashMap;
+
+/// Configures which lint rules run, the severity they report with, and how violations are
+/// suppressed inline
+#[derive(Debug, Clone)]
+pub struct LintConfig {
+    severities: HashMap<String, Option<Severity>>,
+    suppression_directive_name: Option<String>,
+    suppression_marker: Option<String>,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            severities: HashMap::new(),
+            suppression_directive_name: Some(Self::DEFAULT_SUPPRESSION_DIRECTIVE_NAME.to_owned()),
+            suppression_marker: Some(Self::DEFAULT_SUPPRESSION_MARKER.to_owned()),
+        }
+    }
+}
+
+impl LintConfig {
+    pub const DEFAULT_SUPPRESSION_DIRECTIVE_NAME: &'static str = "lintIgnore";
+    pub const DEFAULT_SUPPRESSION_MARKER: &'static str = "lint-ignore";
+    /// The argument of the suppression directive listing the rules to suppress
+    pub const SUPPRESSION_DIRECTIVE_RULES_ARGUMENT_NAME: &'static str = "rules";
+
+    /// Overrides the default severity of the rule named `rule`
+    pub fn with_severity(mut self, rule: impl Into<String>, severity: Severity) -> Self {
+        self.severities.insert(rule.into(), Some(severity));
+        self
+    }
+
+    /// Disables the rule named `rule`
+    pub fn without_rule(mut self, rule: impl Into<String>) -> Self {
+        self.severities.insert(rule.into(), None);
+        self
+    }
+
+    /// Sets the name of the directive used to suppress violations, or `None` to disallow
+    /// suppressions through directives
+    pub fn with_suppression_directive_name(mut self, name: Option<impl Into<String>>) -> Self {
+        self.suppression_directive_name = name.map(Into::into);
+        self
+    }
+
+    /// Sets the description marker used to suppress violations, or `None` to disallow
+    /// suppressions through descriptions
+    pub fn with_suppression_marker(mut self, marker: Option<impl Into<String>>) -> Self {
+        self.suppression_marker = marker.map(Into::into);
+        self
+    }
+
+    /// Returns the severity to report violations of `rule` with, or `None` if the rule is disabled
+    pub fn severity(&self, rule: &str, default_severity: Severity) -> Option<Severity> {
+        self.severities
+            .get(rule)
+            .copied()
+            .unwrap_or(Some(default_severity))
+    }
+
+    pub(crate) fn suppression_directive_name(&self) -> Option<&str> {
+        self.suppression_directive_name.as_deref()
+    }
+
+    pub(crate) fn suppression_marker(&self) -> Option<&str> {
+        self.suppression_marker.as_deref()
+    }
+}
@@ -0,0 +1,87 @@
+use crate::definition::lint::{BuiltinLintRules, LintConfig, LintNode, LintViolation};
+use crate::definition::{Rule, Validator};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::{Argument, AsIter, Directive, Value, ValueReference};
+use std::marker::PhantomData;
+
+pub struct Linter<'a, S: SchemaDefinition, R: Rule<'a, S, Error = LintViolation<'a, S>>> {
+    schema_definition: PhantomData<&'a S>,
+    rule: PhantomData<R>,
+}
+
+pub type BuiltinLintRulesLinter<'a, S> = Linter<'a, S, BuiltinLintRules<'a, S>>;
+
+impl<'a, S: SchemaDefinition, R: Rule<'a, S, Error = LintViolation<'a, S>>> Linter<'a, S, R> {
+    /// Runs the lint rules `R` against `schema_definition`, applying the severities and
+    /// suppressions of `config`
+    pub fn lint(schema_definition: &'a S, config: &LintConfig) -> Vec<LintViolation<'a, S>> {
+        Validator::<'a, S, R>::validate(schema_definition)
+            .filter_map(|mut violation| {
+                let severity = config.severity(violation.rule(), violation.severity())?;
+                if Self::is_suppressed(config, violation.rule(), violation.node()) {
+                    return None;
+                }
+                violation.set_severity(severity);
+                Some(violation)
+            })
+            .collect()
+    }
+
+    fn is_suppressed(config: &LintConfig, rule: &str, node: LintNode<'a, S>) -> bool {
+        Self::is_suppressed_by_directive(config, rule, node)
+            || Self::is_suppressed_by_description(config, rule, node)
+            || node
+                .parent()
+                .is_some_and(|parent| Self::is_suppressed(config, rule, parent))
+    }
+
+    fn is_suppressed_by_directive(config: &LintConfig, rule: &str, node: LintNode<'a, S>) -> bool {
+        let Some(directive_name) = config.suppression_directive_name() else {
+            return false;
+        };
+        node.directives()
+            .into_iter()
+            .flat_map(|directives| directives.iter())
+            .filter(|directive| directive.name() == directive_name)
+            .any(|directive| {
+                match directive.arguments().and_then(|arguments| {
+                    arguments.iter().find(|argument| {
+                        argument.name() == LintConfig::SUPPRESSION_DIRECTIVE_RULES_ARGUMENT_NAME
+                    })
+                }) {
+                    Some(argument) => match argument.value().as_ref() {
+                        ValueReference::List(rules) => rules
+                            .iter()
+                            .any(|value| matches!(value.as_ref(), ValueReference::String(s) if s == rule)),
+                        ValueReference::String(s) => s == rule,
+                        ValueReference::Null => true,
+                        _ => false,
+                    },
+                    None => true,
+                }
+            })
+    }
+
+    fn is_suppressed_by_description(
+        config: &LintConfig,
+        rule: &str,
+        node: LintNode<'a, S>,
+    ) -> bool {
+        let (Some(marker), Some(description)) = (config.suppression_marker(), node.description())
+        else {
+            return false;
+        };
+        description.lines().any(|line| {
+            line.trim().strip_prefix(marker).is_some_and(|rest| {
+                let rest = rest.trim();
+                if rest.is_empty() {
+                    true
+                } else if let Some(rules) = rest.strip_prefix(':') {
+                    rules.split(',').any(|r| r.trim() == rule)
+                } else {
+                    false
+                }
+            })
+        })
+    }
+}
@@ -0,0 +1,181 @@
+use crate::definition::ArgumentDefinitionParent;
+use bluejay_core::definition::{
+    DirectiveDefinition, EnumValueDefinition, FieldDefinition, HasDirectives, InputValueDefinition,
+    SchemaDefinition, TypeDefinitionReference,
+};
+
+#[cfg(feature = "parser-integration")]
+use bluejay_parser::{ast::definition::SchemaDefinition as ParserSchemaDefinition, HasSpan, Span};
+
+/// A definition within a schema that a lint rule can report on
+pub enum LintNode<'a, S: SchemaDefinition> {
+    TypeDefinition(TypeDefinitionReference<'a, S::TypeDefinition>),
+    FieldDefinition {
+        field_definition: &'a S::FieldDefinition,
+        parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    },
+    ArgumentDefinition {
+        argument_definition: &'a S::InputValueDefinition,
+        parent: ArgumentDefinitionParent<'a, S>,
+    },
+    InputFieldDefinition {
+        input_field_definition: &'a S::InputValueDefinition,
+        input_object_type_definition: &'a S::InputObjectTypeDefinition,
+    },
+    EnumValueDefinition {
+        enum_value_definition: &'a S::EnumValueDefinition,
+        enum_type_definition: &'a S::EnumTypeDefinition,
+    },
+    DirectiveDefinition(&'a S::DirectiveDefinition),
+}
+
+impl<S: SchemaDefinition> Clone for LintNode<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: SchemaDefinition> Copy for LintNode<'_, S> {}
+
+impl<'a, S: SchemaDefinition> LintNode<'a, S> {
+    pub fn name(&self) -> &'a str {
+        match self {
+            Self::TypeDefinition(type_definition) => type_definition.name(),
+            Self::FieldDefinition {
+                field_definition, ..
+            } => field_definition.name(),
+            Self::ArgumentDefinition {
+                argument_definition,
+                ..
+            } => argument_definition.name(),
+            Self::InputFieldDefinition {
+                input_field_definition,
+                ..
+            } => input_field_definition.name(),
+            Self::EnumValueDefinition {
+                enum_value_definition,
+                ..
+            } => enum_value_definition.name(),
+            Self::DirectiveDefinition(directive_definition) => directive_definition.name(),
+        }
+    }
+
+    pub fn description(&self) -> Option<&'a str> {
+        match self {
+            Self::TypeDefinition(type_definition) => type_definition.description(),
+            Self::FieldDefinition {
+                field_definition, ..
+            } => field_definition.description(),
+            Self::ArgumentDefinition {
+                argument_definition,
+                ..
+            } => argument_definition.description(),
+            Self::InputFieldDefinition {
+                input_field_definition,
+                ..
+            } => input_field_definition.description(),
+            Self::EnumValueDefinition {
+                enum_value_definition,
+                ..
+            } => enum_value_definition.description(),
+            Self::DirectiveDefinition(directive_definition) => directive_definition.description(),
+        }
+    }
+
+    pub fn directives(&self) -> Option<&'a <S as SchemaDefinition>::Directives> {
+        match self {
+            Self::TypeDefinition(type_definition) => match type_definition {
+                TypeDefinitionReference::BuiltinScalar(_) => None,
+                TypeDefinitionReference::CustomScalar(cstd) => cstd.directives(),
+                TypeDefinitionReference::Object(otd) => otd.directives(),
+                TypeDefinitionReference::InputObject(iotd) => iotd.directives(),
+                TypeDefinitionReference::Enum(etd) => etd.directives(),
+                TypeDefinitionReference::Union(utd) => utd.directives(),
+                TypeDefinitionReference::Interface(itd) => itd.directives(),
+            },
+            Self::FieldDefinition {
+                field_definition, ..
+            } => field_definition.directives(),
+            Self::ArgumentDefinition {
+                argument_definition,
+                ..
+            } => argument_definition.directives(),
+            Self::InputFieldDefinition {
+                input_field_definition,
+                ..
+            } => input_field_definition.directives(),
+            Self::EnumValueDefinition {
+                enum_value_definition,
+                ..
+            } => enum_value_definition.directives(),
+            Self::DirectiveDefinition(_) => None,
+        }
+    }
+
+    /// The definition containing this node, if any
+    pub fn parent(&self) -> Option<Self> {
+        match self {
+            Self::FieldDefinition { parent_type, .. } => Some(Self::TypeDefinition(*parent_type)),
+            Self::ArgumentDefinition { parent, .. } => Some(match parent {
+                ArgumentDefinitionParent::FieldDefinition {
+                    field_definition,
+                    parent_type,
+                } => Self::FieldDefinition {
+                    field_definition,
+                    parent_type: *parent_type,
+                },
+                ArgumentDefinitionParent::DirectiveDefinition(directive_definition) => {
+                    Self::DirectiveDefinition(directive_definition)
+                }
+            }),
+            Self::InputFieldDefinition {
+                input_object_type_definition,
+                ..
+            } => Some(Self::TypeDefinition(TypeDefinitionReference::InputObject(
+                *input_object_type_definition,
+            ))),
+            Self::EnumValueDefinition {
+                enum_type_definition,
+                ..
+            } => Some(Self::TypeDefinition(TypeDefinitionReference::Enum(
+                *enum_type_definition,
+            ))),
+            Self::TypeDefinition(_) | Self::DirectiveDefinition(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "parser-integration")]
+impl<'a> LintNode<'a, ParserSchemaDefinition<'a>> {
+    pub(crate) fn name_span(&self) -> Option<&'a Span> {
+        match self {
+            Self::TypeDefinition(type_definition) => match type_definition {
+                TypeDefinitionReference::BuiltinScalar(_) => None,
+                TypeDefinitionReference::CustomScalar(cstd) => Some(cstd.name_token().span()),
+                TypeDefinitionReference::Object(otd) => Some(otd.name_token().span()),
+                TypeDefinitionReference::InputObject(iotd) => Some(iotd.name_token().span()),
+                TypeDefinitionReference::Enum(etd) => Some(etd.name_token().span()),
+                TypeDefinitionReference::Union(utd) => Some(utd.name_token().span()),
+                TypeDefinitionReference::Interface(itd) => Some(itd.name_token().span()),
+            },
+            Self::FieldDefinition {
+                field_definition, ..
+            } => Some(field_definition.name_token().span()),
+            Self::ArgumentDefinition {
+                argument_definition,
+                ..
+            } => Some(argument_definition.name_token().span()),
+            Self::InputFieldDefinition {
+                input_field_definition,
+                ..
+            } => Some(input_field_definition.name_token().span()),
+            Self::EnumValueDefinition {
+                enum_value_definition,
+                ..
+            } => Some(enum_value_definition.name_token().span()),
+            Self::DirectiveDefinition(directive_definition) => {
+                Some(directive_definition.name_token().span())
+            }
+        }
+    }
+}
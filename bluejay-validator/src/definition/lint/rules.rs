@@ -0,0 +1,23 @@
+mod deprecation_reasons;
+mod field_descriptions;
+mod mutation_input_and_payload;
+mod naming_conventions;
+mod relay_connections;
+
+pub use deprecation_reasons::DeprecationReasons;
+pub use field_descriptions::FieldDescriptions;
+pub use mutation_input_and_payload::MutationInputAndPayload;
+pub use naming_conventions::NamingConventions;
+pub use relay_connections::RelayConnections;
+
+crate::combine_definition_rules!(
+    BuiltinLintRules,
+    crate::definition::lint::LintViolation,
+    [
+        DeprecationReasons,
+        FieldDescriptions,
+        MutationInputAndPayload,
+        NamingConventions,
+        RelayConnections,
+    ],
+);
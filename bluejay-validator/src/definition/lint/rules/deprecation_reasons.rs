@@ -0,0 +1,112 @@
+use crate::definition::lint::{LintNode, LintViolation, Severity};
+use crate::definition::{ArgumentDefinitionParent, Rule, Visitor};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::{Argument, AsIter, Directive, Value, ValueReference};
+
+/// Usages of `@deprecated` must specify a non-blank `reason`
+pub struct DeprecationReasons<'a, S: SchemaDefinition + 'a> {
+    violations: Vec<LintViolation<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for DeprecationReasons<'a, S> {
+    fn visit_field_definition(
+        &mut self,
+        field_definition: &'a <S as SchemaDefinition>::FieldDefinition,
+        parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        if !parent_type.is_builtin() {
+            self.visit_node(LintNode::FieldDefinition {
+                field_definition,
+                parent_type,
+            });
+        }
+    }
+
+    fn visit_argument_definition(
+        &mut self,
+        argument_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+        parent: ArgumentDefinitionParent<'a, S>,
+    ) {
+        self.visit_node(LintNode::ArgumentDefinition {
+            argument_definition,
+            parent,
+        });
+    }
+
+    fn visit_input_field_definition(
+        &mut self,
+        input_field_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+        input_object_type_definition: &'a <S as SchemaDefinition>::InputObjectTypeDefinition,
+    ) {
+        self.visit_node(LintNode::InputFieldDefinition {
+            input_field_definition,
+            input_object_type_definition,
+        });
+    }
+
+    fn visit_enum_value_definition(
+        &mut self,
+        enum_value_definition: &'a <S as SchemaDefinition>::EnumValueDefinition,
+        enum_type_definition: &'a <S as SchemaDefinition>::EnumTypeDefinition,
+    ) {
+        self.visit_node(LintNode::EnumValueDefinition {
+            enum_value_definition,
+            enum_type_definition,
+        });
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> DeprecationReasons<'a, S> {
+    const NAME: &'static str = "deprecation-reasons";
+    const DEPRECATED_DIRECTIVE_NAME: &'static str = "deprecated";
+    const REASON_ARGUMENT_NAME: &'static str = "reason";
+
+    fn visit_node(&mut self, node: LintNode<'a, S>) {
+        let missing_reason = node
+            .directives()
+            .into_iter()
+            .flat_map(|directives| directives.iter())
+            .filter(|directive| directive.name() == Self::DEPRECATED_DIRECTIVE_NAME)
+            .any(|directive| {
+                !directive
+                    .arguments()
+                    .into_iter()
+                    .flat_map(|arguments| arguments.iter())
+                    .any(|argument| {
+                        argument.name() == Self::REASON_ARGUMENT_NAME
+                            && matches!(
+                                argument.value().as_ref(),
+                                ValueReference::String(reason) if !reason.trim().is_empty(),
+                            )
+                    })
+            });
+
+        if missing_reason {
+            self.violations.push(LintViolation::new(
+                Self::NAME,
+                Severity::Warning,
+                node,
+                format!("Deprecation of `{}` should specify a reason", node.name()),
+            ));
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for DeprecationReasons<'a, S> {
+    type Item = LintViolation<'a, S>;
+    type IntoIter = std::vec::IntoIter<LintViolation<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.violations.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for DeprecationReasons<'a, S> {
+    type Error = LintViolation<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::definition::lint::{LintNode, LintViolation, Severity};
+use crate::definition::{Rule, Visitor};
+use bluejay_core::definition::{FieldDefinition, SchemaDefinition, TypeDefinitionReference};
+
+/// Every field of an object or interface type must have a non-blank description
+pub struct FieldDescriptions<'a, S: SchemaDefinition + 'a> {
+    violations: Vec<LintViolation<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for FieldDescriptions<'a, S> {
+    fn visit_field_definition(
+        &mut self,
+        field_definition: &'a <S as SchemaDefinition>::FieldDefinition,
+        parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        if field_definition.is_builtin() || parent_type.is_builtin() {
+            return;
+        }
+
+        if field_definition
+            .description()
+            .is_none_or(|description| description.trim().is_empty())
+        {
+            self.violations.push(LintViolation::new(
+                Self::NAME,
+                Severity::Warning,
+                LintNode::FieldDefinition {
+                    field_definition,
+                    parent_type,
+                },
+                format!(
+                    "Field `{}.{}` should have a description",
+                    parent_type.name(),
+                    field_definition.name(),
+                ),
+            ));
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> FieldDescriptions<'a, S> {
+    const NAME: &'static str = "field-descriptions";
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for FieldDescriptions<'a, S> {
+    type Item = LintViolation<'a, S>;
+    type IntoIter = std::vec::IntoIter<LintViolation<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.violations.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for FieldDescriptions<'a, S> {
+    type Error = LintViolation<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+}
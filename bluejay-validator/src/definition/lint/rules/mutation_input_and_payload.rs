@@ -0,0 +1,112 @@
+use crate::definition::lint::{LintNode, LintViolation, Severity};
+use crate::definition::{Rule, Visitor};
+use bluejay_core::definition::{
+    BaseInputTypeReference, BaseOutputTypeReference, FieldDefinition, InputType,
+    InputTypeReference, InputValueDefinition, ObjectTypeDefinition, OutputType,
+    OutputTypeReference, SchemaDefinition, TypeDefinitionReference,
+};
+use bluejay_core::AsIter;
+
+/// Fields of the mutation root type must take a single non-null input object argument named
+/// `input` and return an object type whose name ends with `Payload`
+pub struct MutationInputAndPayload<'a, S: SchemaDefinition + 'a> {
+    schema_definition: &'a S,
+    violations: Vec<LintViolation<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for MutationInputAndPayload<'a, S> {
+    fn visit_field_definition(
+        &mut self,
+        field_definition: &'a <S as SchemaDefinition>::FieldDefinition,
+        parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        let is_mutation_field = parent_type.as_object().is_some_and(|otd| {
+            self.schema_definition
+                .mutation()
+                .is_some_and(|mutation| mutation.name() == otd.name())
+        });
+        if !is_mutation_field || field_definition.is_builtin() {
+            return;
+        }
+
+        let node = LintNode::FieldDefinition {
+            field_definition,
+            parent_type,
+        };
+        let field_name = field_definition.name();
+
+        let has_valid_input =
+            field_definition
+                .arguments_definition()
+                .is_some_and(|arguments_definition| {
+                    let mut arguments = arguments_definition.iter();
+                    match (arguments.next(), arguments.next()) {
+                        (Some(argument_definition), None) => {
+                            argument_definition.name() == Self::INPUT_ARGUMENT_NAME
+                                && matches!(
+                                    argument_definition.r#type().as_ref(self.schema_definition),
+                                    InputTypeReference::Base(
+                                        BaseInputTypeReference::InputObject(_),
+                                        true
+                                    ),
+                                )
+                        }
+                        _ => false,
+                    }
+                });
+        if !has_valid_input {
+            self.violations.push(LintViolation::new(
+                Self::NAME,
+                Severity::Warning,
+                node,
+                format!(
+                    "Mutation `{field_name}` should take a single non-null input object argument named `{}`",
+                    Self::INPUT_ARGUMENT_NAME,
+                ),
+            ));
+        }
+
+        let has_valid_payload = matches!(
+            field_definition.r#type().as_ref(self.schema_definition),
+            OutputTypeReference::Base(BaseOutputTypeReference::Object(otd), _)
+                if otd.name().ends_with(Self::PAYLOAD_SUFFIX),
+        );
+        if !has_valid_payload {
+            self.violations.push(LintViolation::new(
+                Self::NAME,
+                Severity::Warning,
+                node,
+                format!(
+                    "Mutation `{field_name}` should return an object type with a name ending in `{}`",
+                    Self::PAYLOAD_SUFFIX,
+                ),
+            ));
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> MutationInputAndPayload<'a, S> {
+    const NAME: &'static str = "mutation-input-payload";
+    const INPUT_ARGUMENT_NAME: &'static str = "input";
+    const PAYLOAD_SUFFIX: &'static str = "Payload";
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for MutationInputAndPayload<'a, S> {
+    type Item = LintViolation<'a, S>;
+    type IntoIter = std::vec::IntoIter<LintViolation<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.violations.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for MutationInputAndPayload<'a, S> {
+    type Error = LintViolation<'a, S>;
+
+    fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            violations: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::definition::lint::{LintNode, LintViolation, Severity};
+use crate::definition::{ArgumentDefinitionParent, Rule, Visitor};
+use crate::utils::is_camel_case;
+use bluejay_core::definition::{
+    DirectiveDefinition, EnumTypeDefinition, EnumValueDefinition, FieldDefinition,
+    InputObjectTypeDefinition, InputValueDefinition, InterfaceTypeDefinition, ObjectTypeDefinition,
+    ScalarTypeDefinition, SchemaDefinition, TypeDefinitionReference, UnionTypeDefinition,
+};
+
+/// Type names must be `PascalCase`, field, argument, input field and directive names
+/// `camelCase`, and enum values `SCREAMING_SNAKE_CASE`
+pub struct NamingConventions<'a, S: SchemaDefinition + 'a> {
+    violations: Vec<LintViolation<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for NamingConventions<'a, S> {
+    fn visit_custom_scalar_type_definition(
+        &mut self,
+        custom_scalar_type_definition: &'a <S as SchemaDefinition>::CustomScalarTypeDefinition,
+    ) {
+        self.visit_type_definition(
+            custom_scalar_type_definition.name(),
+            TypeDefinitionReference::CustomScalar(custom_scalar_type_definition),
+        );
+    }
+
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a <S as SchemaDefinition>::ObjectTypeDefinition,
+    ) {
+        if !object_type_definition.is_builtin() {
+            self.visit_type_definition(
+                object_type_definition.name(),
+                TypeDefinitionReference::Object(object_type_definition),
+            );
+        }
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        interface_type_definition: &'a <S as SchemaDefinition>::InterfaceTypeDefinition,
+    ) {
+        self.visit_type_definition(
+            interface_type_definition.name(),
+            TypeDefinitionReference::Interface(interface_type_definition),
+        );
+    }
+
+    fn visit_union_type_definition(
+        &mut self,
+        union_type_definition: &'a <S as SchemaDefinition>::UnionTypeDefinition,
+    ) {
+        self.visit_type_definition(
+            union_type_definition.name(),
+            TypeDefinitionReference::Union(union_type_definition),
+        );
+    }
+
+    fn visit_input_object_type_definition(
+        &mut self,
+        input_object_type_definition: &'a <S as SchemaDefinition>::InputObjectTypeDefinition,
+    ) {
+        self.visit_type_definition(
+            input_object_type_definition.name(),
+            TypeDefinitionReference::InputObject(input_object_type_definition),
+        );
+    }
+
+    fn visit_enum_type_definition(
+        &mut self,
+        enum_type_definition: &'a <S as SchemaDefinition>::EnumTypeDefinition,
+    ) {
+        if !enum_type_definition.is_builtin() {
+            self.visit_type_definition(
+                enum_type_definition.name(),
+                TypeDefinitionReference::Enum(enum_type_definition),
+            );
+        }
+    }
+
+    fn visit_directive_definition(
+        &mut self,
+        directive_definition: &'a <S as SchemaDefinition>::DirectiveDefinition,
+    ) {
+        if !directive_definition.is_builtin() && !is_camel_case(directive_definition.name()) {
+            self.push(
+                LintNode::DirectiveDefinition(directive_definition),
+                "Directive",
+                "camelCase",
+            );
+        }
+    }
+
+    fn visit_field_definition(
+        &mut self,
+        field_definition: &'a <S as SchemaDefinition>::FieldDefinition,
+        parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        if !field_definition.is_builtin()
+            && !parent_type.is_builtin()
+            && !is_camel_case(field_definition.name())
+        {
+            self.push(
+                LintNode::FieldDefinition {
+                    field_definition,
+                    parent_type,
+                },
+                "Field",
+                "camelCase",
+            );
+        }
+    }
+
+    fn visit_argument_definition(
+        &mut self,
+        argument_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+        parent: ArgumentDefinitionParent<'a, S>,
+    ) {
+        if !is_camel_case(argument_definition.name()) {
+            self.push(
+                LintNode::ArgumentDefinition {
+                    argument_definition,
+                    parent,
+                },
+                "Argument",
+                "camelCase",
+            );
+        }
+    }
+
+    fn visit_input_field_definition(
+        &mut self,
+        input_field_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+        input_object_type_definition: &'a <S as SchemaDefinition>::InputObjectTypeDefinition,
+    ) {
+        if !is_camel_case(input_field_definition.name()) {
+            self.push(
+                LintNode::InputFieldDefinition {
+                    input_field_definition,
+                    input_object_type_definition,
+                },
+                "Input field",
+                "camelCase",
+            );
+        }
+    }
+
+    fn visit_enum_value_definition(
+        &mut self,
+        enum_value_definition: &'a <S as SchemaDefinition>::EnumValueDefinition,
+        enum_type_definition: &'a <S as SchemaDefinition>::EnumTypeDefinition,
+    ) {
+        if !enum_type_definition.is_builtin()
+            && !is_screaming_snake_case(enum_value_definition.name())
+        {
+            self.push(
+                LintNode::EnumValueDefinition {
+                    enum_value_definition,
+                    enum_type_definition,
+                },
+                "Enum value",
+                "SCREAMING_SNAKE_CASE",
+            );
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> NamingConventions<'a, S> {
+    const NAME: &'static str = "naming-conventions";
+
+    fn visit_type_definition(
+        &mut self,
+        name: &str,
+        type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        if !is_pascal_case(name) {
+            self.push(
+                LintNode::TypeDefinition(type_definition),
+                "Type",
+                "PascalCase",
+            );
+        }
+    }
+
+    fn push(&mut self, node: LintNode<'a, S>, kind: &str, convention: &str) {
+        self.violations.push(LintViolation::new(
+            Self::NAME,
+            Severity::Warning,
+            node,
+            format!("{kind} `{}` should be {convention}", node.name()),
+        ));
+    }
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_screaming_snake_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for NamingConventions<'a, S> {
+    type Item = LintViolation<'a, S>;
+    type IntoIter = std::vec::IntoIter<LintViolation<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.violations.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for NamingConventions<'a, S> {
+    type Error = LintViolation<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+}
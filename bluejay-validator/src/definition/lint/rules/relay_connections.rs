@@ -0,0 +1,252 @@
+use crate::definition::lint::{LintNode, LintViolation, Severity};
+use crate::definition::{Rule, Visitor};
+use bluejay_core::definition::{
+    ArgumentsDefinition, BaseInputTypeReference, BaseOutputTypeReference, FieldDefinition,
+    FieldsDefinition, InputType, InputValueDefinition, ObjectTypeDefinition, OutputType,
+    OutputTypeReference, SchemaDefinition, TypeDefinitionReference,
+};
+use bluejay_core::BuiltinScalarDefinition;
+
+/// Connection types and the fields returning them must follow the
+/// [Relay Cursor Connections Specification](https://relay.dev/graphql/connections.htm)
+pub struct RelayConnections<'a, S: SchemaDefinition + 'a> {
+    schema_definition: &'a S,
+    violations: Vec<LintViolation<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for RelayConnections<'a, S> {
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a <S as SchemaDefinition>::ObjectTypeDefinition,
+    ) {
+        let name = object_type_definition.name();
+        if name.ends_with(Self::CONNECTION_SUFFIX) {
+            self.visit_connection_type(object_type_definition);
+        } else if name == Self::PAGE_INFO_TYPE_NAME {
+            self.visit_page_info_type(object_type_definition);
+        }
+    }
+
+    fn visit_field_definition(
+        &mut self,
+        field_definition: &'a <S as SchemaDefinition>::FieldDefinition,
+        parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        let returns_connection = matches!(
+            field_definition.r#type().as_ref(self.schema_definition),
+            OutputTypeReference::Base(BaseOutputTypeReference::Object(otd), _)
+                if otd.name().ends_with(Self::CONNECTION_SUFFIX),
+        );
+        if !returns_connection {
+            return;
+        }
+
+        let arguments_definition = field_definition.arguments_definition();
+        let has_pagination_arguments = |count: &str, cursor: &str| {
+            arguments_definition.is_some_and(|arguments_definition| {
+                arguments_definition.get(count).is_some_and(|argument| {
+                    matches!(
+                        argument.r#type().base(self.schema_definition),
+                        BaseInputTypeReference::BuiltinScalar(BuiltinScalarDefinition::Int),
+                    )
+                }) && arguments_definition.get(cursor).is_some()
+            })
+        };
+
+        if !has_pagination_arguments("first", "after")
+            && !has_pagination_arguments("last", "before")
+        {
+            self.violations.push(LintViolation::new(
+                Self::NAME,
+                Severity::Warning,
+                LintNode::FieldDefinition {
+                    field_definition,
+                    parent_type,
+                },
+                format!(
+                    "Field `{}.{}` returns a connection and should accept `first` and `after` and/or `last` and `before` arguments",
+                    parent_type.name(),
+                    field_definition.name(),
+                ),
+            ));
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> RelayConnections<'a, S> {
+    const NAME: &'static str = "relay-connections";
+    const CONNECTION_SUFFIX: &'static str = "Connection";
+    const PAGE_INFO_TYPE_NAME: &'static str = "PageInfo";
+
+    fn visit_connection_type(&mut self, otd: &'a S::ObjectTypeDefinition) {
+        let node = LintNode::TypeDefinition(TypeDefinitionReference::Object(otd));
+        let fields_definition = otd.fields_definition();
+
+        match fields_definition
+            .get("edges")
+            .map(|fd| fd.r#type().as_ref(self.schema_definition))
+        {
+            Some(OutputTypeReference::List(inner, _)) => {
+                match inner.as_ref(self.schema_definition) {
+                    OutputTypeReference::Base(BaseOutputTypeReference::Object(edge), _) => {
+                        self.visit_edge_type(otd, edge)
+                    }
+                    _ => self.push(
+                        node,
+                        format!(
+                            "Field `edges` of connection type `{}` should be a list of an edge object type",
+                            otd.name(),
+                        ),
+                    ),
+                }
+            }
+            _ => self.push(
+                node,
+                format!(
+                    "Connection type `{}` should have an `edges` field returning a list",
+                    otd.name(),
+                ),
+            ),
+        }
+
+        let has_valid_page_info = fields_definition.get("pageInfo").is_some_and(|fd| {
+            matches!(
+                fd.r#type().as_ref(self.schema_definition),
+                OutputTypeReference::Base(BaseOutputTypeReference::Object(page_info), true)
+                    if page_info.name() == Self::PAGE_INFO_TYPE_NAME,
+            )
+        });
+        if !has_valid_page_info {
+            self.push(
+                node,
+                format!(
+                    "Connection type `{}` should have a `pageInfo` field of type `{}!`",
+                    otd.name(),
+                    Self::PAGE_INFO_TYPE_NAME,
+                ),
+            );
+        }
+    }
+
+    fn visit_edge_type(
+        &mut self,
+        connection: &'a S::ObjectTypeDefinition,
+        edge: &'a S::ObjectTypeDefinition,
+    ) {
+        let node = LintNode::TypeDefinition(TypeDefinitionReference::Object(connection));
+        let fields_definition = edge.fields_definition();
+
+        let has_valid_node = fields_definition.get("node").is_some_and(|fd| {
+            matches!(
+                fd.r#type().as_ref(self.schema_definition),
+                OutputTypeReference::Base(..),
+            )
+        });
+        if !has_valid_node {
+            self.push(
+                node,
+                format!(
+                    "Edge type `{}` of connection type `{}` should have a `node` field that does not return a list",
+                    edge.name(),
+                    connection.name(),
+                ),
+            );
+        }
+
+        let has_valid_cursor = fields_definition.get("cursor").is_some_and(|fd| {
+            matches!(
+                fd.r#type().as_ref(self.schema_definition),
+                OutputTypeReference::Base(base, _) if base.is_scalar_or_enum(),
+            )
+        });
+        if !has_valid_cursor {
+            self.push(
+                node,
+                format!(
+                    "Edge type `{}` of connection type `{}` should have a `cursor` field returning a scalar",
+                    edge.name(),
+                    connection.name(),
+                ),
+            );
+        }
+    }
+
+    fn visit_page_info_type(&mut self, otd: &'a S::ObjectTypeDefinition) {
+        let node = LintNode::TypeDefinition(TypeDefinitionReference::Object(otd));
+        let fields_definition = otd.fields_definition();
+
+        ["hasNextPage", "hasPreviousPage"]
+            .into_iter()
+            .filter(|field_name| {
+                !fields_definition.get(field_name).is_some_and(|fd| {
+                    matches!(
+                        fd.r#type().as_ref(self.schema_definition),
+                        OutputTypeReference::Base(
+                            BaseOutputTypeReference::BuiltinScalar(
+                                BuiltinScalarDefinition::Boolean
+                            ),
+                            true,
+                        ),
+                    )
+                })
+            })
+            .for_each(|field_name| {
+                self.push(
+                    node,
+                    format!(
+                        "Type `{}` should have a field `{field_name}` of type `Boolean!`",
+                        Self::PAGE_INFO_TYPE_NAME,
+                    ),
+                )
+            });
+
+        ["startCursor", "endCursor"]
+            .into_iter()
+            .filter(|field_name| {
+                !fields_definition.get(field_name).is_some_and(|fd| {
+                    matches!(
+                        fd.r#type().as_ref(self.schema_definition),
+                        OutputTypeReference::Base(base, _) if base.is_scalar_or_enum(),
+                    )
+                })
+            })
+            .for_each(|field_name| {
+                self.push(
+                    node,
+                    format!(
+                        "Type `{}` should have a field `{field_name}` returning a scalar",
+                        Self::PAGE_INFO_TYPE_NAME,
+                    ),
+                )
+            });
+    }
+
+    fn push(&mut self, node: LintNode<'a, S>, message: String) {
+        self.violations.push(LintViolation::new(
+            Self::NAME,
+            Severity::Warning,
+            node,
+            message,
+        ));
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for RelayConnections<'a, S> {
+    type Item = LintViolation<'a, S>;
+    type IntoIter = std::vec::IntoIter<LintViolation<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.violations.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for RelayConnections<'a, S> {
+    type Error = LintViolation<'a, S>;
+
+    fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            violations: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::definition::lint::LintNode;
+use bluejay_core::definition::SchemaDefinition;
+
+#[cfg(feature = "parser-integration")]
+use bluejay_parser::{
+    ast::definition::SchemaDefinition as ParserSchemaDefinition,
+    error::{Annotation, Error as ParserError, Severity as ParserSeverity},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+pub struct LintViolation<'a, S: SchemaDefinition> {
+    rule: &'static str,
+    severity: Severity,
+    node: LintNode<'a, S>,
+    message: String,
+}
+
+impl<'a, S: SchemaDefinition> LintViolation<'a, S> {
+    pub fn new(
+        rule: &'static str,
+        severity: Severity,
+        node: LintNode<'a, S>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            rule,
+            severity,
+            node,
+            message: message.into(),
+        }
+    }
+
+    /// The name of the rule that was violated
+    pub fn rule(&self) -> &'static str {
+        self.rule
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub(crate) fn set_severity(&mut self, severity: Severity) {
+        self.severity = severity;
+    }
+
+    /// The definition that violates the rule
+    pub fn node(&self) -> LintNode<'a, S> {
+        self.node
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[cfg(feature = "parser-integration")]
+impl<'a> From<LintViolation<'a, ParserSchemaDefinition<'a>>> for ParserError {
+    fn from(value: LintViolation<'a, ParserSchemaDefinition<'a>>) -> Self {
+        let LintViolation {
+            rule,
+            severity,
+            node,
+            message,
+        } = value;
+        Self::new(
+            format!("{message} [{rule}]"),
+            node.name_span()
+                .map(|span| Annotation::new(message, span.clone())),
+            Vec::new(),
+        )
+        .with_severity(match severity {
+            Severity::Warning => ParserSeverity::Warning,
+            Severity::Error => ParserSeverity::Error,
+        })
+    }
+}
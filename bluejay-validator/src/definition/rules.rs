@@ -1,10 +1,24 @@
+mod argument_definition_uniqueness;
+mod default_values_are_valid;
+mod directive_definition_circular_references;
 mod enum_value_definition_uniqueness;
+mod field_definition_uniqueness;
 mod input_field_definition_uniqueness;
 mod input_object_circular_references;
+mod interface_implementations_are_valid;
+mod reserved_names;
+mod union_member_type_uniqueness;
 
+pub use argument_definition_uniqueness::ArgumentDefinitionUniqueness;
+pub use default_values_are_valid::DefaultValuesAreValid;
+pub use directive_definition_circular_references::DirectiveDefinitionCircularReferences;
 pub use enum_value_definition_uniqueness::EnumValueDefinitionUniqueness;
+pub use field_definition_uniqueness::FieldDefinitionUniqueness;
 pub use input_field_definition_uniqueness::InputFieldDefinitionUniqueness;
 pub use input_object_circular_references::InputObjectCircularReferences;
+pub use interface_implementations_are_valid::InterfaceImplementationsAreValid;
+pub use reserved_names::ReservedNames;
+pub use union_member_type_uniqueness::UnionMemberTypeUniqueness;
 
 #[macro_export]
 macro_rules! combine_definition_rules {
@@ -34,6 +48,22 @@ macro_rules! combine_definition_rules {
             }
 
             impl<'a, S: bluejay_core::definition::SchemaDefinition> $crate::definition::Visitor<'a, S> for $name<'a, S> {
+                fn visit_custom_scalar_type_definition(&mut self, custom_scalar_type_definition: &'a S::CustomScalarTypeDefinition) {
+                    $(self.[<$rule:snake>].visit_custom_scalar_type_definition(custom_scalar_type_definition);)*
+                }
+
+                fn visit_object_type_definition(&mut self, object_type_definition: &'a S::ObjectTypeDefinition) {
+                    $(self.[<$rule:snake>].visit_object_type_definition(object_type_definition);)*
+                }
+
+                fn visit_interface_type_definition(&mut self, interface_type_definition: &'a S::InterfaceTypeDefinition) {
+                    $(self.[<$rule:snake>].visit_interface_type_definition(interface_type_definition);)*
+                }
+
+                fn visit_union_type_definition(&mut self, union_type_definition: &'a S::UnionTypeDefinition) {
+                    $(self.[<$rule:snake>].visit_union_type_definition(union_type_definition);)*
+                }
+
                 fn visit_input_object_type_definition(&mut self, input_object_type_definition: &'a S::InputObjectTypeDefinition) {
                     $(self.[<$rule:snake>].visit_input_object_type_definition(input_object_type_definition);)*
                 }
@@ -41,6 +71,30 @@ macro_rules! combine_definition_rules {
                 fn visit_enum_type_definition(&mut self, enum_type_definition: &'a S::EnumTypeDefinition) {
                     $(self.[<$rule:snake>].visit_enum_type_definition(enum_type_definition);)*
                 }
+
+                fn visit_directive_definition(&mut self, directive_definition: &'a S::DirectiveDefinition) {
+                    $(self.[<$rule:snake>].visit_directive_definition(directive_definition);)*
+                }
+
+                fn visit_field_definition(&mut self, field_definition: &'a S::FieldDefinition, parent_type: bluejay_core::definition::TypeDefinitionReference<'a, S::TypeDefinition>) {
+                    $(self.[<$rule:snake>].visit_field_definition(field_definition, parent_type);)*
+                }
+
+                fn visit_argument_definition(&mut self, argument_definition: &'a S::InputValueDefinition, parent: $crate::definition::ArgumentDefinitionParent<'a, S>) {
+                    $(self.[<$rule:snake>].visit_argument_definition(argument_definition, parent);)*
+                }
+
+                fn visit_input_field_definition(&mut self, input_field_definition: &'a S::InputValueDefinition, input_object_type_definition: &'a S::InputObjectTypeDefinition) {
+                    $(self.[<$rule:snake>].visit_input_field_definition(input_field_definition, input_object_type_definition);)*
+                }
+
+                fn visit_enum_value_definition(&mut self, enum_value_definition: &'a S::EnumValueDefinition, enum_type_definition: &'a S::EnumTypeDefinition) {
+                    $(self.[<$rule:snake>].visit_enum_value_definition(enum_value_definition, enum_type_definition);)*
+                }
+
+                fn visit_directive(&mut self, directive: &'a S::Directive) {
+                    $(self.[<$rule:snake>].visit_directive(directive);)*
+                }
             }
         }
     };
@@ -50,8 +104,15 @@ combine_definition_rules!(
     BuiltinRules,
     crate::definition::Error,
     [
+        ArgumentDefinitionUniqueness,
+        DefaultValuesAreValid,
+        DirectiveDefinitionCircularReferences,
         EnumValueDefinitionUniqueness,
+        FieldDefinitionUniqueness,
         InputFieldDefinitionUniqueness,
         InputObjectCircularReferences,
+        InterfaceImplementationsAreValid,
+        ReservedNames,
+        UnionMemberTypeUniqueness,
     ],
 );
@@ -0,0 +1,74 @@
+use crate::definition::{Error, Rule, Visitor};
+use crate::utils::duplicates;
+use bluejay_core::definition::{
+    DirectiveDefinition, FieldDefinition, InputValueDefinition, InterfaceTypeDefinition,
+    ObjectTypeDefinition, SchemaDefinition,
+};
+use bluejay_core::AsIter;
+
+pub struct ArgumentDefinitionUniqueness<'a, S: SchemaDefinition + 'a> {
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for ArgumentDefinitionUniqueness<'a, S> {
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a <S as SchemaDefinition>::ObjectTypeDefinition,
+    ) {
+        self.visit_fields_definition(object_type_definition.fields_definition());
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        interface_type_definition: &'a <S as SchemaDefinition>::InterfaceTypeDefinition,
+    ) {
+        self.visit_fields_definition(interface_type_definition.fields_definition());
+    }
+
+    fn visit_directive_definition(
+        &mut self,
+        directive_definition: &'a <S as SchemaDefinition>::DirectiveDefinition,
+    ) {
+        if let Some(arguments_definition) = directive_definition.arguments_definition() {
+            self.visit_arguments_definition(arguments_definition);
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition> ArgumentDefinitionUniqueness<'a, S> {
+    fn visit_fields_definition(&mut self, fields_definition: &'a S::FieldsDefinition) {
+        fields_definition.iter().for_each(|field_definition| {
+            if let Some(arguments_definition) = field_definition.arguments_definition() {
+                self.visit_arguments_definition(arguments_definition);
+            }
+        });
+    }
+
+    fn visit_arguments_definition(&mut self, arguments_definition: &'a S::ArgumentsDefinition) {
+        self.errors.extend(
+            duplicates(arguments_definition.iter(), InputValueDefinition::name).map(
+                |(name, argument_definitions)| Error::NonUniqueArgumentDefinitionNames {
+                    name,
+                    argument_definitions,
+                },
+            ),
+        );
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for ArgumentDefinitionUniqueness<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for ArgumentDefinitionUniqueness<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self { errors: Vec::new() }
+    }
+}
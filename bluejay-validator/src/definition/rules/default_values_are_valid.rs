@@ -0,0 +1,64 @@
+use crate::definition::{ArgumentDefinitionParent, Error, Rule, Visitor};
+use crate::value::input_coercion::CoerceInput;
+use bluejay_core::definition::{InputValueDefinition, SchemaDefinition};
+
+pub struct DefaultValuesAreValid<'a, S: SchemaDefinition + 'a> {
+    schema_definition: &'a S,
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for DefaultValuesAreValid<'a, S> {
+    fn visit_argument_definition(
+        &mut self,
+        argument_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+        _parent: ArgumentDefinitionParent<'a, S>,
+    ) {
+        self.visit_input_value_definition(argument_definition);
+    }
+
+    fn visit_input_field_definition(
+        &mut self,
+        input_field_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+        _input_object_type_definition: &'a <S as SchemaDefinition>::InputObjectTypeDefinition,
+    ) {
+        self.visit_input_value_definition(input_field_definition);
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> DefaultValuesAreValid<'a, S> {
+    fn visit_input_value_definition(
+        &mut self,
+        input_value_definition: &'a S::InputValueDefinition,
+    ) {
+        if let Some(default_value) = input_value_definition.default_value() {
+            if let Err(coercion_errors) = self.schema_definition.coerce_const_value(
+                input_value_definition.r#type(),
+                default_value,
+                Default::default(),
+            ) {
+                self.errors
+                    .extend(coercion_errors.into_iter().map(Error::InvalidDefaultValue));
+            }
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for DefaultValuesAreValid<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for DefaultValuesAreValid<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            errors: Vec::new(),
+        }
+    }
+}
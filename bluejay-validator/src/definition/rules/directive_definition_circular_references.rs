@@ -0,0 +1,128 @@
+use crate::definition::{Error, Rule, Visitor};
+use bluejay_core::definition::{
+    BaseInputTypeReference, Directive, DirectiveDefinition, EnumTypeDefinition, HasDirectives,
+    InputObjectTypeDefinition, InputType, InputValueDefinition, ScalarTypeDefinition,
+    SchemaDefinition,
+};
+use bluejay_core::{AsIter, Directive as _};
+use std::collections::HashSet;
+
+/// A directive definition must not reference itself, either directly through the directives
+/// applied to its arguments, or indirectly through the directives and types it references.
+pub struct DirectiveDefinitionCircularReferences<'a, S: SchemaDefinition + 'a> {
+    schema_definition: &'a S,
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for DirectiveDefinitionCircularReferences<'a, S> {
+    fn visit_directive_definition(
+        &mut self,
+        directive_definition: &'a <S as SchemaDefinition>::DirectiveDefinition,
+    ) {
+        if directive_definition.is_builtin() {
+            return;
+        }
+
+        let mut visitor = CircularReferenceVisitor {
+            schema_definition: self.schema_definition,
+            target: directive_definition,
+            circular_references: Vec::new(),
+            encountered_directive_definitions: HashSet::new(),
+            encountered_type_definitions: HashSet::new(),
+        };
+        visitor.visit_directive_definition(directive_definition);
+
+        if !visitor.circular_references.is_empty() {
+            self.errors
+                .push(Error::DirectiveDefinitionCircularReferences {
+                    directive_definition,
+                    circular_references: visitor.circular_references,
+                });
+        }
+    }
+}
+
+struct CircularReferenceVisitor<'a, S: SchemaDefinition> {
+    schema_definition: &'a S,
+    target: &'a S::DirectiveDefinition,
+    circular_references: Vec<&'a S::Directive>,
+    encountered_directive_definitions: HashSet<&'a str>,
+    encountered_type_definitions: HashSet<&'a str>,
+}
+
+impl<'a, S: SchemaDefinition> CircularReferenceVisitor<'a, S> {
+    fn visit_directive_definition(&mut self, directive_definition: &'a S::DirectiveDefinition) {
+        directive_definition
+            .arguments_definition()
+            .into_iter()
+            .flat_map(|arguments_definition| arguments_definition.iter())
+            .for_each(|argument_definition| self.visit_input_value_definition(argument_definition));
+    }
+
+    fn visit_input_value_definition(
+        &mut self,
+        input_value_definition: &'a S::InputValueDefinition,
+    ) {
+        self.visit_directives(input_value_definition.directives());
+        match input_value_definition.r#type().base(self.schema_definition) {
+            BaseInputTypeReference::CustomScalar(cstd) => {
+                if self.encountered_type_definitions.insert(cstd.name()) {
+                    self.visit_directives(cstd.directives());
+                }
+            }
+            BaseInputTypeReference::Enum(etd) => {
+                if self.encountered_type_definitions.insert(etd.name()) {
+                    self.visit_directives(etd.directives());
+                    etd.enum_value_definitions()
+                        .iter()
+                        .for_each(|evd| self.visit_directives(evd.directives()));
+                }
+            }
+            BaseInputTypeReference::InputObject(iotd) => {
+                if self.encountered_type_definitions.insert(iotd.name()) {
+                    self.visit_directives(iotd.directives());
+                    iotd.input_field_definitions()
+                        .iter()
+                        .for_each(|ivd| self.visit_input_value_definition(ivd));
+                }
+            }
+            BaseInputTypeReference::BuiltinScalar(_) => {}
+        }
+    }
+
+    fn visit_directives(&mut self, directives: Option<&'a <S as SchemaDefinition>::Directives>) {
+        directives
+            .into_iter()
+            .flat_map(|directives| directives.iter())
+            .for_each(|directive| {
+                if directive.name() == self.target.name() {
+                    self.circular_references.push(directive);
+                } else if self
+                    .encountered_directive_definitions
+                    .insert(directive.name())
+                {
+                    self.visit_directive_definition(directive.definition(self.schema_definition));
+                }
+            });
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for DirectiveDefinitionCircularReferences<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for DirectiveDefinitionCircularReferences<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            errors: Vec::new(),
+        }
+    }
+}
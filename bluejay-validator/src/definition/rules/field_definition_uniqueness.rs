@@ -0,0 +1,56 @@
+use crate::definition::{Error, Rule, Visitor};
+use crate::utils::duplicates;
+use bluejay_core::definition::{
+    FieldDefinition, InterfaceTypeDefinition, ObjectTypeDefinition, SchemaDefinition,
+};
+use bluejay_core::AsIter;
+
+pub struct FieldDefinitionUniqueness<'a, S: SchemaDefinition + 'a> {
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for FieldDefinitionUniqueness<'a, S> {
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a <S as SchemaDefinition>::ObjectTypeDefinition,
+    ) {
+        self.visit_fields_definition(object_type_definition.fields_definition());
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        interface_type_definition: &'a <S as SchemaDefinition>::InterfaceTypeDefinition,
+    ) {
+        self.visit_fields_definition(interface_type_definition.fields_definition());
+    }
+}
+
+impl<'a, S: SchemaDefinition> FieldDefinitionUniqueness<'a, S> {
+    fn visit_fields_definition(&mut self, fields_definition: &'a S::FieldsDefinition) {
+        self.errors.extend(
+            duplicates(fields_definition.iter(), FieldDefinition::name).map(
+                |(name, field_definitions)| Error::NonUniqueFieldDefinitionNames {
+                    name,
+                    field_definitions,
+                },
+            ),
+        );
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for FieldDefinitionUniqueness<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for FieldDefinitionUniqueness<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self { errors: Vec::new() }
+    }
+}
@@ -0,0 +1,284 @@
+use crate::definition::{Error, Rule, Visitor};
+use crate::utils::duplicates;
+use bluejay_core::definition::{
+    ArgumentsDefinition, BaseOutputTypeReference, FieldDefinition, FieldsDefinition, InputType,
+    InputValueDefinition, InterfaceImplementation, InterfaceTypeDefinition, ObjectTypeDefinition,
+    OutputType, OutputTypeReference, SchemaDefinition, UnionMemberTypes, UnionTypeDefinition,
+};
+use bluejay_core::AsIter;
+
+pub struct InterfaceImplementationsAreValid<'a, S: SchemaDefinition + 'a> {
+    schema_definition: &'a S,
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for InterfaceImplementationsAreValid<'a, S> {
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a <S as SchemaDefinition>::ObjectTypeDefinition,
+    ) {
+        if let Some(interface_implementations) = object_type_definition.interface_implementations()
+        {
+            self.visit_interface_implementations(
+                object_type_definition.name(),
+                object_type_definition.fields_definition(),
+                interface_implementations,
+                None,
+            );
+        }
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        interface_type_definition: &'a <S as SchemaDefinition>::InterfaceTypeDefinition,
+    ) {
+        if let Some(interface_implementations) =
+            interface_type_definition.interface_implementations()
+        {
+            self.visit_interface_implementations(
+                interface_type_definition.name(),
+                interface_type_definition.fields_definition(),
+                interface_implementations,
+                Some(interface_type_definition),
+            );
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> InterfaceImplementationsAreValid<'a, S> {
+    fn visit_interface_implementations(
+        &mut self,
+        implementor_name: &'a str,
+        fields_definition: &'a S::FieldsDefinition,
+        interface_implementations: &'a S::InterfaceImplementations,
+        interface_type_definition: Option<&'a S::InterfaceTypeDefinition>,
+    ) {
+        self.errors.extend(
+            duplicates(
+                interface_implementations.iter(),
+                InterfaceImplementation::name,
+            )
+            .map(|(name, interface_implementations)| {
+                Error::NonUniqueInterfaceImplementations {
+                    name,
+                    interface_implementations,
+                }
+            }),
+        );
+
+        interface_implementations
+            .iter()
+            .for_each(|interface_implementation| {
+                if let Some(interface_type_definition) = interface_type_definition
+                    .filter(|_| interface_implementation.name() == implementor_name)
+                {
+                    self.errors
+                        .push(Error::InterfaceImplementationSelfReference {
+                            interface_type_definition,
+                            interface_implementation,
+                        });
+                    return;
+                }
+
+                let interface = interface_implementation.interface(self.schema_definition);
+
+                self.errors.extend(
+                    interface
+                        .interface_implementations()
+                        .into_iter()
+                        .flat_map(|transitive_implementations| transitive_implementations.iter())
+                        .filter(|transitive_implementation| {
+                            !interface_implementations
+                                .iter()
+                                .any(|ii| ii.name() == transitive_implementation.name())
+                        })
+                        .map(|transitive_implementation| {
+                            Error::InterfaceImplementationMissingTransitiveInterface {
+                                implementor_name,
+                                interface_implementation,
+                                missing_interface_name: transitive_implementation.name(),
+                            }
+                        }),
+                );
+
+                interface
+                    .fields_definition()
+                    .iter()
+                    .filter(|interface_field_definition| !interface_field_definition.is_builtin())
+                    .for_each(|interface_field_definition| {
+                        match fields_definition.get(interface_field_definition.name()) {
+                            Some(field_definition) => self.visit_field_definition(
+                                implementor_name,
+                                interface_implementation,
+                                field_definition,
+                                interface_field_definition,
+                            ),
+                            None => self
+                                .errors
+                                .push(Error::InterfaceImplementationMissingField {
+                                    implementor_name,
+                                    interface_implementation,
+                                    interface_field_definition,
+                                }),
+                        }
+                    });
+            });
+    }
+
+    fn visit_field_definition(
+        &mut self,
+        implementor_name: &'a str,
+        interface_implementation: &'a S::InterfaceImplementation,
+        field_definition: &'a S::FieldDefinition,
+        interface_field_definition: &'a S::FieldDefinition,
+    ) {
+        if !self.is_valid_implementation_field_type(
+            field_definition.r#type().as_ref(self.schema_definition),
+            interface_field_definition
+                .r#type()
+                .as_ref(self.schema_definition),
+        ) {
+            self.errors
+                .push(Error::InterfaceImplementationFieldTypeNotCovariant {
+                    implementor_name,
+                    interface_implementation,
+                    field_definition,
+                    interface_field_definition,
+                });
+        }
+
+        let arguments_definition = field_definition.arguments_definition();
+        let interface_arguments_definition = interface_field_definition.arguments_definition();
+
+        interface_arguments_definition
+            .into_iter()
+            .flat_map(|interface_arguments_definition| interface_arguments_definition.iter())
+            .for_each(|interface_argument_definition| {
+                match arguments_definition.and_then(|arguments_definition| {
+                    arguments_definition.get(interface_argument_definition.name())
+                }) {
+                    Some(argument_definition) => {
+                        if argument_definition.r#type().as_shallow_ref()
+                            != interface_argument_definition.r#type().as_shallow_ref()
+                        {
+                            self.errors
+                                .push(Error::InterfaceImplementationArgumentTypeMismatch {
+                                    implementor_name,
+                                    interface_implementation,
+                                    field_definition,
+                                    argument_definition,
+                                    interface_argument_definition,
+                                });
+                        }
+                    }
+                    None => self
+                        .errors
+                        .push(Error::InterfaceImplementationMissingArgument {
+                            implementor_name,
+                            interface_implementation,
+                            field_definition,
+                            interface_argument_definition,
+                        }),
+                }
+            });
+
+        self.errors.extend(
+            arguments_definition
+                .into_iter()
+                .flat_map(|arguments_definition| arguments_definition.iter())
+                .filter(|argument_definition| {
+                    argument_definition.is_required()
+                        && interface_arguments_definition
+                            .and_then(|interface_arguments_definition| {
+                                interface_arguments_definition.get(argument_definition.name())
+                            })
+                            .is_none()
+                })
+                .map(|argument_definition| {
+                    Error::InterfaceImplementationAdditionalArgumentRequired {
+                        implementor_name,
+                        interface_implementation,
+                        field_definition,
+                        argument_definition,
+                    }
+                }),
+        );
+    }
+
+    /// Implements [IsValidImplementationFieldType](https://spec.graphql.org/draft/#IsValidImplementationFieldType())
+    fn is_valid_implementation_field_type(
+        &self,
+        field_type: OutputTypeReference<'a, S::OutputType>,
+        implemented_field_type: OutputTypeReference<'a, S::OutputType>,
+    ) -> bool {
+        if implemented_field_type.is_required() && !field_type.is_required() {
+            return false;
+        }
+
+        match (field_type, implemented_field_type) {
+            (
+                OutputTypeReference::List(inner, _),
+                OutputTypeReference::List(implemented_inner, _),
+            ) => self.is_valid_implementation_field_type(
+                inner.as_ref(self.schema_definition),
+                implemented_inner.as_ref(self.schema_definition),
+            ),
+            (
+                OutputTypeReference::Base(base, _),
+                OutputTypeReference::Base(implemented_base, _),
+            ) => {
+                base.name() == implemented_base.name()
+                    || match (base, implemented_base) {
+                        (
+                            BaseOutputTypeReference::Object(otd),
+                            BaseOutputTypeReference::Union(utd),
+                        ) => utd.union_member_types().contains_type(otd.name()),
+                        (
+                            BaseOutputTypeReference::Object(otd),
+                            BaseOutputTypeReference::Interface(itd),
+                        ) => Self::declares_interface(otd.interface_implementations(), itd.name()),
+                        (
+                            BaseOutputTypeReference::Interface(implementor),
+                            BaseOutputTypeReference::Interface(itd),
+                        ) => Self::declares_interface(
+                            implementor.interface_implementations(),
+                            itd.name(),
+                        ),
+                        _ => false,
+                    }
+            }
+            _ => false,
+        }
+    }
+
+    fn declares_interface(
+        interface_implementations: Option<&'a S::InterfaceImplementations>,
+        interface_name: &str,
+    ) -> bool {
+        interface_implementations.is_some_and(|interface_implementations| {
+            interface_implementations
+                .iter()
+                .any(|ii| ii.name() == interface_name)
+        })
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for InterfaceImplementationsAreValid<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for InterfaceImplementationsAreValid<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            errors: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::definition::{ArgumentDefinitionParent, Error, Rule, Visitor};
+use bluejay_core::definition::{
+    DirectiveDefinition, EnumTypeDefinition, EnumValueDefinition, FieldDefinition,
+    InputObjectTypeDefinition, InputValueDefinition, InterfaceTypeDefinition, ObjectTypeDefinition,
+    ScalarTypeDefinition, SchemaDefinition, TypeDefinitionReference, UnionTypeDefinition,
+};
+
+/// Names beginning with `__` are reserved for the introspection system, so only builtin
+/// definitions may use them.
+pub struct ReservedNames<'a, S: SchemaDefinition + 'a> {
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for ReservedNames<'a, S> {
+    fn visit_custom_scalar_type_definition(
+        &mut self,
+        custom_scalar_type_definition: &'a <S as SchemaDefinition>::CustomScalarTypeDefinition,
+    ) {
+        self.visit_type_definition_name(
+            custom_scalar_type_definition.name(),
+            TypeDefinitionReference::CustomScalar(custom_scalar_type_definition),
+        );
+    }
+
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a <S as SchemaDefinition>::ObjectTypeDefinition,
+    ) {
+        if !object_type_definition.is_builtin() {
+            self.visit_type_definition_name(
+                object_type_definition.name(),
+                TypeDefinitionReference::Object(object_type_definition),
+            );
+        }
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        interface_type_definition: &'a <S as SchemaDefinition>::InterfaceTypeDefinition,
+    ) {
+        self.visit_type_definition_name(
+            interface_type_definition.name(),
+            TypeDefinitionReference::Interface(interface_type_definition),
+        );
+    }
+
+    fn visit_union_type_definition(
+        &mut self,
+        union_type_definition: &'a <S as SchemaDefinition>::UnionTypeDefinition,
+    ) {
+        self.visit_type_definition_name(
+            union_type_definition.name(),
+            TypeDefinitionReference::Union(union_type_definition),
+        );
+    }
+
+    fn visit_input_object_type_definition(
+        &mut self,
+        input_object_type_definition: &'a <S as SchemaDefinition>::InputObjectTypeDefinition,
+    ) {
+        self.visit_type_definition_name(
+            input_object_type_definition.name(),
+            TypeDefinitionReference::InputObject(input_object_type_definition),
+        );
+    }
+
+    fn visit_enum_type_definition(
+        &mut self,
+        enum_type_definition: &'a <S as SchemaDefinition>::EnumTypeDefinition,
+    ) {
+        if !enum_type_definition.is_builtin() {
+            self.visit_type_definition_name(
+                enum_type_definition.name(),
+                TypeDefinitionReference::Enum(enum_type_definition),
+            );
+        }
+    }
+
+    fn visit_directive_definition(
+        &mut self,
+        directive_definition: &'a <S as SchemaDefinition>::DirectiveDefinition,
+    ) {
+        if !directive_definition.is_builtin() && Self::is_reserved(directive_definition.name()) {
+            self.errors.push(Error::ReservedDirectiveDefinitionName {
+                directive_definition,
+            });
+        }
+    }
+
+    fn visit_field_definition(
+        &mut self,
+        field_definition: &'a <S as SchemaDefinition>::FieldDefinition,
+        _parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        if !field_definition.is_builtin() && Self::is_reserved(field_definition.name()) {
+            self.errors
+                .push(Error::ReservedFieldDefinitionName { field_definition });
+        }
+    }
+
+    fn visit_argument_definition(
+        &mut self,
+        argument_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+        _parent: ArgumentDefinitionParent<'a, S>,
+    ) {
+        self.visit_input_value_definition(argument_definition);
+    }
+
+    fn visit_input_field_definition(
+        &mut self,
+        input_field_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+        _input_object_type_definition: &'a <S as SchemaDefinition>::InputObjectTypeDefinition,
+    ) {
+        self.visit_input_value_definition(input_field_definition);
+    }
+
+    fn visit_enum_value_definition(
+        &mut self,
+        enum_value_definition: &'a <S as SchemaDefinition>::EnumValueDefinition,
+        _enum_type_definition: &'a <S as SchemaDefinition>::EnumTypeDefinition,
+    ) {
+        if Self::is_reserved(enum_value_definition.name()) {
+            self.errors.push(Error::ReservedEnumValueDefinitionName {
+                enum_value_definition,
+            });
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition + 'a> ReservedNames<'a, S> {
+    const RESERVED_PREFIX: &'static str = "__";
+
+    fn is_reserved(name: &str) -> bool {
+        name.starts_with(Self::RESERVED_PREFIX)
+    }
+
+    fn visit_type_definition_name(
+        &mut self,
+        name: &str,
+        type_definition: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        if Self::is_reserved(name) {
+            self.errors
+                .push(Error::ReservedTypeDefinitionName { type_definition });
+        }
+    }
+
+    fn visit_input_value_definition(
+        &mut self,
+        input_value_definition: &'a S::InputValueDefinition,
+    ) {
+        if Self::is_reserved(input_value_definition.name()) {
+            self.errors.push(Error::ReservedInputValueDefinitionName {
+                input_value_definition,
+            });
+        }
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for ReservedNames<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for ReservedNames<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self { errors: Vec::new() }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::definition::{Error, Rule, Visitor};
+use crate::utils::duplicates;
+use bluejay_core::definition::{SchemaDefinition, UnionMemberType, UnionTypeDefinition};
+use bluejay_core::AsIter;
+
+/// Union member types are guaranteed to be object types by
+/// [`UnionMemberType::member_type`], so only their uniqueness needs validating.
+pub struct UnionMemberTypeUniqueness<'a, S: SchemaDefinition + 'a> {
+    errors: Vec<Error<'a, S>>,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for UnionMemberTypeUniqueness<'a, S> {
+    fn visit_union_type_definition(
+        &mut self,
+        union_type_definition: &'a <S as SchemaDefinition>::UnionTypeDefinition,
+    ) {
+        self.errors.extend(
+            duplicates(
+                union_type_definition.union_member_types().iter(),
+                UnionMemberType::name,
+            )
+            .map(
+                |(name, union_member_types)| Error::NonUniqueUnionMemberTypes {
+                    name,
+                    union_type_definition,
+                    union_member_types,
+                },
+            ),
+        );
+    }
+}
+
+impl<'a, S: SchemaDefinition> IntoIterator for UnionMemberTypeUniqueness<'a, S> {
+    type Item = Error<'a, S>;
+    type IntoIter = std::vec::IntoIter<Error<'a, S>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for UnionMemberTypeUniqueness<'a, S> {
+    type Error = Error<'a, S>;
+
+    fn new(_: &'a S) -> Self {
+        Self { errors: Vec::new() }
+    }
+}
@@ -1,5 +1,10 @@
-use crate::definition::{BuiltinRules, Rule};
-use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use crate::definition::{ArgumentDefinitionParent, BuiltinRules, Rule};
+use bluejay_core::definition::{
+    DirectiveDefinition, EnumTypeDefinition, FieldDefinition, HasDirectives,
+    InputObjectTypeDefinition, InterfaceTypeDefinition, ObjectTypeDefinition, SchemaDefinition,
+    TypeDefinitionReference,
+};
+use bluejay_core::AsIter;
 
 pub struct Validator<'a, S: SchemaDefinition, R: Rule<'a, S>> {
     schema_definition: &'a S,
@@ -17,15 +22,67 @@ impl<'a, S: SchemaDefinition, R: Rule<'a, S>> Validator<'a, S, R> {
     }
 
     fn visit(&mut self) {
+        self.visit_directives(self.schema_definition.directives());
         self.schema_definition.type_definitions().for_each(
             |type_definition| match type_definition {
+                TypeDefinitionReference::CustomScalar(cstd) => {
+                    self.visit_custom_scalar_type_definition(cstd)
+                }
+                TypeDefinitionReference::Object(otd) => self.visit_object_type_definition(otd),
+                TypeDefinitionReference::Interface(itd) => {
+                    self.visit_interface_type_definition(itd)
+                }
+                TypeDefinitionReference::Union(utd) => self.visit_union_type_definition(utd),
                 TypeDefinitionReference::InputObject(iotd) => {
                     self.visit_input_object_type_definition(iotd)
                 }
                 TypeDefinitionReference::Enum(etd) => self.visit_enum_type_definition(etd),
-                _ => {}
+                TypeDefinitionReference::BuiltinScalar(_) => {}
             },
-        )
+        );
+        self.schema_definition
+            .directive_definitions()
+            .for_each(|directive_definition| self.visit_directive_definition(directive_definition));
+    }
+
+    fn visit_custom_scalar_type_definition(
+        &mut self,
+        custom_scalar_type_definition: &'a S::CustomScalarTypeDefinition,
+    ) {
+        self.rule
+            .visit_custom_scalar_type_definition(custom_scalar_type_definition);
+        self.visit_directives(custom_scalar_type_definition.directives());
+    }
+
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a S::ObjectTypeDefinition,
+    ) {
+        self.rule
+            .visit_object_type_definition(object_type_definition);
+        self.visit_directives(object_type_definition.directives());
+        self.visit_fields_definition(
+            object_type_definition.fields_definition(),
+            TypeDefinitionReference::Object(object_type_definition),
+        );
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        interface_type_definition: &'a S::InterfaceTypeDefinition,
+    ) {
+        self.rule
+            .visit_interface_type_definition(interface_type_definition);
+        self.visit_directives(interface_type_definition.directives());
+        self.visit_fields_definition(
+            interface_type_definition.fields_definition(),
+            TypeDefinitionReference::Interface(interface_type_definition),
+        );
+    }
+
+    fn visit_union_type_definition(&mut self, union_type_definition: &'a S::UnionTypeDefinition) {
+        self.rule.visit_union_type_definition(union_type_definition);
+        self.visit_directives(union_type_definition.directives());
     }
 
     fn visit_input_object_type_definition(
@@ -34,10 +91,79 @@ impl<'a, S: SchemaDefinition, R: Rule<'a, S>> Validator<'a, S, R> {
     ) {
         self.rule
             .visit_input_object_type_definition(input_object_type_definition);
+        self.visit_directives(input_object_type_definition.directives());
+        input_object_type_definition
+            .input_field_definitions()
+            .iter()
+            .for_each(|input_field_definition| {
+                self.rule.visit_input_field_definition(
+                    input_field_definition,
+                    input_object_type_definition,
+                );
+                self.visit_directives(input_field_definition.directives());
+            });
     }
 
     fn visit_enum_type_definition(&mut self, enum_type_definition: &'a S::EnumTypeDefinition) {
         self.rule.visit_enum_type_definition(enum_type_definition);
+        self.visit_directives(enum_type_definition.directives());
+        enum_type_definition
+            .enum_value_definitions()
+            .iter()
+            .for_each(|enum_value_definition| {
+                self.rule
+                    .visit_enum_value_definition(enum_value_definition, enum_type_definition);
+                self.visit_directives(enum_value_definition.directives());
+            });
+    }
+
+    fn visit_directive_definition(&mut self, directive_definition: &'a S::DirectiveDefinition) {
+        self.rule.visit_directive_definition(directive_definition);
+        self.visit_arguments_definition(
+            directive_definition.arguments_definition(),
+            ArgumentDefinitionParent::DirectiveDefinition(directive_definition),
+        );
+    }
+
+    fn visit_fields_definition(
+        &mut self,
+        fields_definition: &'a S::FieldsDefinition,
+        parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        fields_definition.iter().for_each(|field_definition| {
+            self.rule
+                .visit_field_definition(field_definition, parent_type);
+            self.visit_directives(field_definition.directives());
+            self.visit_arguments_definition(
+                field_definition.arguments_definition(),
+                ArgumentDefinitionParent::FieldDefinition {
+                    field_definition,
+                    parent_type,
+                },
+            );
+        });
+    }
+
+    fn visit_arguments_definition(
+        &mut self,
+        arguments_definition: Option<&'a S::ArgumentsDefinition>,
+        parent: ArgumentDefinitionParent<'a, S>,
+    ) {
+        arguments_definition
+            .into_iter()
+            .flat_map(|arguments_definition| arguments_definition.iter())
+            .for_each(|argument_definition| {
+                self.rule
+                    .visit_argument_definition(argument_definition, parent);
+                self.visit_directives(argument_definition.directives());
+            });
+    }
+
+    fn visit_directives(&mut self, directives: Option<&'a <S as SchemaDefinition>::Directives>) {
+        directives
+            .into_iter()
+            .flat_map(|directives| directives.iter())
+            .for_each(|directive| self.rule.visit_directive(directive));
     }
 
     pub fn validate(schema_definition: &'a S) -> <Self as IntoIterator>::IntoIter {
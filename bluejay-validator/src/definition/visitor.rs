@@ -1,6 +1,43 @@
-use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+
+/// The definition that an argument definition belongs to
+pub enum ArgumentDefinitionParent<'a, S: SchemaDefinition> {
+    FieldDefinition {
+        field_definition: &'a S::FieldDefinition,
+        parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    },
+    DirectiveDefinition(&'a S::DirectiveDefinition),
+}
+
+impl<S: SchemaDefinition> Clone for ArgumentDefinitionParent<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: SchemaDefinition> Copy for ArgumentDefinitionParent<'_, S> {}
 
 pub trait Visitor<'a, S: SchemaDefinition> {
+    fn visit_custom_scalar_type_definition(
+        &mut self,
+        _custom_scalar_type_definition: &'a S::CustomScalarTypeDefinition,
+    ) {
+    }
+
+    fn visit_object_type_definition(
+        &mut self,
+        _object_type_definition: &'a S::ObjectTypeDefinition,
+    ) {
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        _interface_type_definition: &'a S::InterfaceTypeDefinition,
+    ) {
+    }
+
+    fn visit_union_type_definition(&mut self, _union_type_definition: &'a S::UnionTypeDefinition) {}
+
     fn visit_input_object_type_definition(
         &mut self,
         _input_object_type_definition: &'a S::InputObjectTypeDefinition,
@@ -8,4 +45,38 @@ pub trait Visitor<'a, S: SchemaDefinition> {
     }
 
     fn visit_enum_type_definition(&mut self, _enum_type_definition: &'a S::EnumTypeDefinition) {}
+
+    fn visit_directive_definition(&mut self, _directive_definition: &'a S::DirectiveDefinition) {}
+
+    fn visit_field_definition(
+        &mut self,
+        _field_definition: &'a S::FieldDefinition,
+        _parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+    }
+
+    /// Called for the arguments of both field definitions and directive definitions
+    fn visit_argument_definition(
+        &mut self,
+        _argument_definition: &'a S::InputValueDefinition,
+        _parent: ArgumentDefinitionParent<'a, S>,
+    ) {
+    }
+
+    fn visit_input_field_definition(
+        &mut self,
+        _input_field_definition: &'a S::InputValueDefinition,
+        _input_object_type_definition: &'a S::InputObjectTypeDefinition,
+    ) {
+    }
+
+    fn visit_enum_value_definition(
+        &mut self,
+        _enum_value_definition: &'a S::EnumValueDefinition,
+        _enum_type_definition: &'a S::EnumTypeDefinition,
+    ) {
+    }
+
+    /// Called for every usage of a directive within the schema, including on the schema itself
+    fn visit_directive(&mut self, _directive: &'a S::Directive) {}
 }
@@ -1,5 +1,7 @@
 mod cache;
 pub mod document;
 pub mod operation;
+mod schema_cache;
 
 pub use cache::Cache;
+pub use schema_cache::SchemaCache;
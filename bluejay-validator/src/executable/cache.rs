@@ -1,19 +1,57 @@
-use crate::executable::document::VariableDefinitionInputType;
+use crate::executable::{document::VariableDefinitionInputType, SchemaCache};
 use bluejay_core::definition::SchemaDefinition;
 use bluejay_core::executable::{
     ExecutableDocument, FragmentDefinition, OperationDefinition, VariableDefinition,
 };
 use bluejay_core::{AsIter, Indexed};
 use std::collections::HashMap;
+use std::sync::OnceLock;
 
+/// Data about an executable document that is used by many rules and analyzers, built once per
+/// document. The schema-derived part is a [`SchemaCache`], which can be shared between documents
+/// with [`Cache::with_schema_cache`]. Otherwise, it is only built for the document the first
+/// time it is needed, so documents that never look up possible types don't pay for it.
 pub struct Cache<'a, E: ExecutableDocument, S: SchemaDefinition> {
     variable_definition_input_types:
         HashMap<Indexed<'a, E::VariableType>, VariableDefinitionInputType<'a, S::InputType>>,
     indexed_fragment_definitions: HashMap<&'a str, &'a E::FragmentDefinition>,
+    schema_definition: &'a S,
+    schema_cache: DocumentSchemaCache<'a>,
+}
+
+enum DocumentSchemaCache<'a> {
+    Shared(&'a SchemaCache<'a>),
+    Lazy(OnceLock<SchemaCache<'a>>),
 }
 
 impl<'a, E: ExecutableDocument, S: SchemaDefinition> Cache<'a, E, S> {
     pub fn new(executable_document: &'a E, schema_definition: &'a S) -> Self {
+        Self::with_cow_schema_cache(
+            executable_document,
+            schema_definition,
+            DocumentSchemaCache::Lazy(OnceLock::new()),
+        )
+    }
+
+    /// Builds the cache for `executable_document` reusing a [`SchemaCache`] that was built
+    /// for `schema_definition` ahead of time
+    pub fn with_schema_cache(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        schema_cache: &'a SchemaCache<'a>,
+    ) -> Self {
+        Self::with_cow_schema_cache(
+            executable_document,
+            schema_definition,
+            DocumentSchemaCache::Shared(schema_cache),
+        )
+    }
+
+    fn with_cow_schema_cache(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        schema_cache: DocumentSchemaCache<'a>,
+    ) -> Self {
         let variable_definition_input_types =
             HashMap::from_iter(executable_document.operation_definitions().flat_map(
                 |operation_definition: &'a E::OperationDefinition| {
@@ -46,6 +84,8 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> Cache<'a, E, S> {
         Self {
             variable_definition_input_types,
             indexed_fragment_definitions,
+            schema_definition,
+            schema_cache,
         }
     }
 
@@ -60,4 +100,13 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> Cache<'a, E, S> {
     pub fn fragment_definition(&self, name: &str) -> Option<&'a E::FragmentDefinition> {
         self.indexed_fragment_definitions.get(name).copied()
     }
+
+    pub fn schema_cache(&self) -> &SchemaCache<'a> {
+        match &self.schema_cache {
+            DocumentSchemaCache::Shared(schema_cache) => schema_cache,
+            DocumentSchemaCache::Lazy(schema_cache) => {
+                schema_cache.get_or_init(|| SchemaCache::new(self.schema_definition))
+            }
+        }
+    }
 }
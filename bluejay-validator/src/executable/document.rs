@@ -1,16 +1,23 @@
 mod analyzer;
+mod batch;
+mod dynamic_rules;
 mod error;
+pub mod lint;
 mod orchestrator;
 mod path;
+mod project;
 mod rule;
 pub mod rules;
 mod variable_definition_input_type;
 mod visitor;
 
 pub use analyzer::Analyzer;
+pub use batch::DocumentBatch;
+pub use dynamic_rules::{BuiltinRule, DynamicRules, RuleSet, UnknownRuleError};
 pub use error::{ArgumentError, DirectiveError, Error};
 pub use orchestrator::{BuiltinRulesValidator, Orchestrator};
 pub use path::{Path, PathRoot};
+pub use project::{Project, ProjectDocument};
 pub use rule::{Rule, RuleErrorAdapter};
 pub use rules::BuiltinRules;
 pub use variable_definition_input_type::VariableDefinitionInputType;
@@ -0,0 +1,271 @@
+use crate::executable::{
+    document::{Orchestrator, ProjectDocument, Rule},
+    Cache, SchemaCache,
+};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::ExecutableDocument;
+use bluejay_core::Indexed;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use std::collections::HashSet;
+
+/// Many executable documents validated against the same schema, such as a set of persisted
+/// queries checked against a new version of the schema.
+///
+/// Every document gets its own [`Cache`], all built on top of the same [`SchemaCache`].
+/// With the `rayon` feature enabled, the `par_` variants of the methods build the caches and
+/// validate the documents in parallel, with the same results as their sequential counterparts.
+pub struct DocumentBatch<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    executable_documents: &'a [E],
+    schema_definition: &'a S,
+    caches: Vec<Cache<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition> DocumentBatch<'a, E, S> {
+    pub fn new(
+        executable_documents: &'a [E],
+        schema_definition: &'a S,
+        schema_cache: &'a SchemaCache<'a>,
+    ) -> Self {
+        let caches = executable_documents
+            .iter()
+            .map(|executable_document| {
+                Cache::with_schema_cache(executable_document, schema_definition, schema_cache)
+            })
+            .collect();
+        Self {
+            executable_documents,
+            schema_definition,
+            caches,
+        }
+    }
+
+    /// Like [`new`](Self::new), building the caches in parallel
+    #[cfg(feature = "rayon")]
+    pub fn par_new(
+        executable_documents: &'a [E],
+        schema_definition: &'a S,
+        schema_cache: &'a SchemaCache<'a>,
+    ) -> Self
+    where
+        E: Sync,
+        S: Sync,
+        Cache<'a, E, S>: Send,
+    {
+        let caches = executable_documents
+            .par_iter()
+            .map(|executable_document| {
+                Cache::with_schema_cache(executable_document, schema_definition, schema_cache)
+            })
+            .collect();
+        Self {
+            executable_documents,
+            schema_definition,
+            caches,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.executable_documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.executable_documents.is_empty()
+    }
+
+    pub(crate) fn schema_definition(&self) -> &'a S {
+        self.schema_definition
+    }
+
+    /// The documents of the batch, in order, along with their caches
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&'a E, &Cache<'a, E, S>)> {
+        self.executable_documents.iter().zip(&self.caches)
+    }
+
+    /// Validates every document of the batch with the rules `V`, returning the errors of each
+    /// document at the same index as the document. The errors of a document are in the same
+    /// order as they would be when validating that document alone.
+    pub fn validate<V: Rule<'a, E, S>>(&'a self) -> Vec<Vec<V::Error>> {
+        self.executable_documents
+            .iter()
+            .zip(&self.caches)
+            .map(|(executable_document, cache)| {
+                self.validate_document::<V>(executable_document, cache)
+            })
+            .collect()
+    }
+
+    /// Like [`validate`](Self::validate), validating the documents in parallel
+    #[cfg(feature = "rayon")]
+    pub fn par_validate<V: Rule<'a, E, S>>(&'a self) -> Vec<Vec<V::Error>>
+    where
+        E: Sync,
+        S: Sync,
+        Cache<'a, E, S>: Sync,
+        V::Error: Send,
+    {
+        self.executable_documents
+            .par_iter()
+            .zip(&self.caches)
+            .map(|(executable_document, cache)| {
+                self.validate_document::<V>(executable_document, cache)
+            })
+            .collect()
+    }
+
+    /// Validates every operation of every document of the batch on its own with the rules `V`,
+    /// along with the fragment definitions it spreads, as with
+    /// [`Orchestrator::validate_operation_definition`]. Returns the errors of the operations of
+    /// each document at the same index as the document, in the order of the operations.
+    /// Fragment definitions that no operation spreads are not validated, and errors within a
+    /// fragment definition are reported for every operation spreading it.
+    pub fn validate_operations<V: Rule<'a, E, S>>(&'a self) -> Vec<Vec<V::Error>> {
+        self.executable_documents
+            .iter()
+            .zip(&self.caches)
+            .map(|(executable_document, cache)| {
+                self.validate_document_operations::<V>(executable_document, cache)
+            })
+            .collect()
+    }
+
+    /// Like [`validate_operations`](Self::validate_operations), validating the documents in
+    /// parallel
+    #[cfg(feature = "rayon")]
+    pub fn par_validate_operations<V: Rule<'a, E, S>>(&'a self) -> Vec<Vec<V::Error>>
+    where
+        E: Sync,
+        S: Sync,
+        Cache<'a, E, S>: Sync,
+        V::Error: Send,
+    {
+        self.executable_documents
+            .par_iter()
+            .zip(&self.caches)
+            .map(|(executable_document, cache)| {
+                self.validate_document_operations::<V>(executable_document, cache)
+            })
+            .collect()
+    }
+
+    fn validate_document<V: Rule<'a, E, S>>(
+        &self,
+        executable_document: &'a E,
+        cache: &'a Cache<'a, E, S>,
+    ) -> Vec<V::Error> {
+        Orchestrator::<'a, E, S, V>::validate(executable_document, self.schema_definition, cache)
+            .collect()
+    }
+
+    fn validate_document_operations<V: Rule<'a, E, S>>(
+        &self,
+        executable_document: &'a E,
+        cache: &'a Cache<'a, E, S>,
+    ) -> Vec<V::Error> {
+        executable_document
+            .operation_definitions()
+            .flat_map(|operation_definition| {
+                Orchestrator::<'a, E, S, V>::validate_operation_definition(
+                    executable_document,
+                    self.schema_definition,
+                    operation_definition,
+                    cache,
+                )
+            })
+            .collect()
+    }
+}
+
+impl<'a, 'b, E: ExecutableDocument, S: SchemaDefinition>
+    DocumentBatch<'a, ProjectDocument<'b, E>, S>
+{
+    /// Validates the documents of a [`Project`](crate::executable::document::Project) with the
+    /// rules `V`, returning the errors of each document at the same index as the document.
+    ///
+    /// Every operation definition is validated on its own along with the fragment definitions
+    /// it spreads, including those defined in other documents, as with
+    /// [`Orchestrator::validate_operation_definition`], so that variable usages within them are
+    /// checked against the operation spreading them. Errors within a fragment definition are
+    /// reported for every operation spreading it. The fragment definitions that no operation of
+    /// their own document spreads are then validated on their own with that document.
+    /// Fragment definitions that no document spreads are reported by
+    /// [`Project::unused_fragment_definitions`](crate::executable::document::Project::unused_fragment_definitions).
+    pub fn validate_project<V: Rule<'a, ProjectDocument<'b, E>, S>>(
+        &'a self,
+    ) -> Vec<Vec<V::Error>> {
+        self.executable_documents
+            .iter()
+            .zip(&self.caches)
+            .map(|(project_document, cache)| {
+                self.validate_project_document::<V>(project_document, cache)
+            })
+            .collect()
+    }
+
+    /// Like [`validate_project`](Self::validate_project), validating the documents in parallel
+    #[cfg(feature = "rayon")]
+    pub fn par_validate_project<V: Rule<'a, ProjectDocument<'b, E>, S>>(
+        &'a self,
+    ) -> Vec<Vec<V::Error>>
+    where
+        ProjectDocument<'b, E>: Sync,
+        S: Sync,
+        Cache<'a, ProjectDocument<'b, E>, S>: Sync,
+        V::Error: Send,
+    {
+        self.executable_documents
+            .par_iter()
+            .zip(&self.caches)
+            .map(|(project_document, cache)| {
+                self.validate_project_document::<V>(project_document, cache)
+            })
+            .collect()
+    }
+
+    fn validate_project_document<V: Rule<'a, ProjectDocument<'b, E>, S>>(
+        &self,
+        project_document: &'a ProjectDocument<'b, E>,
+        cache: &'a Cache<'a, ProjectDocument<'b, E>, S>,
+    ) -> Vec<V::Error> {
+        let mut spread_fragment_definitions = HashSet::new();
+        let mut errors: Vec<V::Error> = project_document
+            .operation_definitions()
+            .flat_map(|operation_definition| {
+                spread_fragment_definitions.extend(
+                    Orchestrator::<'a, ProjectDocument<'b, E>, S, V>::reachable_fragment_definitions(
+                        project_document,
+                        operation_definition,
+                        cache,
+                    )
+                    .into_iter()
+                    .map(Indexed),
+                );
+                Orchestrator::<'a, ProjectDocument<'b, E>, S, V>::validate_operation_definition(
+                    project_document,
+                    self.schema_definition,
+                    operation_definition,
+                    cache,
+                )
+            })
+            .collect();
+        let unspread_fragment_definitions: Vec<&'a E::FragmentDefinition> = project_document
+            .own_fragment_definitions()
+            .iter()
+            .copied()
+            .filter(|fragment_definition| {
+                !spread_fragment_definitions.contains(&Indexed(*fragment_definition))
+            })
+            .collect();
+        if !unspread_fragment_definitions.is_empty() {
+            errors.extend(
+                Orchestrator::<'a, ProjectDocument<'b, E>, S, V>::validate_fragment_definitions(
+                    project_document,
+                    self.schema_definition,
+                    &unspread_fragment_definitions,
+                    cache,
+                ),
+            );
+        }
+        errors
+    }
+}
@@ -0,0 +1,494 @@
+use crate::executable::{
+    document::{rules, Error, Orchestrator, Path, Rule, Visitor},
+    Cache,
+};
+use bluejay_core::definition::{DirectiveLocation, SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::ExecutableDocument;
+use std::str::FromStr;
+
+macro_rules! define_builtin_rule {
+    ($($rule:ident<$($generic:ident),+>),* $(,)?) => {
+        /// Names one of the rules in [`BuiltinRules`](crate::executable::document::BuiltinRules)
+        /// so that rules can be selected at runtime
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum BuiltinRule {
+            $($rule,)*
+        }
+
+        impl BuiltinRule {
+            /// Every builtin rule, in the order that [`BuiltinRules`](crate::executable::document::BuiltinRules) runs them
+            pub const ALL: &'static [Self] = &[$(Self::$rule,)*];
+
+            /// The name of the rule, which is the name of the type implementing it
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$rule => stringify!($rule),)*
+                }
+            }
+
+            fn build<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a>(
+                &self,
+                executable_document: &'a E,
+                schema_definition: &'a S,
+                cache: &'a Cache<'a, E, S>,
+            ) -> DynamicRule<'a, E, S> {
+                match self {
+                    $(Self::$rule => DynamicRule::$rule(Visitor::new(
+                        executable_document,
+                        schema_definition,
+                        cache,
+                    )),)*
+                }
+            }
+        }
+
+        enum DynamicRule<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> {
+            $($rule(rules::$rule<'a, $($generic),+>),)*
+        }
+
+        impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> DynamicRule<'a, E, S> {
+            fn restrict_to_operation(&mut self, operation_definition: &'a E::OperationDefinition, fragment_definitions: &[&'a E::FragmentDefinition]) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::restrict_to_operation(rule, operation_definition, fragment_definitions),)*
+                }
+            }
+
+            fn restrict_to_fragment_definitions(&mut self, fragment_definitions: &[&'a E::FragmentDefinition]) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::restrict_to_fragment_definitions(rule, fragment_definitions),)*
+                }
+            }
+
+            fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_operation_definition(rule, operation_definition),)*
+                }
+            }
+
+            fn visit_selection_set(&mut self, selection_set: &'a E::SelectionSet, r#type: TypeDefinitionReference<'a, S::TypeDefinition>) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_selection_set(rule, selection_set, r#type),)*
+                }
+            }
+
+            fn visit_field(&mut self, field: &'a E::Field, field_definition: &'a S::FieldDefinition, path: &Path<'a, E>) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_field(rule, field, field_definition, path),)*
+                }
+            }
+
+            fn visit_const_directive(&mut self, directive: &'a E::Directive<true>, location: DirectiveLocation) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_const_directive(rule, directive, location),)*
+                }
+            }
+
+            fn visit_variable_directive(&mut self, directive: &'a E::Directive<false>, location: DirectiveLocation) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_variable_directive(rule, directive, location),)*
+                }
+            }
+
+            fn visit_const_directives(&mut self, directives: &'a E::Directives<true>, location: DirectiveLocation) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_const_directives(rule, directives, location),)*
+                }
+            }
+
+            fn visit_variable_directives(&mut self, directives: &'a E::Directives<false>, location: DirectiveLocation) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_variable_directives(rule, directives, location),)*
+                }
+            }
+
+            fn visit_fragment_definition(&mut self, fragment_definition: &'a E::FragmentDefinition) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_fragment_definition(rule, fragment_definition),)*
+                }
+            }
+
+            fn visit_inline_fragment(&mut self, inline_fragment: &'a E::InlineFragment, scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_inline_fragment(rule, inline_fragment, scoped_type),)*
+                }
+            }
+
+            fn visit_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>, path: &Path<'a, E>) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_fragment_spread(rule, fragment_spread, scoped_type, path),)*
+                }
+            }
+
+            fn visit_const_argument(&mut self, argument: &'a E::Argument<true>, input_value_definition: &'a S::InputValueDefinition) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_const_argument(rule, argument, input_value_definition),)*
+                }
+            }
+
+            fn visit_variable_argument(&mut self, argument: &'a E::Argument<false>, input_value_definition: &'a S::InputValueDefinition, path: &Path<'a, E>) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_variable_argument(rule, argument, input_value_definition, path),)*
+                }
+            }
+
+            fn visit_variable_definition(&mut self, variable_definition: &'a E::VariableDefinition) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_variable_definition(rule, variable_definition),)*
+                }
+            }
+
+            fn visit_variable_definitions(&mut self, variable_definitions: &'a E::VariableDefinitions) {
+                match self {
+                    $(Self::$rule(rule) => Visitor::<'a, E, S>::visit_variable_definitions(rule, variable_definitions),)*
+                }
+            }
+
+            fn extend_errors(self, errors: &mut Vec<Error<'a, E, S>>) {
+                match self {
+                    $(Self::$rule(rule) => errors.extend(Rule::<'a, E, S>::into_errors(rule)),)*
+                }
+            }
+        }
+    };
+}
+
+define_builtin_rule!(
+    NamedOperationNameUniqueness<E>,
+    LoneAnonymousOperation<E>,
+    SubscriptionOperationSingleRootField<E>,
+    FieldSelections<E, S>,
+    FieldSelectionMerging<E, S>,
+    OperationTypeIsDefined<E, S>,
+    LeafFieldSelections<E, S>,
+    ArgumentNames<E, S>,
+    ArgumentUniqueness<E, S>,
+    RequiredArguments<E, S>,
+    FragmentNameUniqueness<E>,
+    FragmentSpreadTypeExists<E, S>,
+    FragmentsOnCompositeTypes<E, S>,
+    FragmentsMustBeUsed<E>,
+    FragmentSpreadTargetDefined<E, S>,
+    FragmentSpreadsMustNotFormCycles<E, S>,
+    FragmentSpreadIsPossible<E, S>,
+    ValueIsValid<E, S>,
+    DirectivesAreDefined<E, S>,
+    DirectivesAreInValidLocations<E, S>,
+    DirectivesAreUniquePerLocation<E, S>,
+    DeferAndStreamDirectivesOnValidRootField<E, S>,
+    StreamDirectivesOnListFields<E, S>,
+    DeferAndStreamDirectiveLabelsAreUnique<E, S>,
+    VariableUniqueness<E, S>,
+    VariablesAreInputTypes<E, S>,
+    AllVariableUsesDefined<E, S>,
+    AllVariablesUsed<E, S>,
+    AllVariableUsagesAllowed<E, S>,
+);
+
+impl std::fmt::Display for BuiltinRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for BuiltinRule {
+    type Err = UnknownRuleError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|rule| rule.name() == name)
+            .copied()
+            .ok_or_else(|| UnknownRuleError {
+                name: name.to_owned(),
+            })
+    }
+}
+
+/// Returned when a rule name does not match any [`BuiltinRule`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownRuleError {
+    name: String,
+}
+
+impl UnknownRuleError {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl std::fmt::Display for UnknownRuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "No builtin rule named `{}`", self.name)
+    }
+}
+
+impl std::error::Error for UnknownRuleError {}
+
+/// A selection of [`BuiltinRule`]s that can be built from configuration at runtime.
+///
+/// Rules always run in the same order as [`BuiltinRules`](crate::executable::document::BuiltinRules),
+/// regardless of the order they were added in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleSet {
+    rules: Vec<BuiltinRule>,
+}
+
+impl RuleSet {
+    /// A rule set containing every builtin rule
+    pub fn all() -> Self {
+        Self::from_iter(BuiltinRule::ALL.iter().copied())
+    }
+
+    /// Builds a rule set from rule names, such as `FieldSelectionMerging`
+    pub fn from_names<'n>(
+        names: impl IntoIterator<Item = &'n str>,
+    ) -> Result<Self, UnknownRuleError> {
+        names.into_iter().map(BuiltinRule::from_str).collect()
+    }
+
+    pub fn with_rule(mut self, rule: BuiltinRule) -> Self {
+        if let Err(idx) = self.rules.binary_search(&rule) {
+            self.rules.insert(idx, rule);
+        }
+        self
+    }
+
+    pub fn without_rule(mut self, rule: BuiltinRule) -> Self {
+        self.rules.retain(|r| *r != rule);
+        self
+    }
+
+    pub fn contains(&self, rule: BuiltinRule) -> bool {
+        self.rules.binary_search(&rule).is_ok()
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = BuiltinRule> + '_ {
+        self.rules.iter().copied()
+    }
+
+    /// Validates `executable_document` against only the rules in this set
+    pub fn validate<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a>(
+        &self,
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        cache: &'a Cache<'a, E, S>,
+    ) -> std::vec::IntoIter<Error<'a, E, S>> {
+        Orchestrator::validate_with(
+            executable_document,
+            schema_definition,
+            DynamicRules::from_rule_set(self, executable_document, schema_definition, cache),
+        )
+    }
+}
+
+impl FromIterator<BuiltinRule> for RuleSet {
+    fn from_iter<T: IntoIterator<Item = BuiltinRule>>(iter: T) -> Self {
+        let mut rules = Vec::from_iter(iter);
+        rules.sort_unstable();
+        rules.dedup();
+        Self { rules }
+    }
+}
+
+/// Rules selected at runtime, dispatched through an enum of the builtin rules.
+///
+/// Constructing this through [`Visitor::new`] enables every builtin rule, which makes it a
+/// drop-in replacement for [`BuiltinRules`](crate::executable::document::BuiltinRules).
+pub struct DynamicRules<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    rules: Vec<DynamicRule<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> DynamicRules<'a, E, S> {
+    pub fn from_rule_set(
+        rule_set: &RuleSet,
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        cache: &'a Cache<'a, E, S>,
+    ) -> Self {
+        Self {
+            rules: rule_set
+                .rules()
+                .map(|rule| rule.build(executable_document, schema_definition, cache))
+                .collect(),
+        }
+    }
+}
+
+#[warn(clippy::missing_trait_methods)]
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
+    for DynamicRules<'a, E, S>
+{
+    fn new(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        cache: &'a Cache<'a, E, S>,
+    ) -> Self {
+        Self::from_rule_set(
+            &RuleSet::all(),
+            executable_document,
+            schema_definition,
+            cache,
+        )
+    }
+
+    fn restrict_to_operation(
+        &mut self,
+        operation_definition: &'a E::OperationDefinition,
+        fragment_definitions: &[&'a E::FragmentDefinition],
+    ) {
+        self.rules.iter_mut().for_each(|rule| {
+            rule.restrict_to_operation(operation_definition, fragment_definitions)
+        });
+    }
+
+    fn restrict_to_fragment_definitions(
+        &mut self,
+        fragment_definitions: &[&'a E::FragmentDefinition],
+    ) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.restrict_to_fragment_definitions(fragment_definitions));
+    }
+
+    fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_operation_definition(operation_definition));
+    }
+
+    fn visit_selection_set(
+        &mut self,
+        selection_set: &'a E::SelectionSet,
+        r#type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_selection_set(selection_set, r#type));
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        path: &Path<'a, E>,
+    ) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_field(field, field_definition, path));
+    }
+
+    fn visit_const_directive(
+        &mut self,
+        directive: &'a E::Directive<true>,
+        location: DirectiveLocation,
+    ) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_const_directive(directive, location));
+    }
+
+    fn visit_variable_directive(
+        &mut self,
+        directive: &'a E::Directive<false>,
+        location: DirectiveLocation,
+    ) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_variable_directive(directive, location));
+    }
+
+    fn visit_const_directives(
+        &mut self,
+        directives: &'a E::Directives<true>,
+        location: DirectiveLocation,
+    ) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_const_directives(directives, location));
+    }
+
+    fn visit_variable_directives(
+        &mut self,
+        directives: &'a E::Directives<false>,
+        location: DirectiveLocation,
+    ) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_variable_directives(directives, location));
+    }
+
+    fn visit_fragment_definition(&mut self, fragment_definition: &'a E::FragmentDefinition) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_fragment_definition(fragment_definition));
+    }
+
+    fn visit_inline_fragment(
+        &mut self,
+        inline_fragment: &'a E::InlineFragment,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_inline_fragment(inline_fragment, scoped_type));
+    }
+
+    fn visit_fragment_spread(
+        &mut self,
+        fragment_spread: &'a E::FragmentSpread,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        path: &Path<'a, E>,
+    ) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_fragment_spread(fragment_spread, scoped_type, path));
+    }
+
+    fn visit_const_argument(
+        &mut self,
+        argument: &'a E::Argument<true>,
+        input_value_definition: &'a S::InputValueDefinition,
+    ) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_const_argument(argument, input_value_definition));
+    }
+
+    fn visit_variable_argument(
+        &mut self,
+        argument: &'a E::Argument<false>,
+        input_value_definition: &'a S::InputValueDefinition,
+        path: &Path<'a, E>,
+    ) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_variable_argument(argument, input_value_definition, path));
+    }
+
+    fn visit_variable_definition(&mut self, variable_definition: &'a E::VariableDefinition) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_variable_definition(variable_definition));
+    }
+
+    fn visit_variable_definitions(&mut self, variable_definitions: &'a E::VariableDefinitions) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.visit_variable_definitions(variable_definitions));
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for DynamicRules<'a, E, S>
+{
+    type Error = Error<'a, E, S>;
+    type Errors = std::vec::IntoIter<Error<'a, E, S>>;
+
+    fn into_errors(self) -> Self::Errors {
+        let mut errors = Vec::new();
+        self.rules
+            .into_iter()
+            .for_each(|rule| rule.extend_errors(&mut errors));
+        errors.into_iter()
+    }
+}
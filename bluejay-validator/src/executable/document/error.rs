@@ -1,9 +1,14 @@
+#[cfg(feature = "parser-integration")]
+use crate::utils::with_did_you_mean;
 use crate::value::input_coercion::Error as InputCoercionError;
 use bluejay_core::definition::{
     FieldDefinition, InputType, OutputType, SchemaDefinition, TypeDefinitionReference,
 };
-use bluejay_core::executable::{ExecutableDocument, OperationDefinition, VariableType};
-use bluejay_core::{OperationType, Value};
+use bluejay_core::executable::{
+    ExecutableDocument, ExplicitOperationDefinition, Field, FragmentDefinition, FragmentSpread,
+    InlineFragment, OperationDefinition, VariableDefinition, VariableType,
+};
+use bluejay_core::{Directive, OperationType, Value, Variable};
 #[cfg(feature = "parser-integration")]
 use bluejay_parser::{
     ast::executable::ExecutableDocument as ParserExecutableDocument,
@@ -32,6 +37,7 @@ pub enum Error<'a, E: ExecutableDocument, S: SchemaDefinition> {
     FieldDoesNotExistOnType {
         field: &'a E::Field,
         r#type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        suggestions: Vec<&'a str>,
     },
     OperationTypeNotDefined {
         operation: &'a E::ExplicitOperationDefinition,
@@ -65,6 +71,7 @@ pub enum Error<'a, E: ExecutableDocument, S: SchemaDefinition> {
     },
     FragmentSpreadTargetUndefined {
         fragment_spread: &'a E::FragmentSpread,
+        suggestions: Vec<&'a str>,
     },
     FragmentSpreadCycle {
         fragment_definition: &'a E::FragmentDefinition,
@@ -87,6 +94,11 @@ pub enum Error<'a, E: ExecutableDocument, S: SchemaDefinition> {
         field_a: &'a E::Field,
         field_b: &'a E::Field,
     },
+    FieldSelectionsDoNotMergeDifferingStreamDirectives {
+        selection_set: &'a E::SelectionSet,
+        field_a: &'a E::Field,
+        field_b: &'a E::Field,
+    },
     FragmentSpreadIsNotPossible {
         fragment_spread: &'a E::FragmentSpread,
         parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
@@ -125,12 +137,307 @@ pub enum Error<'a, E: ExecutableDocument, S: SchemaDefinition> {
         variable_type: &'a E::VariableType,
         parent_type_name: &'a str,
     },
+    DeferOrStreamDirectiveOnSubscriptionRoot {
+        directive: &'a E::Directive<false>,
+    },
+    StreamDirectiveOnNonListField {
+        directive: &'a E::Directive<false>,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+    },
+    DeferOrStreamDirectiveLabelNotStatic {
+        directive: &'a E::Directive<false>,
+        label: &'a E::Value<false>,
+    },
+    NonUniqueDeferAndStreamDirectiveLabels {
+        label: &'a str,
+        directives: Vec<&'a E::Directive<false>>,
+    },
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition> Error<'a, E, S> {
+    /// A stable, machine-readable code identifying the kind of error, e.g. `FIELD_NOT_DEFINED`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NonUniqueOperationNames { .. } => "OPERATION_NAME_NOT_UNIQUE",
+            Self::NotLoneAnonymousOperation { .. } => "ANONYMOUS_OPERATION_NOT_ALONE",
+            Self::SubscriptionRootNotSingleField { .. } => "SUBSCRIPTION_ROOT_NOT_SINGLE_FIELD",
+            Self::FieldDoesNotExistOnType { .. } => "FIELD_NOT_DEFINED",
+            Self::OperationTypeNotDefined { .. } => "OPERATION_TYPE_NOT_DEFINED",
+            Self::LeafFieldSelectionNotEmpty { .. } => "LEAF_FIELD_SELECTION_NOT_EMPTY",
+            Self::NonLeafFieldSelectionEmpty { .. } => "NON_LEAF_FIELD_SELECTION_EMPTY",
+            Self::NonUniqueFragmentDefinitionNames { .. } => "FRAGMENT_NAME_NOT_UNIQUE",
+            Self::FragmentDefinitionTargetTypeDoesNotExist { .. }
+            | Self::InlineFragmentTargetTypeDoesNotExist { .. } => "FRAGMENT_TYPE_NOT_DEFINED",
+            Self::FragmentDefinitionTargetTypeNotComposite { .. }
+            | Self::InlineFragmentTargetTypeNotComposite { .. } => "FRAGMENT_TYPE_NOT_COMPOSITE",
+            Self::FragmentDefinitionUnused { .. } => "FRAGMENT_UNUSED",
+            Self::FragmentSpreadTargetUndefined { .. } => "FRAGMENT_NOT_DEFINED",
+            Self::FragmentSpreadCycle { .. } => "FRAGMENT_SPREAD_CYCLE",
+            Self::FieldSelectionsDoNotMergeIncompatibleTypes { .. } => {
+                "FIELD_SELECTIONS_INCOMPATIBLE_TYPES"
+            }
+            Self::FieldSelectionsDoNotMergeDifferingNames { .. } => {
+                "FIELD_SELECTIONS_DIFFERING_NAMES"
+            }
+            Self::FieldSelectionsDoNotMergeDifferingArguments { .. } => {
+                "FIELD_SELECTIONS_DIFFERING_ARGUMENTS"
+            }
+            Self::FieldSelectionsDoNotMergeDifferingStreamDirectives { .. } => {
+                "FIELD_SELECTIONS_DIFFERING_STREAM_DIRECTIVES"
+            }
+            Self::FragmentSpreadIsNotPossible { .. }
+            | Self::InlineFragmentSpreadIsNotPossible { .. } => "FRAGMENT_SPREAD_NOT_POSSIBLE",
+            Self::InvalidConstValue(error) => error.code(),
+            Self::InvalidVariableValue(error) => error.code(),
+            Self::InvalidConstDirective(error) => error.code(),
+            Self::InvalidVariableDirective(error) => error.code(),
+            Self::InvalidConstArgument(error) => error.code(),
+            Self::InvalidVariableArgument(error) => error.code(),
+            Self::NonUniqueVariableDefinitionNames { .. } => "VARIABLE_NAME_NOT_UNIQUE",
+            Self::VariableDefinitionTypeNotInput { .. } => "VARIABLE_TYPE_NOT_INPUT",
+            Self::VariableNotDefined { .. } => "VARIABLE_NOT_DEFINED",
+            Self::VariableDefinitionUnused { .. } => "VARIABLE_UNUSED",
+            Self::InvalidVariableUsage { .. } => "VARIABLE_USAGE_NOT_ALLOWED",
+            Self::InvalidOneOfVariableUsage { .. } => "ONE_OF_VARIABLE_USAGE_NOT_ALLOWED",
+            Self::DeferOrStreamDirectiveOnSubscriptionRoot { .. } => {
+                "DEFER_STREAM_ON_SUBSCRIPTION_ROOT"
+            }
+            Self::StreamDirectiveOnNonListField { .. } => "STREAM_ON_NON_LIST_FIELD",
+            Self::DeferOrStreamDirectiveLabelNotStatic { .. } => "DEFER_STREAM_LABEL_NOT_STATIC",
+            Self::NonUniqueDeferAndStreamDirectiveLabels { .. } => "DEFER_STREAM_LABEL_NOT_UNIQUE",
+        }
+    }
+
+    /// The incremental delivery RFC defining `@defer` and `@stream`, which are not part of the
+    /// specification draft yet
+    const INCREMENTAL_DELIVERY_RFC: &'static str =
+        "https://github.com/graphql/graphql-wg/blob/main/rfcs/DeferStream.md";
+
+    /// Link to the validation rule in the GraphQL specification that the error violates, or to
+    /// the incremental delivery RFC for the rules of `@defer` and `@stream`
+    pub fn spec_section(&self) -> &'static str {
+        match self {
+            Self::NonUniqueOperationNames { .. } => spec_section!("Operation-Name-Uniqueness"),
+            Self::NotLoneAnonymousOperation { .. } => spec_section!("Lone-Anonymous-Operation"),
+            Self::SubscriptionRootNotSingleField { .. } => spec_section!("Single-root-field"),
+            Self::FieldDoesNotExistOnType { .. } => spec_section!("Field-Selections"),
+            Self::OperationTypeNotDefined { .. } => spec_section!("Operation-Type-Existence"),
+            Self::LeafFieldSelectionNotEmpty { .. } | Self::NonLeafFieldSelectionEmpty { .. } => {
+                spec_section!("Leaf-Field-Selections")
+            }
+            Self::NonUniqueFragmentDefinitionNames { .. } => {
+                spec_section!("Fragment-Name-Uniqueness")
+            }
+            Self::FragmentDefinitionTargetTypeDoesNotExist { .. }
+            | Self::InlineFragmentTargetTypeDoesNotExist { .. } => {
+                spec_section!("Fragment-Spread-Type-Existence")
+            }
+            Self::FragmentDefinitionTargetTypeNotComposite { .. }
+            | Self::InlineFragmentTargetTypeNotComposite { .. } => {
+                spec_section!("Fragments-On-Composite-Types")
+            }
+            Self::FragmentDefinitionUnused { .. } => spec_section!("Fragments-Must-Be-Used"),
+            Self::FragmentSpreadTargetUndefined { .. } => {
+                spec_section!("Fragment-spread-target-defined")
+            }
+            Self::FragmentSpreadCycle { .. } => {
+                spec_section!("Fragment-spreads-must-not-form-cycles")
+            }
+            Self::FieldSelectionsDoNotMergeIncompatibleTypes { .. }
+            | Self::FieldSelectionsDoNotMergeDifferingNames { .. }
+            | Self::FieldSelectionsDoNotMergeDifferingArguments { .. }
+            | Self::FieldSelectionsDoNotMergeDifferingStreamDirectives { .. } => {
+                spec_section!("Field-Selection-Merging")
+            }
+            Self::FragmentSpreadIsNotPossible { .. }
+            | Self::InlineFragmentSpreadIsNotPossible { .. } => {
+                spec_section!("Fragment-spread-is-possible")
+            }
+            Self::InvalidConstValue(error) => error.spec_section(),
+            Self::InvalidVariableValue(error) => error.spec_section(),
+            Self::InvalidConstDirective(error) => error.spec_section(),
+            Self::InvalidVariableDirective(error) => error.spec_section(),
+            Self::InvalidConstArgument(error) => error.spec_section(),
+            Self::InvalidVariableArgument(error) => error.spec_section(),
+            Self::NonUniqueVariableDefinitionNames { .. } => spec_section!("Variable-Uniqueness"),
+            Self::VariableDefinitionTypeNotInput { .. } => {
+                spec_section!("Variables-Are-Input-Types")
+            }
+            Self::VariableNotDefined { .. } => spec_section!("All-Variable-Uses-Defined"),
+            Self::VariableDefinitionUnused { .. } => spec_section!("All-Variables-Used"),
+            Self::InvalidVariableUsage { .. } | Self::InvalidOneOfVariableUsage { .. } => {
+                spec_section!("All-Variable-Usages-are-Allowed")
+            }
+            Self::DeferOrStreamDirectiveOnSubscriptionRoot { .. }
+            | Self::StreamDirectiveOnNonListField { .. }
+            | Self::DeferOrStreamDirectiveLabelNotStatic { .. }
+            | Self::NonUniqueDeferAndStreamDirectiveLabels { .. } => Self::INCREMENTAL_DELIVERY_RFC,
+        }
+    }
+
+    /// Structured details about the error, such as the names of the types and fields involved
+    pub fn extensions(&self) -> Vec<(&'static str, Cow<'a, str>)> {
+        match self {
+            Self::NonUniqueOperationNames { name, .. } => {
+                vec![("operationName", Cow::Borrowed(*name))]
+            }
+            Self::NotLoneAnonymousOperation { .. } => Vec::new(),
+            Self::SubscriptionRootNotSingleField { operation } => operation
+                .as_ref()
+                .name()
+                .map(|name| ("operationName", Cow::Borrowed(name)))
+                .into_iter()
+                .collect(),
+            Self::FieldDoesNotExistOnType { field, r#type, .. } => vec![
+                ("typeName", Cow::Borrowed(r#type.name())),
+                ("fieldName", Cow::Borrowed(field.name())),
+            ],
+            Self::OperationTypeNotDefined { operation } => vec![(
+                "operationType",
+                Cow::Owned(operation.operation_type().to_string()),
+            )],
+            Self::LeafFieldSelectionNotEmpty { r#type, .. } => {
+                vec![("typeName", Cow::Borrowed(r#type.base_name()))]
+            }
+            Self::NonLeafFieldSelectionEmpty { field, r#type } => vec![
+                ("typeName", Cow::Borrowed(r#type.base_name())),
+                ("fieldName", Cow::Borrowed(field.name())),
+            ],
+            Self::NonUniqueFragmentDefinitionNames { name, .. } => {
+                vec![("fragmentName", Cow::Borrowed(*name))]
+            }
+            Self::FragmentDefinitionTargetTypeDoesNotExist {
+                fragment_definition,
+            }
+            | Self::FragmentDefinitionTargetTypeNotComposite {
+                fragment_definition,
+            } => vec![
+                ("fragmentName", Cow::Borrowed(fragment_definition.name())),
+                (
+                    "typeName",
+                    Cow::Borrowed(fragment_definition.type_condition()),
+                ),
+            ],
+            Self::InlineFragmentTargetTypeDoesNotExist { inline_fragment }
+            | Self::InlineFragmentTargetTypeNotComposite { inline_fragment } => inline_fragment
+                .type_condition()
+                .map(|type_condition| ("typeName", Cow::Borrowed(type_condition)))
+                .into_iter()
+                .collect(),
+            Self::FragmentDefinitionUnused {
+                fragment_definition,
+            }
+            | Self::FragmentSpreadCycle {
+                fragment_definition,
+                ..
+            } => vec![("fragmentName", Cow::Borrowed(fragment_definition.name()))],
+            Self::FragmentSpreadTargetUndefined {
+                fragment_spread, ..
+            } => vec![("fragmentName", Cow::Borrowed(fragment_spread.name()))],
+            Self::FieldSelectionsDoNotMergeIncompatibleTypes { field_a, .. }
+            | Self::FieldSelectionsDoNotMergeDifferingNames { field_a, .. }
+            | Self::FieldSelectionsDoNotMergeDifferingArguments { field_a, .. }
+            | Self::FieldSelectionsDoNotMergeDifferingStreamDirectives { field_a, .. } => {
+                vec![("responseName", Cow::Borrowed(field_a.response_name()))]
+            }
+            Self::FragmentSpreadIsNotPossible {
+                fragment_spread,
+                parent_type,
+            } => vec![
+                ("fragmentName", Cow::Borrowed(fragment_spread.name())),
+                ("typeName", Cow::Borrowed(parent_type.name())),
+            ],
+            Self::InlineFragmentSpreadIsNotPossible { parent_type, .. } => {
+                vec![("typeName", Cow::Borrowed(parent_type.name()))]
+            }
+            Self::InvalidConstValue(error) => error.extensions(),
+            Self::InvalidVariableValue(error) => error.extensions(),
+            Self::InvalidConstDirective(error) => error.extensions(),
+            Self::InvalidVariableDirective(error) => error.extensions(),
+            Self::InvalidConstArgument(error) => error.extensions(),
+            Self::InvalidVariableArgument(error) => error.extensions(),
+            Self::NonUniqueVariableDefinitionNames { name, .. } => {
+                vec![("variableName", Cow::Borrowed(*name))]
+            }
+            Self::VariableDefinitionTypeNotInput {
+                variable_definition,
+            } => vec![
+                (
+                    "variableName",
+                    Cow::Borrowed(variable_definition.variable()),
+                ),
+                (
+                    "typeName",
+                    Cow::Borrowed(variable_definition.r#type().as_ref().name()),
+                ),
+            ],
+            Self::VariableNotDefined {
+                variable,
+                operation_definition,
+            } => std::iter::once(("variableName", Cow::Borrowed(variable.name())))
+                .chain(
+                    operation_definition
+                        .as_ref()
+                        .name()
+                        .map(|name| ("operationName", Cow::Borrowed(name))),
+                )
+                .collect(),
+            Self::VariableDefinitionUnused {
+                variable_definition,
+            } => vec![(
+                "variableName",
+                Cow::Borrowed(variable_definition.variable()),
+            )],
+            Self::InvalidVariableUsage {
+                variable,
+                location_type,
+                ..
+            } => vec![
+                ("variableName", Cow::Borrowed(variable.name())),
+                ("typeName", Cow::Owned(location_type.display_name())),
+            ],
+            Self::InvalidOneOfVariableUsage {
+                variable,
+                parent_type_name,
+                ..
+            } => vec![
+                ("variableName", Cow::Borrowed(variable.name())),
+                ("typeName", Cow::Borrowed(*parent_type_name)),
+            ],
+            Self::DeferOrStreamDirectiveOnSubscriptionRoot { directive }
+            | Self::DeferOrStreamDirectiveLabelNotStatic { directive, .. } => {
+                vec![("directiveName", Cow::Borrowed(directive.name()))]
+            }
+            Self::StreamDirectiveOnNonListField {
+                field,
+                field_definition,
+                ..
+            } => vec![
+                ("fieldName", Cow::Borrowed(field.name())),
+                (
+                    "typeName",
+                    Cow::Owned(field_definition.r#type().display_name()),
+                ),
+            ],
+            Self::NonUniqueDeferAndStreamDirectiveLabels { label, .. } => {
+                vec![("label", Cow::Borrowed(*label))]
+            }
+        }
+    }
 }
 
 #[cfg(feature = "parser-integration")]
 impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> for ParserError {
     fn from(value: Error<'a, ParserExecutableDocument<'a>, S>) -> Self {
-        match value {
+        let code = value.code();
+        let spec_section = value.spec_section();
+        let extensions: Vec<_> = value
+            .extensions()
+            .into_iter()
+            .map(|(key, value)| (key, value.into_owned()))
+            .collect();
+        let parser_error = match value {
             Error::NonUniqueOperationNames { name, operations } => Self::new(
                 format!("Multiple operation definitions named `{name}`"),
                 None,
@@ -169,14 +476,24 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                 )),
                 Vec::new(),
             ),
-            Error::FieldDoesNotExistOnType { field, r#type } => Self::new(
-                format!(
-                    "Field `{}` does not exist on type `{}`",
-                    field.name().as_ref(),
-                    r#type.name()
+            Error::FieldDoesNotExistOnType {
+                field,
+                r#type,
+                suggestions,
+            } => Self::new(
+                with_did_you_mean(
+                    format!(
+                        "Field `{}` does not exist on type `{}`",
+                        field.name().as_ref(),
+                        r#type.name()
+                    ),
+                    &suggestions,
                 ),
                 Some(Annotation::new(
-                    format!("Field does not exist on type `{}`", r#type.name()),
+                    with_did_you_mean(
+                        format!("Field does not exist on type `{}`", r#type.name()),
+                        &suggestions,
+                    ),
                     field.name().span().clone(),
                 )),
                 Vec::new(),
@@ -312,13 +629,19 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                 )),
                 Vec::new(),
             ),
-            Error::FragmentSpreadTargetUndefined { fragment_spread } => Self::new(
-                format!(
-                    "No fragment defined with name `{}`",
-                    fragment_spread.name().as_ref()
+            Error::FragmentSpreadTargetUndefined {
+                fragment_spread,
+                suggestions,
+            } => Self::new(
+                with_did_you_mean(
+                    format!(
+                        "No fragment defined with name `{}`",
+                        fragment_spread.name().as_ref()
+                    ),
+                    &suggestions,
                 ),
                 Some(Annotation::new(
-                    "No fragment defined with this name",
+                    with_did_you_mean("No fragment defined with this name", &suggestions),
                     fragment_spread.name().span().clone(),
                 )),
                 Vec::new(),
@@ -355,6 +678,21 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                     Annotation::new("Second field", field_b.name().span().clone()),
                 ],
             ),
+            Error::FieldSelectionsDoNotMergeDifferingStreamDirectives {
+                selection_set,
+                field_a,
+                field_b,
+            } => Self::new(
+                "Fields in selection set do not merge due to differing stream directives",
+                Some(Annotation::new(
+                    "Fields in selection set do not merge",
+                    selection_set.span().clone(),
+                )),
+                vec![
+                    Annotation::new("First field", field_a.name().span().clone()),
+                    Annotation::new("Second field", field_b.name().span().clone()),
+                ],
+            ),
             Error::FieldSelectionsDoNotMergeDifferingNames {
                 selection_set,
                 field_a,
@@ -546,6 +884,61 @@ impl<'a, S: SchemaDefinition> From<Error<'a, ParserExecutableDocument<'a>, S>> f
                 )),
                 Vec::new(),
             ),
-        }
+            Error::DeferOrStreamDirectiveOnSubscriptionRoot { directive } => Self::new(
+                format!(
+                    "Directive @{name} cannot be used on the root of a subscription. Disable @{name} by setting the `if` argument to `false`",
+                    name = directive.name().as_ref(),
+                ),
+                Some(Annotation::new(
+                    "Cannot be used on the root of a subscription",
+                    directive.span().clone(),
+                )),
+                Vec::new(),
+            ),
+            Error::StreamDirectiveOnNonListField {
+                directive,
+                field,
+                field_definition,
+            } => Self::new(
+                format!(
+                    "Directive @stream cannot be used on field `{}` of non-list type {}",
+                    field.name().as_ref(),
+                    field_definition.r#type().display_name(),
+                ),
+                Some(Annotation::new(
+                    "Can only be used on fields of list types",
+                    directive.span().clone(),
+                )),
+                Vec::new(),
+            ),
+            Error::DeferOrStreamDirectiveLabelNotStatic { directive, label } => Self::new(
+                format!(
+                    "Label of directive @{} must be a static string, not a variable",
+                    directive.name().as_ref(),
+                ),
+                Some(Annotation::new(
+                    "Label must be a static string",
+                    label.span().clone(),
+                )),
+                Vec::new(),
+            ),
+            Error::NonUniqueDeferAndStreamDirectiveLabels { label, directives } => Self::new(
+                format!("Multiple @defer and @stream directives with label \"{label}\""),
+                None,
+                directives
+                    .into_iter()
+                    .map(|directive| {
+                        Annotation::new(
+                            format!("Directive with label \"{label}\""),
+                            directive.span().clone(),
+                        )
+                    })
+                    .collect(),
+            ),
+        };
+        parser_error
+            .with_code(code)
+            .with_spec_section(spec_section)
+            .with_extensions(extensions)
     }
 }
@@ -1,7 +1,10 @@
+#[cfg(feature = "parser-integration")]
+use crate::utils::with_did_you_mean;
 use bluejay_core::definition::{
     DirectiveDefinition, FieldDefinition, InputValueDefinition, SchemaDefinition,
 };
 use bluejay_core::executable::ExecutableDocument;
+use bluejay_core::Argument;
 #[cfg(feature = "parser-integration")]
 use bluejay_parser::{
     ast::executable::ExecutableDocument as ParserExecutableDocument,
@@ -9,6 +12,7 @@ use bluejay_parser::{
     HasSpan,
 };
 use itertools::Itertools;
+use std::borrow::Cow;
 
 pub enum ArgumentError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDefinition> {
     NonUniqueArgumentNames {
@@ -18,10 +22,12 @@ pub enum ArgumentError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDe
     ArgumentDoesNotExistOnField {
         argument: &'a E::Argument<CONST>,
         field_definition: &'a S::FieldDefinition,
+        suggestions: Vec<&'a str>,
     },
     ArgumentDoesNotExistOnDirective {
         argument: &'a E::Argument<CONST>,
         directive_definition: &'a S::DirectiveDefinition,
+        suggestions: Vec<&'a str>,
     },
     DirectiveMissingRequiredArguments {
         directive: &'a E::Directive<CONST>,
@@ -35,12 +41,102 @@ pub enum ArgumentError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDe
     },
 }
 
+impl<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDefinition>
+    ArgumentError<'a, CONST, E, S>
+{
+    /// A stable, machine-readable code identifying the kind of error, e.g. `ARGUMENT_NOT_DEFINED`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NonUniqueArgumentNames { .. } => "ARGUMENT_NAME_NOT_UNIQUE",
+            Self::ArgumentDoesNotExistOnField { .. }
+            | Self::ArgumentDoesNotExistOnDirective { .. } => "ARGUMENT_NOT_DEFINED",
+            Self::DirectiveMissingRequiredArguments { .. }
+            | Self::FieldMissingRequiredArguments { .. } => "REQUIRED_ARGUMENT_MISSING",
+        }
+    }
+
+    /// Link to the validation rule in the GraphQL specification that the error violates
+    pub fn spec_section(&self) -> &'static str {
+        match self {
+            Self::NonUniqueArgumentNames { .. } => spec_section!("Argument-Uniqueness"),
+            Self::ArgumentDoesNotExistOnField { .. }
+            | Self::ArgumentDoesNotExistOnDirective { .. } => spec_section!("Argument-Names"),
+            Self::DirectiveMissingRequiredArguments { .. }
+            | Self::FieldMissingRequiredArguments { .. } => spec_section!("Required-Arguments"),
+        }
+    }
+
+    /// Structured details about the error, such as the names of the field and arguments involved
+    pub fn extensions(&self) -> Vec<(&'static str, Cow<'a, str>)> {
+        match self {
+            Self::NonUniqueArgumentNames { name, .. } => {
+                vec![("argumentName", Cow::Borrowed(*name))]
+            }
+            Self::ArgumentDoesNotExistOnField {
+                argument,
+                field_definition,
+                ..
+            } => vec![
+                ("fieldName", Cow::Borrowed(field_definition.name())),
+                ("argumentName", Cow::Borrowed(argument.name())),
+            ],
+            Self::ArgumentDoesNotExistOnDirective {
+                argument,
+                directive_definition,
+                ..
+            } => vec![
+                ("directiveName", Cow::Borrowed(directive_definition.name())),
+                ("argumentName", Cow::Borrowed(argument.name())),
+            ],
+            Self::DirectiveMissingRequiredArguments {
+                directive_definition,
+                missing_argument_definitions,
+                ..
+            } => vec![
+                ("directiveName", Cow::Borrowed(directive_definition.name())),
+                (
+                    "argumentNames",
+                    Cow::Owned(
+                        missing_argument_definitions
+                            .iter()
+                            .map(|ivd| ivd.name())
+                            .join(", "),
+                    ),
+                ),
+            ],
+            Self::FieldMissingRequiredArguments {
+                field_definition,
+                missing_argument_definitions,
+                ..
+            } => vec![
+                ("fieldName", Cow::Borrowed(field_definition.name())),
+                (
+                    "argumentNames",
+                    Cow::Owned(
+                        missing_argument_definitions
+                            .iter()
+                            .map(|ivd| ivd.name())
+                            .join(", "),
+                    ),
+                ),
+            ],
+        }
+    }
+}
+
 #[cfg(feature = "parser-integration")]
 impl<'a, const CONST: bool, S: SchemaDefinition>
     From<ArgumentError<'a, CONST, ParserExecutableDocument<'a>, S>> for ParserError
 {
     fn from(value: ArgumentError<'a, CONST, ParserExecutableDocument<'a>, S>) -> Self {
-        match value {
+        let code = value.code();
+        let spec_section = value.spec_section();
+        let extensions: Vec<_> = value
+            .extensions()
+            .into_iter()
+            .map(|(key, value)| (key, value.into_owned()))
+            .collect();
+        let parser_error = match value {
             ArgumentError::NonUniqueArgumentNames { arguments, name } => Self::new(
                 format!("Multiple arguments with name `{name}`"),
                 None,
@@ -57,14 +153,18 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
             ArgumentError::ArgumentDoesNotExistOnField {
                 argument,
                 field_definition,
+                suggestions,
             } => Self::new(
-                format!(
-                    "Field `{}` does not define an argument named `{}`",
-                    field_definition.name(),
-                    argument.name().as_ref(),
+                with_did_you_mean(
+                    format!(
+                        "Field `{}` does not define an argument named `{}`",
+                        field_definition.name(),
+                        argument.name().as_ref(),
+                    ),
+                    &suggestions,
                 ),
                 Some(Annotation::new(
-                    "No argument definition with this name",
+                    with_did_you_mean("No argument definition with this name", &suggestions),
                     argument.name().span().clone(),
                 )),
                 Vec::new(),
@@ -72,14 +172,18 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
             ArgumentError::ArgumentDoesNotExistOnDirective {
                 argument,
                 directive_definition,
+                suggestions,
             } => Self::new(
-                format!(
-                    "Directive `{}` does not define an argument named `{}`",
-                    directive_definition.name(),
-                    argument.name().as_ref(),
+                with_did_you_mean(
+                    format!(
+                        "Directive `{}` does not define an argument named `{}`",
+                        directive_definition.name(),
+                        argument.name().as_ref(),
+                    ),
+                    &suggestions,
                 ),
                 Some(Annotation::new(
-                    "No argument definition with this name",
+                    with_did_you_mean("No argument definition with this name", &suggestions),
                     argument.name().span().clone(),
                 )),
                 Vec::new(),
@@ -130,6 +234,10 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
                     Vec::new(),
                 )
             }
-        }
+        };
+        parser_error
+            .with_code(code)
+            .with_spec_section(spec_section)
+            .with_extensions(extensions)
     }
 }
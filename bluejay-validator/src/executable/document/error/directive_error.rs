@@ -1,6 +1,8 @@
+#[cfg(feature = "parser-integration")]
+use crate::utils::with_did_you_mean;
 use bluejay_core::definition::{DirectiveDefinition, DirectiveLocation, SchemaDefinition};
 use bluejay_core::executable::ExecutableDocument;
-use bluejay_core::AsIter;
+use bluejay_core::{AsIter, Directive};
 #[cfg(feature = "parser-integration")]
 use bluejay_parser::{
     ast::executable::ExecutableDocument as ParserExecutableDocument,
@@ -8,10 +10,12 @@ use bluejay_parser::{
     HasSpan,
 };
 use itertools::Itertools;
+use std::borrow::Cow;
 
 pub enum DirectiveError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDefinition> {
     DirectiveDoesNotExist {
         directive: &'a E::Directive<CONST>,
+        suggestions: Vec<&'a str>,
     },
     DirectiveInInvalidLocation {
         directive: &'a E::Directive<CONST>,
@@ -24,19 +28,79 @@ pub enum DirectiveError<'a, const CONST: bool, E: ExecutableDocument, S: SchemaD
     },
 }
 
+impl<'a, const CONST: bool, E: ExecutableDocument, S: SchemaDefinition>
+    DirectiveError<'a, CONST, E, S>
+{
+    /// A stable, machine-readable code identifying the kind of error, e.g. `DIRECTIVE_NOT_DEFINED`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::DirectiveDoesNotExist { .. } => "DIRECTIVE_NOT_DEFINED",
+            Self::DirectiveInInvalidLocation { .. } => "DIRECTIVE_LOCATION_NOT_ALLOWED",
+            Self::DirectivesNotUniquePerLocation { .. } => "DIRECTIVE_NOT_UNIQUE",
+        }
+    }
+
+    /// Link to the validation rule in the GraphQL specification that the error violates
+    pub fn spec_section(&self) -> &'static str {
+        match self {
+            Self::DirectiveDoesNotExist { .. } => spec_section!("Directives-Are-Defined"),
+            Self::DirectiveInInvalidLocation { .. } => {
+                spec_section!("Directives-Are-In-Valid-Locations")
+            }
+            Self::DirectivesNotUniquePerLocation { .. } => {
+                spec_section!("Directives-Are-Unique-Per-Location")
+            }
+        }
+    }
+
+    /// Structured details about the error, such as the name of the directive involved
+    pub fn extensions(&self) -> Vec<(&'static str, Cow<'a, str>)> {
+        match self {
+            Self::DirectiveDoesNotExist { directive, .. } => {
+                vec![("directiveName", Cow::Borrowed(directive.name()))]
+            }
+            Self::DirectiveInInvalidLocation {
+                directive_definition,
+                location,
+                ..
+            } => vec![
+                ("directiveName", Cow::Borrowed(directive_definition.name())),
+                ("location", Cow::Owned(location.to_string())),
+            ],
+            Self::DirectivesNotUniquePerLocation {
+                directive_definition,
+                ..
+            } => vec![("directiveName", Cow::Borrowed(directive_definition.name()))],
+        }
+    }
+}
+
 #[cfg(feature = "parser-integration")]
 impl<'a, const CONST: bool, S: SchemaDefinition>
     From<DirectiveError<'a, CONST, ParserExecutableDocument<'a>, S>> for ParserError
 {
     fn from(value: DirectiveError<'a, CONST, ParserExecutableDocument<'a>, S>) -> Self {
-        match value {
-            DirectiveError::DirectiveDoesNotExist { directive } => Self::new(
-                format!(
-                    "No directive definition with name `@{}`",
-                    directive.name().as_ref()
+        let code = value.code();
+        let spec_section = value.spec_section();
+        let extensions: Vec<_> = value
+            .extensions()
+            .into_iter()
+            .map(|(key, value)| (key, value.into_owned()))
+            .collect();
+        let parser_error = match value {
+            DirectiveError::DirectiveDoesNotExist {
+                directive,
+                suggestions,
+            } => Self::new(
+                with_did_you_mean(
+                    format!(
+                        "No directive definition with name `@{}`",
+                        directive.name().as_ref()
+                    ),
+                    &suggestions,
                 ),
                 Some(Annotation::new(
-                    "No directive definition with this name",
+                    with_did_you_mean("No directive definition with this name", &suggestions),
                     directive.name().span().clone(),
                 )),
                 Vec::new(),
@@ -68,6 +132,10 @@ impl<'a, const CONST: bool, S: SchemaDefinition>
                     directive.span().clone(),
                 )).collect(),
             ),
-        }
+        };
+        parser_error
+            .with_code(code)
+            .with_spec_section(spec_section)
+            .with_extensions(extensions)
     }
 }
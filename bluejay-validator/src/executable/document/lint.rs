@@ -0,0 +1,26 @@
+//! Opt-in linting for executable documents, run on top of the spec [validation](super::Orchestrator)
+//! to catch documents that are valid but undesirable in client code.
+//!
+//! Lint rules are [`Rule`](super::Rule)s reporting [`LintViolation`]s, so they can be combined
+//! in tuples like the builtin rules. [`BuiltinLintRules`] groups the rules that need no
+//! configuration. Each lint rule has a name used to configure its [`Severity`] or disable it
+//! through a [`LintConfig`], which also lists the fields that every [`Linter`] reports through
+//! the `forbidden-fields` rule. Operation names that are not unique across many documents are
+//! reported by [`Linter::lint_batch`].
+
+mod config;
+mod linter;
+mod node;
+mod rules;
+mod violation;
+
+pub use crate::definition::lint::Severity;
+pub use config::LintConfig;
+pub use linter::{BuiltinLintRulesLinter, Linter};
+pub use node::LintNode;
+pub(crate) use rules::ForbiddenFields;
+pub use rules::{
+    BuiltinLintRules, MaxFragmentSelections, NamedOperations, NoAnonymousInlineFragments,
+    NoDuplicateFields, TypenameWithInterfaceId, VariableNamingConventions,
+};
+pub use violation::LintViolation;
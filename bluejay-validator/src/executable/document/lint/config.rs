@@ -0,0 +1,51 @@
+use crate::executable::document::lint::Severity;
+use std::collections::{HashMap, HashSet};
+
+/// Configures which lint rules run against executable documents, the severity they report with,
+/// and the fields that must not be selected
+#[derive(Debug, Clone, Default)]
+pub struct LintConfig {
+    severities: HashMap<String, Option<Severity>>,
+    forbidden_fields: HashMap<String, HashSet<String>>,
+}
+
+impl LintConfig {
+    /// Overrides the default severity of the rule named `rule`
+    pub fn with_severity(mut self, rule: impl Into<String>, severity: Severity) -> Self {
+        self.severities.insert(rule.into(), Some(severity));
+        self
+    }
+
+    /// Disables the rule named `rule`
+    pub fn without_rule(mut self, rule: impl Into<String>) -> Self {
+        self.severities.insert(rule.into(), None);
+        self
+    }
+
+    /// Forbids selecting the field named `field_name` of the type named `type_name`, as reported
+    /// by the `forbidden-fields` rule
+    pub fn with_forbidden_field(
+        mut self,
+        type_name: impl Into<String>,
+        field_name: impl Into<String>,
+    ) -> Self {
+        self.forbidden_fields
+            .entry(type_name.into())
+            .or_default()
+            .insert(field_name.into());
+        self
+    }
+
+    /// Returns the severity to report violations of `rule` with, or `None` if the rule is disabled
+    pub fn severity(&self, rule: &str, default_severity: Severity) -> Option<Severity> {
+        self.severities
+            .get(rule)
+            .copied()
+            .unwrap_or(Some(default_severity))
+    }
+
+    /// The names of the forbidden fields, by the name of their type
+    pub(crate) fn forbidden_fields(&self) -> &HashMap<String, HashSet<String>> {
+        &self.forbidden_fields
+    }
+}
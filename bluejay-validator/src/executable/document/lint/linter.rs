@@ -0,0 +1,122 @@
+use crate::executable::{
+    document::{
+        lint::{BuiltinLintRules, ForbiddenFields, LintConfig, LintNode, LintViolation, Severity},
+        DocumentBatch, Orchestrator, Rule, Visitor,
+    },
+    Cache,
+};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::{ExecutableDocument, OperationDefinition};
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+pub struct Linter<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    R: Rule<'a, E, S, Error = LintViolation<'a, E>>,
+> {
+    executable_document: PhantomData<&'a E>,
+    schema_definition: PhantomData<&'a S>,
+    rule: PhantomData<R>,
+}
+
+pub type BuiltinLintRulesLinter<'a, E, S> = Linter<'a, E, S, BuiltinLintRules<'a, E>>;
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        R: Rule<'a, E, S, Error = LintViolation<'a, E>>,
+    > Linter<'a, E, S, R>
+{
+    const UNIQUE_OPERATION_NAMES: &'static str = "unique-operation-names";
+
+    /// Runs the lint rules `R` against `executable_document`, along with [`ForbiddenFields`]
+    /// for the fields forbidden by `config`, applying the severities of `config`
+    pub fn lint(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        cache: &'a Cache<'a, E, S>,
+        config: &LintConfig,
+    ) -> Vec<LintViolation<'a, E>> {
+        Orchestrator::<'a, E, S, (R, ForbiddenFields<'a, E>)>::validate_with(
+            executable_document,
+            schema_definition,
+            (
+                Visitor::new(executable_document, schema_definition, cache),
+                ForbiddenFields::with_config(cache, config),
+            ),
+        )
+        .filter_map(|violation| Self::apply_config(violation, config))
+        .collect()
+    }
+
+    /// Runs the lint rules `R` against every document of `batch`, returning the violations of
+    /// each document at the same index as the document. Operations sharing their name with
+    /// another operation of the batch are reported by the `unique-operation-names` rule, as
+    /// their names no longer identify them once the documents are deployed together.
+    pub fn lint_batch(
+        batch: &'a DocumentBatch<'a, E, S>,
+        config: &LintConfig,
+    ) -> Vec<Vec<LintViolation<'a, E>>> {
+        let mut violations: Vec<Vec<LintViolation<'a, E>>> = batch
+            .iter()
+            .map(|(executable_document, cache)| {
+                Self::lint(
+                    executable_document,
+                    batch.schema_definition(),
+                    cache,
+                    config,
+                )
+            })
+            .collect();
+
+        let mut operations_by_name: BTreeMap<&'a str, Vec<(usize, &'a E::OperationDefinition)>> =
+            BTreeMap::new();
+        batch
+            .iter()
+            .enumerate()
+            .for_each(|(index, (executable_document, _))| {
+                executable_document
+                    .operation_definitions()
+                    .for_each(|operation_definition| {
+                        if let Some(name) = operation_definition.as_ref().name() {
+                            operations_by_name
+                                .entry(name)
+                                .or_default()
+                                .push((index, operation_definition));
+                        }
+                    })
+            });
+
+        operations_by_name
+            .into_iter()
+            .filter(|(_, operations)| operations.len() > 1)
+            .for_each(|(name, operations)| {
+                let count = operations.len();
+                operations
+                    .into_iter()
+                    .for_each(|(index, operation_definition)| {
+                        let violation = LintViolation::new(
+                            Self::UNIQUE_OPERATION_NAMES,
+                            Severity::Error,
+                            LintNode::OperationDefinition(operation_definition),
+                            format!("Operation name `{name}` is used by {count} operations"),
+                        );
+                        violations[index].extend(Self::apply_config(violation, config));
+                    })
+            });
+
+        violations
+    }
+
+    fn apply_config(
+        mut violation: LintViolation<'a, E>,
+        config: &LintConfig,
+    ) -> Option<LintViolation<'a, E>> {
+        let severity = config.severity(violation.rule(), violation.severity())?;
+        violation.set_severity(severity);
+        Some(violation)
+    }
+}
@@ -0,0 +1,51 @@
+use bluejay_core::executable::ExecutableDocument;
+
+#[cfg(feature = "parser-integration")]
+use bluejay_parser::{
+    ast::executable::{
+        ExecutableDocument as ParserExecutableDocument,
+        OperationDefinition as ParserOperationDefinition,
+    },
+    HasSpan, Span,
+};
+
+/// An element of an executable document that a lint rule can report on
+pub enum LintNode<'a, E: ExecutableDocument> {
+    OperationDefinition(&'a E::OperationDefinition),
+    FragmentDefinition(&'a E::FragmentDefinition),
+    VariableDefinition(&'a E::VariableDefinition),
+    Field(&'a E::Field),
+    InlineFragment(&'a E::InlineFragment),
+}
+
+impl<E: ExecutableDocument> Clone for LintNode<'_, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: ExecutableDocument> Copy for LintNode<'_, E> {}
+
+#[cfg(feature = "parser-integration")]
+impl<'a> LintNode<'a, ParserExecutableDocument<'a>> {
+    /// The span of the name of the element, or of the whole element when it has no name
+    pub(crate) fn span(&self) -> &'a Span {
+        match self {
+            Self::OperationDefinition(operation_definition) => match operation_definition {
+                ParserOperationDefinition::Explicit(explicit_operation_definition) => {
+                    match explicit_operation_definition.name() {
+                        Some(name) => name.span(),
+                        None => explicit_operation_definition.operation_type().span(),
+                    }
+                }
+                ParserOperationDefinition::Implicit(implicit_operation_definition) => {
+                    implicit_operation_definition.span()
+                }
+            },
+            Self::FragmentDefinition(fragment_definition) => fragment_definition.name().span(),
+            Self::VariableDefinition(variable_definition) => variable_definition.variable().span(),
+            Self::Field(field) => field.name().span(),
+            Self::InlineFragment(inline_fragment) => inline_fragment.span(),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+mod forbidden_fields;
+mod max_fragment_selections;
+mod named_operations;
+mod no_anonymous_inline_fragments;
+mod no_duplicate_fields;
+mod typename_with_interface_id;
+mod variable_naming_conventions;
+
+pub(crate) use forbidden_fields::ForbiddenFields;
+pub use max_fragment_selections::MaxFragmentSelections;
+pub use named_operations::NamedOperations;
+pub use no_anonymous_inline_fragments::NoAnonymousInlineFragments;
+pub use no_duplicate_fields::NoDuplicateFields;
+pub use typename_with_interface_id::TypenameWithInterfaceId;
+pub use variable_naming_conventions::VariableNamingConventions;
+
+/// The lint rules that need no configuration, with the default maximum for
+/// [`MaxFragmentSelections`]
+pub type BuiltinLintRules<'a, E> = (
+    NamedOperations<'a, E>,
+    NoAnonymousInlineFragments<'a, E>,
+    VariableNamingConventions<'a, E>,
+    TypenameWithInterfaceId<'a, E>,
+    NoDuplicateFields<'a, E>,
+    MaxFragmentSelections<'a, E>,
+);
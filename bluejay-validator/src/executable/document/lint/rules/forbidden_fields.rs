@@ -0,0 +1,114 @@
+use crate::executable::{
+    document::{
+        lint::{LintConfig, LintNode, LintViolation, Severity},
+        Rule, Visitor,
+    },
+    Cache, SchemaCache,
+};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field, Selection, SelectionReference};
+use bluejay_core::AsIter;
+use std::collections::{HashMap, HashSet};
+
+/// The fields forbidden with [`LintConfig::with_forbidden_field`] must not be selected. A field
+/// selected on an interface or a union is forbidden when it is forbidden on the interface or
+/// union itself or on any of its possible types.
+///
+/// Only [`Linter`](crate::executable::document::lint::Linter) runs this rule, with the forbidden
+/// fields of its config, as built through [`Visitor::new`] it would forbid no field.
+pub(crate) struct ForbiddenFields<'a, E: ExecutableDocument> {
+    violations: Vec<LintViolation<'a, E>>,
+    /// Only looked up when some fields are forbidden, to not build it for nothing
+    schema_cache: Option<&'a SchemaCache<'a>>,
+    forbidden_fields: HashMap<String, HashSet<String>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition> Visitor<'a, E, S>
+    for ForbiddenFields<'a, E>
+{
+    fn new(_: &'a E, _: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            violations: Vec::new(),
+            schema_cache: None,
+            forbidden_fields: HashMap::new(),
+        }
+    }
+
+    fn visit_selection_set(
+        &mut self,
+        selection_set: &'a E::SelectionSet,
+        r#type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        if self.forbidden_fields.is_empty() {
+            return;
+        }
+        selection_set.iter().for_each(|selection| {
+            if let SelectionReference::Field(field) = selection.as_ref() {
+                if let Some(type_name) = self.forbidden_on(r#type.name(), field.name()) {
+                    let message = if type_name == r#type.name() {
+                        format!("Field `{}.{}` is forbidden", type_name, field.name())
+                    } else {
+                        format!(
+                            "Field `{}.{}` is forbidden on its possible type `{}`",
+                            r#type.name(),
+                            field.name(),
+                            type_name,
+                        )
+                    };
+                    self.violations.push(LintViolation::new(
+                        Self::NAME,
+                        Severity::Error,
+                        LintNode::Field(field),
+                        message,
+                    ));
+                }
+            }
+        });
+    }
+}
+
+impl<'a, E: ExecutableDocument> ForbiddenFields<'a, E> {
+    const NAME: &'static str = "forbidden-fields";
+
+    /// Forbids the fields forbidden by `config`
+    pub(crate) fn with_config<S: SchemaDefinition>(
+        cache: &'a Cache<'a, E, S>,
+        config: &LintConfig,
+    ) -> Self {
+        Self {
+            violations: Vec::new(),
+            schema_cache: (!config.forbidden_fields().is_empty()).then(|| cache.schema_cache()),
+            forbidden_fields: config.forbidden_fields().clone(),
+        }
+    }
+
+    /// The name of the type that forbids the field named `field_name` when selected on the type
+    /// named `type_name`: that type itself, or the first of its possible types forbidding it
+    fn forbidden_on(&self, type_name: &'a str, field_name: &str) -> Option<&'a str> {
+        let is_forbidden = |type_name: &str| {
+            self.forbidden_fields
+                .get(type_name)
+                .is_some_and(|field_names| field_names.contains(field_name))
+        };
+        if is_forbidden(type_name) {
+            return Some(type_name);
+        }
+        self.schema_cache?
+            .possible_type_names(type_name)?
+            .iter()
+            .copied()
+            .filter(|possible_type_name| is_forbidden(possible_type_name))
+            .min()
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for ForbiddenFields<'a, E>
+{
+    type Error = LintViolation<'a, E>;
+    type Errors = std::vec::IntoIter<LintViolation<'a, E>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.violations.into_iter()
+    }
+}
@@ -0,0 +1,75 @@
+use crate::executable::{
+    document::{
+        lint::{LintNode, LintViolation, Severity},
+        Rule, Visitor,
+    },
+    Cache,
+};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, InlineFragment, Selection, SelectionReference,
+};
+use bluejay_core::AsIter;
+
+/// Fragment definitions must not select more than `MAX` fields, counting the fields of nested
+/// selection sets and inline fragments but not those of spread fragments. Large fragments are
+/// better split into smaller ones colocated with the code using them.
+pub struct MaxFragmentSelections<'a, E: ExecutableDocument, const MAX: usize = 50> {
+    violations: Vec<LintViolation<'a, E>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, const MAX: usize> Visitor<'a, E, S>
+    for MaxFragmentSelections<'a, E, MAX>
+{
+    fn new(_: &'a E, _: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+
+    fn visit_fragment_definition(&mut self, fragment_definition: &'a E::FragmentDefinition) {
+        let mut count = 0;
+        let mut selection_sets = vec![fragment_definition.selection_set()];
+        while let Some(selection_set) = selection_sets.pop() {
+            selection_set
+                .iter()
+                .for_each(|selection| match selection.as_ref() {
+                    SelectionReference::Field(field) => {
+                        count += 1;
+                        selection_sets.extend(field.selection_set());
+                    }
+                    SelectionReference::InlineFragment(inline_fragment) => {
+                        selection_sets.push(inline_fragment.selection_set());
+                    }
+                    SelectionReference::FragmentSpread(_) => {}
+                });
+        }
+
+        if count > MAX {
+            self.violations.push(LintViolation::new(
+                Self::NAME,
+                Severity::Warning,
+                LintNode::FragmentDefinition(fragment_definition),
+                format!(
+                    "Fragment `{}` selects {count} fields, more than the maximum of {MAX}",
+                    fragment_definition.name(),
+                ),
+            ));
+        }
+    }
+}
+
+impl<E: ExecutableDocument, const MAX: usize> MaxFragmentSelections<'_, E, MAX> {
+    const NAME: &'static str = "max-fragment-selections";
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a, const MAX: usize> Rule<'a, E, S>
+    for MaxFragmentSelections<'a, E, MAX>
+{
+    type Error = LintViolation<'a, E>;
+    type Errors = std::vec::IntoIter<LintViolation<'a, E>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.violations.into_iter()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::executable::{
+    document::{
+        lint::{LintNode, LintViolation, Severity},
+        Rule, Visitor,
+    },
+    Cache,
+};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::{ExecutableDocument, OperationDefinition};
+
+/// Operations must be named, so that they can be identified in logs and metrics
+pub struct NamedOperations<'a, E: ExecutableDocument> {
+    violations: Vec<LintViolation<'a, E>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition> Visitor<'a, E, S>
+    for NamedOperations<'a, E>
+{
+    fn new(_: &'a E, _: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+
+    fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
+        if operation_definition.as_ref().name().is_none() {
+            self.violations.push(LintViolation::new(
+                Self::NAME,
+                Severity::Warning,
+                LintNode::OperationDefinition(operation_definition),
+                "Operation should be named",
+            ));
+        }
+    }
+}
+
+impl<E: ExecutableDocument> NamedOperations<'_, E> {
+    const NAME: &'static str = "named-operations";
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for NamedOperations<'a, E>
+{
+    type Error = LintViolation<'a, E>;
+    type Errors = std::vec::IntoIter<LintViolation<'a, E>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.violations.into_iter()
+    }
+}
@@ -0,0 +1,72 @@
+use crate::executable::{
+    document::{
+        lint::{LintNode, LintViolation, Severity},
+        Rule, Visitor,
+    },
+    Cache,
+};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, InlineFragment, Selection, SelectionReference,
+};
+use bluejay_core::AsIter;
+
+/// Inline fragments within fragment definitions must have a type condition. Fragment
+/// definitions are shared between operations, often from other files, where a selection
+/// grouped without a type condition is easily mistaken for one on a narrower type.
+pub struct NoAnonymousInlineFragments<'a, E: ExecutableDocument> {
+    violations: Vec<LintViolation<'a, E>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition> Visitor<'a, E, S>
+    for NoAnonymousInlineFragments<'a, E>
+{
+    fn new(_: &'a E, _: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+
+    fn visit_fragment_definition(&mut self, fragment_definition: &'a E::FragmentDefinition) {
+        let mut selection_sets = vec![fragment_definition.selection_set()];
+        while let Some(selection_set) = selection_sets.pop() {
+            selection_set
+                .iter()
+                .for_each(|selection| match selection.as_ref() {
+                    SelectionReference::Field(field) => {
+                        selection_sets.extend(field.selection_set())
+                    }
+                    SelectionReference::InlineFragment(inline_fragment) => {
+                        if inline_fragment.type_condition().is_none() {
+                            self.violations.push(LintViolation::new(
+                                Self::NAME,
+                                Severity::Warning,
+                                LintNode::InlineFragment(inline_fragment),
+                                format!(
+                                    "Inline fragment in fragment `{}` should have a type condition",
+                                    fragment_definition.name(),
+                                ),
+                            ));
+                        }
+                        selection_sets.push(inline_fragment.selection_set());
+                    }
+                    SelectionReference::FragmentSpread(_) => {}
+                });
+        }
+    }
+}
+
+impl<E: ExecutableDocument> NoAnonymousInlineFragments<'_, E> {
+    const NAME: &'static str = "no-anonymous-inline-fragments";
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for NoAnonymousInlineFragments<'a, E>
+{
+    type Error = LintViolation<'a, E>;
+    type Errors = std::vec::IntoIter<LintViolation<'a, E>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.violations.into_iter()
+    }
+}
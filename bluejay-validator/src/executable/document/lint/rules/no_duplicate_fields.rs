@@ -0,0 +1,67 @@
+use crate::executable::{
+    document::{
+        lint::{LintNode, LintViolation, Severity},
+        Rule, Visitor,
+    },
+    Cache,
+};
+use crate::utils::duplicates;
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field, Selection, SelectionReference};
+use bluejay_core::AsIter;
+
+/// A field must not be selected more than once without an alias in the same selection set.
+/// Such selections are merged into one, so the repetitions are at best redundant and at worst
+/// hide that the selections were meant to differ.
+pub struct NoDuplicateFields<'a, E: ExecutableDocument> {
+    violations: Vec<LintViolation<'a, E>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition> Visitor<'a, E, S>
+    for NoDuplicateFields<'a, E>
+{
+    fn new(_: &'a E, _: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+
+    fn visit_selection_set(
+        &mut self,
+        selection_set: &'a E::SelectionSet,
+        _type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        let unaliased_fields =
+            selection_set
+                .iter()
+                .filter_map(|selection| match selection.as_ref() {
+                    SelectionReference::Field(field) if field.alias().is_none() => Some(field),
+                    _ => None,
+                });
+        duplicates(unaliased_fields, Field::name).for_each(|(name, fields)| {
+            fields.into_iter().skip(1).for_each(|field| {
+                self.violations.push(LintViolation::new(
+                    Self::NAME,
+                    Severity::Warning,
+                    LintNode::Field(field),
+                    format!("Field `{name}` is selected more than once without an alias"),
+                ));
+            })
+        });
+    }
+}
+
+impl<E: ExecutableDocument> NoDuplicateFields<'_, E> {
+    const NAME: &'static str = "no-duplicate-fields";
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for NoDuplicateFields<'a, E>
+{
+    type Error = LintViolation<'a, E>;
+    type Errors = std::vec::IntoIter<LintViolation<'a, E>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.violations.into_iter()
+    }
+}
@@ -0,0 +1,78 @@
+use crate::executable::{
+    document::{
+        lint::{LintNode, LintViolation, Severity},
+        Rule, Visitor,
+    },
+    Cache,
+};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field, Selection, SelectionReference};
+use bluejay_core::AsIter;
+
+/// Selection sets on interfaces that select `id` must also select `__typename`, as normalized
+/// client caches identify objects by their type and `id`, and `id`s are often only unique per
+/// type
+pub struct TypenameWithInterfaceId<'a, E: ExecutableDocument> {
+    violations: Vec<LintViolation<'a, E>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition> Visitor<'a, E, S>
+    for TypenameWithInterfaceId<'a, E>
+{
+    fn new(_: &'a E, _: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+
+    fn visit_selection_set(
+        &mut self,
+        selection_set: &'a E::SelectionSet,
+        r#type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        if !matches!(r#type, TypeDefinitionReference::Interface(_)) {
+            return;
+        }
+        let fields = || {
+            selection_set
+                .iter()
+                .filter_map(|selection| match selection.as_ref() {
+                    SelectionReference::Field(field) => Some(field),
+                    _ => None,
+                })
+        };
+        if fields().any(|field| field.name() == Self::TYPENAME_FIELD_NAME) {
+            return;
+        }
+        fields()
+            .filter(|field| field.name() == Self::ID_FIELD_NAME)
+            .for_each(|field| {
+                self.violations.push(LintViolation::new(
+                    Self::NAME,
+                    Severity::Warning,
+                    LintNode::Field(field),
+                    format!(
+                        "Selection of `id` on interface `{}` should also select `__typename`",
+                        r#type.name(),
+                    ),
+                ));
+            });
+    }
+}
+
+impl<E: ExecutableDocument> TypenameWithInterfaceId<'_, E> {
+    const NAME: &'static str = "typename-with-interface-id";
+    const ID_FIELD_NAME: &'static str = "id";
+    const TYPENAME_FIELD_NAME: &'static str = "__typename";
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for TypenameWithInterfaceId<'a, E>
+{
+    type Error = LintViolation<'a, E>;
+    type Errors = std::vec::IntoIter<LintViolation<'a, E>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.violations.into_iter()
+    }
+}
@@ -0,0 +1,54 @@
+use crate::executable::{
+    document::{
+        lint::{LintNode, LintViolation, Severity},
+        Rule, Visitor,
+    },
+    Cache,
+};
+use crate::utils::is_camel_case;
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::{ExecutableDocument, VariableDefinition};
+
+/// Variable names must be `camelCase`, like the names of the arguments they are given to
+pub struct VariableNamingConventions<'a, E: ExecutableDocument> {
+    violations: Vec<LintViolation<'a, E>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition> Visitor<'a, E, S>
+    for VariableNamingConventions<'a, E>
+{
+    fn new(_: &'a E, _: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            violations: Vec::new(),
+        }
+    }
+
+    fn visit_variable_definition(&mut self, variable_definition: &'a E::VariableDefinition) {
+        if !is_camel_case(variable_definition.variable()) {
+            self.violations.push(LintViolation::new(
+                Self::NAME,
+                Severity::Warning,
+                LintNode::VariableDefinition(variable_definition),
+                format!(
+                    "Variable `${}` should be camelCase",
+                    variable_definition.variable(),
+                ),
+            ));
+        }
+    }
+}
+
+impl<E: ExecutableDocument> VariableNamingConventions<'_, E> {
+    const NAME: &'static str = "variable-naming-conventions";
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for VariableNamingConventions<'a, E>
+{
+    type Error = LintViolation<'a, E>;
+    type Errors = std::vec::IntoIter<LintViolation<'a, E>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.violations.into_iter()
+    }
+}
@@ -0,0 +1,74 @@
+use crate::executable::document::lint::{LintNode, Severity};
+use bluejay_core::executable::ExecutableDocument;
+
+#[cfg(feature = "parser-integration")]
+use bluejay_parser::{
+    ast::executable::ExecutableDocument as ParserExecutableDocument,
+    error::{Annotation, Error as ParserError, Severity as ParserSeverity},
+};
+
+pub struct LintViolation<'a, E: ExecutableDocument> {
+    rule: &'static str,
+    severity: Severity,
+    node: LintNode<'a, E>,
+    message: String,
+}
+
+impl<'a, E: ExecutableDocument> LintViolation<'a, E> {
+    pub fn new(
+        rule: &'static str,
+        severity: Severity,
+        node: LintNode<'a, E>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            rule,
+            severity,
+            node,
+            message: message.into(),
+        }
+    }
+
+    /// The name of the rule that was violated
+    pub fn rule(&self) -> &'static str {
+        self.rule
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub(crate) fn set_severity(&mut self, severity: Severity) {
+        self.severity = severity;
+    }
+
+    /// The element of the executable document that violates the rule
+    pub fn node(&self) -> LintNode<'a, E> {
+        self.node
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[cfg(feature = "parser-integration")]
+impl<'a> From<LintViolation<'a, ParserExecutableDocument<'a>>> for ParserError {
+    fn from(value: LintViolation<'a, ParserExecutableDocument<'a>>) -> Self {
+        let LintViolation {
+            rule,
+            severity,
+            node,
+            message,
+        } = value;
+        Self::new(
+            format!("{message} [{rule}]"),
+            Some(Annotation::new(message, node.span().clone())),
+            Vec::new(),
+        )
+        .with_severity(match severity {
+            Severity::Warning => ParserSeverity::Warning,
+            Severity::Error => ParserSeverity::Error,
+        })
+    }
+}
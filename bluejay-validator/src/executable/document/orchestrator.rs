@@ -1,5 +1,6 @@
 use crate::executable::{
     document::{Analyzer, BuiltinRules, Path, PathRoot, Rule, Visitor},
+    operation::{resolve_operation, OperationResolutionError},
     Cache,
 };
 use bluejay_core::definition::{
@@ -11,6 +12,7 @@ use bluejay_core::executable::{
     OperationDefinition, Selection, SelectionReference, VariableDefinition,
 };
 use bluejay_core::{Argument, AsIter, Directive, OperationType};
+use std::collections::HashSet;
 
 pub struct Orchestrator<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>> {
     schema_definition: &'a S,
@@ -28,10 +30,18 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
         schema_definition: &'a S,
         cache: &'a Cache<'a, E, S>,
     ) -> Self {
+        Self::with_visitor(
+            executable_document,
+            schema_definition,
+            Visitor::new(executable_document, schema_definition, cache),
+        )
+    }
+
+    fn with_visitor(executable_document: &'a E, schema_definition: &'a S, visitor: V) -> Self {
         Self {
             schema_definition,
             executable_document,
-            visitor: Visitor::new(executable_document, schema_definition, cache),
+            visitor,
         }
     }
 
@@ -48,6 +58,58 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
             });
     }
 
+    fn visit_operation(
+        &mut self,
+        operation_definition: &'a E::OperationDefinition,
+        cache: &'a Cache<'a, E, S>,
+    ) {
+        let fragment_definitions = Self::reachable_fragment_definitions(
+            self.executable_document,
+            operation_definition,
+            cache,
+        );
+        self.visitor
+            .restrict_to_operation(operation_definition, &fragment_definitions);
+        self.visit_operation_definition(operation_definition);
+        fragment_definitions
+            .into_iter()
+            .for_each(|fragment_definition| {
+                self.visit_fragment_definition(fragment_definition);
+            });
+    }
+
+    /// Finds the fragment definitions spread by the operation, directly or through other
+    /// fragment definitions, in the order they are defined in the document
+    pub(crate) fn reachable_fragment_definitions(
+        executable_document: &'a E,
+        operation_definition: &'a E::OperationDefinition,
+        cache: &'a Cache<'a, E, S>,
+    ) -> Vec<&'a E::FragmentDefinition> {
+        let mut fragment_names = HashSet::new();
+        let mut selection_sets = vec![operation_definition.as_ref().selection_set()];
+        while let Some(selection_set) = selection_sets.pop() {
+            selection_set
+                .iter()
+                .for_each(|selection| match selection.as_ref() {
+                    SelectionReference::Field(f) => selection_sets.extend(f.selection_set()),
+                    SelectionReference::InlineFragment(i) => selection_sets.push(i.selection_set()),
+                    SelectionReference::FragmentSpread(fs) => {
+                        if fragment_names.insert(fs.name()) {
+                            selection_sets.extend(
+                                cache
+                                    .fragment_definition(fs.name())
+                                    .map(FragmentDefinition::selection_set),
+                            );
+                        }
+                    }
+                });
+        }
+        executable_document
+            .fragment_definitions()
+            .filter(|fragment_definition| fragment_names.contains(fragment_definition.name()))
+            .collect()
+    }
+
     fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
         let path = Path::new(PathRoot::Operation(operation_definition));
         self.visitor
@@ -337,6 +399,81 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: Visitor<'a, E, S>>
         instance.visitor.into_errors()
     }
 
+    /// Validates using rules that have already been constructed, such as
+    /// [`DynamicRules`](crate::executable::document::DynamicRules) selected at runtime
+    pub fn validate_with(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        rules: V,
+    ) -> <V as Rule<'a, E, S>>::Errors
+    where
+        V: Rule<'a, E, S>,
+    {
+        let mut instance = Self::with_visitor(executable_document, schema_definition, rules);
+        instance.visit();
+        instance.visitor.into_errors()
+    }
+
+    /// Validates only the operation selected by `operation_name`, as described by
+    /// `GetOperation` in the spec, and the fragment definitions it transitively spreads.
+    /// Errors in other operations and unused fragment definitions are not reported.
+    pub fn validate_operation<'b>(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        operation_name: Option<&'b str>,
+        cache: &'a Cache<'a, E, S>,
+    ) -> Result<<V as Rule<'a, E, S>>::Errors, OperationResolutionError<'b>>
+    where
+        V: Rule<'a, E, S>,
+    {
+        let operation_definition = resolve_operation(executable_document, operation_name)?;
+        Ok(Self::validate_operation_definition(
+            executable_document,
+            schema_definition,
+            operation_definition,
+            cache,
+        ))
+    }
+
+    /// Validates only `operation_definition`, which must belong to `executable_document`, and
+    /// the fragment definitions it transitively spreads, like
+    /// [`validate_operation`](Self::validate_operation)
+    pub fn validate_operation_definition(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        operation_definition: &'a E::OperationDefinition,
+        cache: &'a Cache<'a, E, S>,
+    ) -> <V as Rule<'a, E, S>>::Errors
+    where
+        V: Rule<'a, E, S>,
+    {
+        let mut instance = Self::new(executable_document, schema_definition, cache);
+        instance.visit_operation(operation_definition, cache);
+        instance.visitor.into_errors()
+    }
+
+    /// Validates only `fragment_definitions`, which must belong to `executable_document`,
+    /// without any operation definition, as described by
+    /// [`Visitor::restrict_to_fragment_definitions`]
+    pub(crate) fn validate_fragment_definitions(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        fragment_definitions: &[&'a E::FragmentDefinition],
+        cache: &'a Cache<'a, E, S>,
+    ) -> <V as Rule<'a, E, S>>::Errors
+    where
+        V: Rule<'a, E, S>,
+    {
+        let mut instance = Self::new(executable_document, schema_definition, cache);
+        instance
+            .visitor
+            .restrict_to_fragment_definitions(fragment_definitions);
+        fragment_definitions.iter().for_each(|fragment_definition| {
+            instance.visit_fragment_definition(fragment_definition);
+        });
+        instance.visitor.into_errors()
+    }
+
     pub fn analyze(
         executable_document: &'a E,
         schema_definition: &'a S,
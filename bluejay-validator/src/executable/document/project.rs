@@ -0,0 +1,244 @@
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment,
+    OperationDefinition, Selection, SelectionReference,
+};
+use bluejay_core::AsIter;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Executable documents that share their fragment definitions, such as the files of a client
+/// that keeps fragments apart from the operations spreading them.
+///
+/// A fragment spread resolves to the fragment definition with that name in the document it
+/// starts from, and otherwise to the first one in the project, in the order of the documents.
+/// Each document is validated through its [`ProjectDocument`], which also contains the fragment
+/// definitions it spreads from other documents, with
+/// [`DocumentBatch::validate_project`](crate::executable::document::DocumentBatch::validate_project).
+pub struct Project<'a, E: ExecutableDocument> {
+    executable_documents: &'a [E],
+    fragment_definitions: HashMap<&'a str, Vec<(usize, &'a E::FragmentDefinition)>>,
+    project_documents: Vec<ProjectDocument<'a, E>>,
+}
+
+impl<'a, E: ExecutableDocument> Project<'a, E> {
+    pub fn new(executable_documents: &'a [E]) -> Self {
+        let mut fragment_definitions: HashMap<&'a str, Vec<(usize, &'a E::FragmentDefinition)>> =
+            HashMap::new();
+        executable_documents.iter().enumerate().for_each(
+            |(document_index, executable_document)| {
+                executable_document
+                    .fragment_definitions()
+                    .for_each(|fragment_definition| {
+                        fragment_definitions
+                            .entry(fragment_definition.name())
+                            .or_default()
+                            .push((document_index, fragment_definition));
+                    })
+            },
+        );
+        let mut project = Self {
+            executable_documents,
+            fragment_definitions,
+            project_documents: Vec::new(),
+        };
+        project.project_documents = executable_documents
+            .iter()
+            .enumerate()
+            .map(|(document_index, executable_document)| ProjectDocument {
+                executable_document,
+                fragment_definitions: project.document_fragment_definitions(document_index),
+                own_fragment_definitions: executable_document.fragment_definitions().count(),
+            })
+            .collect();
+        project
+    }
+
+    /// The documents of the project, in the same order as the documents it was built from
+    pub fn documents(&self) -> &[ProjectDocument<'a, E>] {
+        &self.project_documents
+    }
+
+    /// The fragment definition that a spread of `name` in the document at `document_index`
+    /// resolves to
+    pub fn resolve_fragment_definition(
+        &self,
+        document_index: usize,
+        name: &str,
+    ) -> Option<&'a E::FragmentDefinition> {
+        let fragment_definitions = self.fragment_definitions.get(name)?;
+        fragment_definitions
+            .iter()
+            .find(|(index, _)| *index == document_index)
+            .or_else(|| fragment_definitions.first())
+            .map(|(_, fragment_definition)| *fragment_definition)
+    }
+
+    /// The fragment definitions whose name is defined in more than one document, by name, with
+    /// the index of their document. Fragment definitions sharing their name within a single
+    /// document are left to [`FragmentNameUniqueness`](crate::executable::document::rules::FragmentNameUniqueness).
+    pub fn duplicate_fragment_definitions(
+        &self,
+    ) -> BTreeMap<&'a str, Vec<(usize, &'a E::FragmentDefinition)>> {
+        self.fragment_definitions
+            .iter()
+            .filter(|(_, fragment_definitions)| {
+                fragment_definitions
+                    .iter()
+                    .any(|(index, _)| *index != fragment_definitions[0].0)
+            })
+            .map(|(name, fragment_definitions)| (*name, fragment_definitions.clone()))
+            .collect()
+    }
+
+    /// The fragment definitions that are not spread by any operation of the project, directly
+    /// or through other fragment definitions, with the index of their document, in the order
+    /// they are defined
+    pub fn unused_fragment_definitions(&self) -> Vec<(usize, &'a E::FragmentDefinition)> {
+        let used: HashSet<*const E::FragmentDefinition> = self
+            .executable_documents
+            .iter()
+            .enumerate()
+            .flat_map(|(document_index, executable_document)| {
+                self.reachable_fragment_definitions(
+                    document_index,
+                    executable_document
+                        .operation_definitions()
+                        .map(|operation_definition| operation_definition.as_ref().selection_set())
+                        .collect(),
+                )
+            })
+            .map(|fragment_definition| fragment_definition as *const _)
+            .collect();
+
+        self.executable_documents
+            .iter()
+            .enumerate()
+            .flat_map(|(document_index, executable_document)| {
+                executable_document
+                    .fragment_definitions()
+                    .map(move |fragment_definition| (document_index, fragment_definition))
+            })
+            .filter(|(_, fragment_definition)| !used.contains(&(*fragment_definition as *const _)))
+            .collect()
+    }
+
+    /// The fragment definitions of the document at `document_index`, followed by those of
+    /// other documents that it spreads
+    fn document_fragment_definitions(
+        &self,
+        document_index: usize,
+    ) -> Vec<&'a E::FragmentDefinition> {
+        let executable_document = &self.executable_documents[document_index];
+        let mut fragment_definitions: Vec<&'a E::FragmentDefinition> =
+            executable_document.fragment_definitions().collect();
+        let own: HashSet<*const E::FragmentDefinition> = fragment_definitions
+            .iter()
+            .map(|fragment_definition| *fragment_definition as *const _)
+            .collect();
+        let selection_sets = executable_document
+            .operation_definitions()
+            .map(|operation_definition| operation_definition.as_ref().selection_set())
+            .chain(
+                executable_document
+                    .fragment_definitions()
+                    .map(FragmentDefinition::selection_set),
+            )
+            .collect();
+        fragment_definitions.extend(
+            self.reachable_fragment_definitions(document_index, selection_sets)
+                .into_iter()
+                .filter(|fragment_definition| !own.contains(&(*fragment_definition as *const _))),
+        );
+        fragment_definitions
+    }
+
+    /// The fragment definitions spread from `selection_sets`, directly or through other
+    /// fragment definitions, resolving spreads from the document at `document_index`
+    fn reachable_fragment_definitions(
+        &self,
+        document_index: usize,
+        mut selection_sets: Vec<&'a E::SelectionSet>,
+    ) -> Vec<&'a E::FragmentDefinition> {
+        let mut fragment_names = HashSet::new();
+        let mut fragment_definitions = Vec::new();
+        while let Some(selection_set) = selection_sets.pop() {
+            selection_set
+                .iter()
+                .for_each(|selection| match selection.as_ref() {
+                    SelectionReference::Field(f) => selection_sets.extend(f.selection_set()),
+                    SelectionReference::InlineFragment(i) => selection_sets.push(i.selection_set()),
+                    SelectionReference::FragmentSpread(fs) => {
+                        if fragment_names.insert(fs.name()) {
+                            if let Some(fragment_definition) =
+                                self.resolve_fragment_definition(document_index, fs.name())
+                            {
+                                fragment_definitions.push(fragment_definition);
+                                selection_sets.push(fragment_definition.selection_set());
+                            }
+                        }
+                    }
+                });
+        }
+        fragment_definitions
+    }
+}
+
+/// A document of a [`Project`]. Its operation definitions are those of the document, and its
+/// fragment definitions are those of the document followed by the ones it spreads from other
+/// documents of the project, directly or through other fragment definitions.
+pub struct ProjectDocument<'a, E: ExecutableDocument> {
+    executable_document: &'a E,
+    fragment_definitions: Vec<&'a E::FragmentDefinition>,
+    /// The number of fragment definitions at the start of `fragment_definitions` that are
+    /// defined in the document itself
+    own_fragment_definitions: usize,
+}
+
+impl<'a, E: ExecutableDocument> ProjectDocument<'a, E> {
+    /// The document of the project this was built from
+    pub fn executable_document(&self) -> &'a E {
+        self.executable_document
+    }
+
+    /// The fragment definitions defined in the document itself, without those spread from
+    /// other documents
+    pub(crate) fn own_fragment_definitions(&self) -> &[&'a E::FragmentDefinition] {
+        &self.fragment_definitions[..self.own_fragment_definitions]
+    }
+}
+
+impl<E: ExecutableDocument> ExecutableDocument for ProjectDocument<'_, E> {
+    type Value<const CONST: bool> = E::Value<CONST>;
+    type VariableType = E::VariableType;
+    type Argument<const CONST: bool> = E::Argument<CONST>;
+    type Arguments<const CONST: bool> = E::Arguments<CONST>;
+    type Directive<const CONST: bool> = E::Directive<CONST>;
+    type Directives<const CONST: bool> = E::Directives<CONST>;
+    type FragmentSpread = E::FragmentSpread;
+    type Field = E::Field;
+    type Selection = E::Selection;
+    type SelectionSet = E::SelectionSet;
+    type InlineFragment = E::InlineFragment;
+    type VariableDefinition = E::VariableDefinition;
+    type VariableDefinitions = E::VariableDefinitions;
+    type ExplicitOperationDefinition = E::ExplicitOperationDefinition;
+    type ImplicitOperationDefinition = E::ImplicitOperationDefinition;
+    type OperationDefinition = E::OperationDefinition;
+    type FragmentDefinition = E::FragmentDefinition;
+    type OperationDefinitions<'b>
+        = E::OperationDefinitions<'b>
+    where
+        Self: 'b;
+    type FragmentDefinitions<'b>
+        = std::iter::Copied<std::slice::Iter<'b, &'b E::FragmentDefinition>>
+    where
+        Self: 'b;
+
+    fn operation_definitions(&self) -> Self::OperationDefinitions<'_> {
+        self.executable_document.operation_definitions()
+    }
+
+    fn fragment_definitions(&self) -> Self::FragmentDefinitions<'_> {
+        let fragment_definitions: &[&E::FragmentDefinition] = &self.fragment_definitions;
+        fragment_definitions.iter().copied()
+    }
+}
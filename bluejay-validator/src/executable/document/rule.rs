@@ -33,7 +33,7 @@ seq_macro::seq!(N in 2..=10 {
     impl_rule!(N);
 });
 
-impl_rule!(26);
+impl_rule!(29);
 
 pub struct RuleErrorAdapter<R, ER> {
     rule: R,
@@ -55,6 +55,23 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, R: Rule<'a, E, S>, ER> Visi
         }
     }
 
+    fn restrict_to_operation(
+        &mut self,
+        operation_definition: &'a E::OperationDefinition,
+        fragment_definitions: &[&'a E::FragmentDefinition],
+    ) {
+        self.rule
+            .restrict_to_operation(operation_definition, fragment_definitions);
+    }
+
+    fn restrict_to_fragment_definitions(
+        &mut self,
+        fragment_definitions: &[&'a E::FragmentDefinition],
+    ) {
+        self.rule
+            .restrict_to_fragment_definitions(fragment_definitions);
+    }
+
     fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
         self.rule.visit_operation_definition(operation_definition);
     }
@@ -3,6 +3,8 @@ mod all_variable_uses_defined;
 mod all_variables_used;
 mod argument_names;
 mod argument_uniqueness;
+mod defer_and_stream_directive_labels_are_unique;
+mod defer_and_stream_directives_on_valid_root_field;
 mod directives_are_defined;
 mod directives_are_in_valid_locations;
 mod directives_are_unique_per_location;
@@ -20,6 +22,7 @@ mod lone_anonymous_operation;
 mod named_operation_name_uniqueness;
 mod operation_type_is_defined;
 mod required_arguments;
+mod stream_directives_on_list_fields;
 mod subscription_operation_single_root_field;
 mod value_is_valid;
 mod variable_uniqueness;
@@ -30,6 +33,8 @@ pub use all_variable_uses_defined::AllVariableUsesDefined;
 pub use all_variables_used::AllVariablesUsed;
 pub use argument_names::ArgumentNames;
 pub use argument_uniqueness::ArgumentUniqueness;
+pub use defer_and_stream_directive_labels_are_unique::DeferAndStreamDirectiveLabelsAreUnique;
+pub use defer_and_stream_directives_on_valid_root_field::DeferAndStreamDirectivesOnValidRootField;
 pub use directives_are_defined::DirectivesAreDefined;
 pub use directives_are_in_valid_locations::DirectivesAreInValidLocations;
 pub use directives_are_unique_per_location::DirectivesAreUniquePerLocation;
@@ -47,6 +52,7 @@ pub use lone_anonymous_operation::LoneAnonymousOperation;
 pub use named_operation_name_uniqueness::NamedOperationNameUniqueness;
 pub use operation_type_is_defined::OperationTypeIsDefined;
 pub use required_arguments::RequiredArguments;
+pub use stream_directives_on_list_fields::StreamDirectivesOnListFields;
 pub use subscription_operation_single_root_field::SubscriptionOperationSingleRootField;
 pub use value_is_valid::ValueIsValid;
 pub use variable_uniqueness::VariableUniqueness;
@@ -74,6 +80,9 @@ pub type BuiltinRules<'a, E, S> = (
     DirectivesAreDefined<'a, E, S>,
     DirectivesAreInValidLocations<'a, E, S>,
     DirectivesAreUniquePerLocation<'a, E, S>,
+    DeferAndStreamDirectivesOnValidRootField<'a, E, S>,
+    StreamDirectivesOnListFields<'a, E, S>,
+    DeferAndStreamDirectiveLabelsAreUnique<'a, E, S>,
     VariableUniqueness<'a, E, S>,
     VariablesAreInputTypes<'a, E, S>,
     AllVariableUsesDefined<'a, E, S>,
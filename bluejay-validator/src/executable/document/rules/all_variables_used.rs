@@ -14,21 +14,25 @@ pub struct AllVariablesUsed<'a, E: ExecutableDocument, S: SchemaDefinition> {
     fragment_references: HashMap<PathRoot<'a, E>, HashSet<Indexed<'a, E::FragmentDefinition>>>,
     variable_usages: HashMap<PathRoot<'a, E>, HashSet<&'a str>>,
     cache: &'a Cache<'a, E, S>,
-    executable_document: &'a E,
+    operation_definitions: Vec<&'a E::OperationDefinition>,
 }
 
 impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
     for AllVariablesUsed<'a, E, S>
 {
-    fn new(executable_document: &'a E, _: &'a S, cache: &'a Cache<'a, E, S>) -> Self {
+    fn new(_: &'a E, _: &'a S, cache: &'a Cache<'a, E, S>) -> Self {
         Self {
             fragment_references: HashMap::new(),
             variable_usages: HashMap::new(),
             cache,
-            executable_document,
+            operation_definitions: Vec::new(),
         }
     }
 
+    fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
+        self.operation_definitions.push(operation_definition);
+    }
+
     fn visit_variable_argument(
         &mut self,
         argument: &'a <E as ExecutableDocument>::Argument<false>,
@@ -109,8 +113,9 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
     type Errors = std::vec::IntoIter<Error<'a, E, S>>;
 
     fn into_errors(self) -> Self::Errors {
-        self.executable_document
-            .operation_definitions()
+        self.operation_definitions
+            .iter()
+            .copied()
             .filter(|operation_definition| {
                 operation_definition
                     .as_ref()
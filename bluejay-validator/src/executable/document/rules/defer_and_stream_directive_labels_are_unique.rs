@@ -0,0 +1,95 @@
+use crate::executable::{
+    document::{Error, Rule, Visitor},
+    Cache,
+};
+use crate::utils::{
+    defines_builtin_directive, directive_argument, DEFER_DIRECTIVE_NAME, STREAM_DIRECTIVE_NAME,
+};
+use bluejay_core::definition::{DirectiveLocation, SchemaDefinition};
+use bluejay_core::executable::ExecutableDocument;
+use bluejay_core::{Argument, Directive, Value, ValueReference};
+use std::collections::BTreeMap;
+
+pub struct DeferAndStreamDirectiveLabelsAreUnique<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    /// Whether the schema opted into the builtin `@defer` directive
+    defer_defined: bool,
+    /// Whether the schema opted into the builtin `@stream` directive
+    stream_defined: bool,
+    directives_by_label: BTreeMap<&'a str, Vec<&'a E::Directive<false>>>,
+    errors: Vec<Error<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
+    for DeferAndStreamDirectiveLabelsAreUnique<'a, E, S>
+{
+    fn new(_: &'a E, schema_definition: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            defer_defined: defines_builtin_directive(schema_definition, DEFER_DIRECTIVE_NAME),
+            stream_defined: defines_builtin_directive(schema_definition, STREAM_DIRECTIVE_NAME),
+            directives_by_label: BTreeMap::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn visit_variable_directive(
+        &mut self,
+        directive: &'a E::Directive<false>,
+        _: DirectiveLocation,
+    ) {
+        let is_incremental_delivery_directive = match directive.name() {
+            DEFER_DIRECTIVE_NAME => self.defer_defined,
+            STREAM_DIRECTIVE_NAME => self.stream_defined,
+            _ => false,
+        };
+        if !is_incremental_delivery_directive {
+            return;
+        }
+
+        if let Some(label) = directive_argument(directive, "label") {
+            match label.value().as_ref() {
+                ValueReference::String(label) => self
+                    .directives_by_label
+                    .entry(label)
+                    .or_default()
+                    .push(directive),
+                ValueReference::Variable(_) => {
+                    self.errors
+                        .push(Error::DeferOrStreamDirectiveLabelNotStatic {
+                            directive,
+                            label: label.value(),
+                        })
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for DeferAndStreamDirectiveLabelsAreUnique<'a, E, S>
+{
+    type Error = Error<'a, E, S>;
+    type Errors = std::vec::IntoIter<Error<'a, E, S>>;
+
+    fn into_errors(self) -> Self::Errors {
+        let Self {
+            directives_by_label,
+            mut errors,
+            ..
+        } = self;
+
+        errors.extend(
+            directives_by_label
+                .into_iter()
+                .filter(|(_, directives)| directives.len() > 1)
+                .map(
+                    |(label, directives)| Error::NonUniqueDeferAndStreamDirectiveLabels {
+                        label,
+                        directives,
+                    },
+                ),
+        );
+
+        errors.into_iter()
+    }
+}
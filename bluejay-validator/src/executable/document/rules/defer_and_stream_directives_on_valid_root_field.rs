@@ -0,0 +1,81 @@
+use crate::executable::{
+    document::{Error, Rule, Visitor},
+    Cache,
+};
+use crate::utils::{
+    defines_builtin_directive, find_directive, is_disabled_by_if_argument, DEFER_DIRECTIVE_NAME,
+    STREAM_DIRECTIVE_NAME,
+};
+use bluejay_core::definition::{ObjectTypeDefinition, SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentSpread, InlineFragment, Selection, SelectionReference,
+};
+use bluejay_core::AsIter;
+
+pub struct DeferAndStreamDirectivesOnValidRootField<'a, E: ExecutableDocument, S: SchemaDefinition>
+{
+    schema_definition: &'a S,
+    /// Whether the schema opted into the builtin `@defer` directive
+    defer_defined: bool,
+    /// Whether the schema opted into the builtin `@stream` directive
+    stream_defined: bool,
+    errors: Vec<Error<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
+    for DeferAndStreamDirectivesOnValidRootField<'a, E, S>
+{
+    fn new(_: &'a E, schema_definition: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            schema_definition,
+            defer_defined: defines_builtin_directive(schema_definition, DEFER_DIRECTIVE_NAME),
+            stream_defined: defines_builtin_directive(schema_definition, STREAM_DIRECTIVE_NAME),
+            errors: Vec::new(),
+        }
+    }
+
+    fn visit_selection_set(
+        &mut self,
+        selection_set: &'a E::SelectionSet,
+        r#type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        let is_subscription_root = self
+            .schema_definition
+            .subscription()
+            .is_some_and(|subscription| subscription.name() == r#type.name());
+
+        if !is_subscription_root {
+            return;
+        }
+
+        self.errors
+            .extend(selection_set.iter().filter_map(|selection| {
+                let directive = match selection.as_ref() {
+                    SelectionReference::Field(field) if self.stream_defined => {
+                        find_directive(field.directives(), STREAM_DIRECTIVE_NAME)
+                    }
+                    SelectionReference::FragmentSpread(fragment_spread) if self.defer_defined => {
+                        find_directive(fragment_spread.directives(), DEFER_DIRECTIVE_NAME)
+                    }
+                    SelectionReference::InlineFragment(inline_fragment) if self.defer_defined => {
+                        find_directive(inline_fragment.directives(), DEFER_DIRECTIVE_NAME)
+                    }
+                    _ => None,
+                }?;
+
+                (!is_disabled_by_if_argument(directive))
+                    .then_some(Error::DeferOrStreamDirectiveOnSubscriptionRoot { directive })
+            }));
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for DeferAndStreamDirectivesOnValidRootField<'a, E, S>
+{
+    type Error = Error<'a, E, S>;
+    type Errors = std::vec::IntoIter<Error<'a, E, S>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.errors.into_iter()
+    }
+}
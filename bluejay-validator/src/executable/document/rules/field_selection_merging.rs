@@ -2,6 +2,7 @@ use crate::executable::{
     document::{Error, Rule, Visitor},
     Cache,
 };
+use crate::utils::{defines_builtin_directive, find_directive, STREAM_DIRECTIVE_NAME};
 use bluejay_core::definition::{
     FieldDefinition, FieldsDefinition, ObjectTypeDefinition, OutputType, OutputTypeReference,
     SchemaDefinition, TypeDefinitionReference,
@@ -10,13 +11,15 @@ use bluejay_core::executable::{
     ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment, Selection,
     SelectionReference,
 };
-use bluejay_core::{Arguments, AsIter, Indexed};
+use bluejay_core::{Arguments, AsIter, Directive, Indexed};
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ops::Not;
 
 pub struct FieldSelectionMerging<'a, E: ExecutableDocument, S: SchemaDefinition> {
     cache: &'a Cache<'a, E, S>,
     schema_definition: &'a S,
+    /// Whether the schema opted into the builtin `@stream` directive
+    stream_defined: bool,
     cached_errors: BTreeMap<Indexed<'a, E::SelectionSet>, Vec<Error<'a, E, S>>>,
 }
 
@@ -27,6 +30,7 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition> Visitor<'a, E, S>
         Self {
             cache,
             schema_definition,
+            stream_defined: defines_builtin_directive(schema_definition, STREAM_DIRECTIVE_NAME),
             cached_errors: BTreeMap::new(),
         }
     }
@@ -194,6 +198,14 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> FieldSelectionMer
                                         field_a: first.field,
                                         field_b: other.field,
                                     })
+                                } else if !self.same_stream_directives(first.field, other.field) {
+                                    Some(
+                                        Error::FieldSelectionsDoNotMergeDifferingStreamDirectives {
+                                            selection_set,
+                                            field_a: first.field,
+                                            field_b: other.field,
+                                        },
+                                    )
                                 } else {
                                     None
                                 }
@@ -336,6 +348,27 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> FieldSelectionMer
         });
     }
 
+    /// Fields are only mergeable if they are either both streamed with the same arguments,
+    /// or neither is streamed, when the schema opted into the builtin `@stream` directive
+    fn same_stream_directives(&self, field_a: &'a E::Field, field_b: &'a E::Field) -> bool {
+        if !self.stream_defined {
+            return true;
+        }
+        match (
+            find_directive(field_a.directives(), STREAM_DIRECTIVE_NAME),
+            find_directive(field_b.directives(), STREAM_DIRECTIVE_NAME),
+        ) {
+            (Some(stream_a), Some(stream_b)) => {
+                <E::Arguments<false> as Arguments<false>>::equivalent(
+                    stream_a.arguments(),
+                    stream_b.arguments(),
+                )
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
     fn same_output_type_shape(
         schema_definition: &S,
         type_a: &S::OutputType,
@@ -2,7 +2,10 @@ use crate::executable::{
     document::{Error, Rule, Visitor},
     Cache,
 };
-use bluejay_core::definition::{FieldsDefinition, SchemaDefinition, TypeDefinitionReference};
+use crate::utils::suggestion_list;
+use bluejay_core::definition::{
+    FieldDefinition, FieldsDefinition, SchemaDefinition, TypeDefinitionReference,
+};
 use bluejay_core::executable::{ExecutableDocument, Field, Selection, SelectionReference};
 use bluejay_core::AsIter;
 use std::ops::Not;
@@ -28,10 +31,19 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
                 .extend(selection_set.iter().filter_map(|selection| {
                     if let SelectionReference::Field(field) = selection.as_ref() {
                         let name = field.name();
-                        fields_definition
-                            .contains_field(name)
-                            .not()
-                            .then_some(Error::FieldDoesNotExistOnType { field, r#type })
+                        fields_definition.contains_field(name).not().then(|| {
+                            Error::FieldDoesNotExistOnType {
+                                field,
+                                r#type,
+                                suggestions: suggestion_list(
+                                    name,
+                                    fields_definition
+                                        .iter()
+                                        .filter(|field_definition| !field_definition.is_builtin())
+                                        .map(FieldDefinition::name),
+                                ),
+                            }
+                        })
                     } else {
                         None
                     }
@@ -2,15 +2,10 @@ use crate::executable::{
     document::{Error, Path, Rule, Visitor},
     Cache,
 };
-use bluejay_core::definition::{
-    ObjectTypeDefinition, SchemaDefinition, TypeDefinitionReference, UnionMemberType,
-    UnionTypeDefinition,
-};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
 use bluejay_core::executable::{
     ExecutableDocument, FragmentDefinition, FragmentSpread, InlineFragment,
 };
-use bluejay_core::AsIter;
-use std::collections::HashSet;
 
 pub struct FragmentSpreadIsPossible<'a, E: ExecutableDocument, S: SchemaDefinition> {
     errors: Vec<Error<'a, E, S>>,
@@ -70,43 +65,30 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> Visitor<'a, E, S>
 }
 
 impl<'a, E: ExecutableDocument, S: SchemaDefinition> FragmentSpreadIsPossible<'a, E, S> {
-    fn get_possible_types(
-        &self,
-        t: TypeDefinitionReference<'a, S::TypeDefinition>,
-    ) -> Option<HashSet<&'a str>> {
-        match t {
-            TypeDefinitionReference::Object(_) => Some(HashSet::from([t.name()])),
-            TypeDefinitionReference::Interface(itd) => Some(HashSet::from_iter(
-                self.schema_definition
-                    .get_interface_implementors(itd)
-                    .map(ObjectTypeDefinition::name),
-            )),
-            TypeDefinitionReference::Union(utd) => Some(HashSet::from_iter(
-                utd.union_member_types()
-                    .iter()
-                    .map(|union_member| union_member.name()),
-            )),
-            TypeDefinitionReference::BuiltinScalar(_)
-            | TypeDefinitionReference::CustomScalar(_)
-            | TypeDefinitionReference::Enum(_)
-            | TypeDefinitionReference::InputObject(_) => None,
-        }
-    }
-
     fn spread_is_not_possible(
         &self,
         parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         fragment_type: TypeDefinitionReference<'a, S::TypeDefinition>,
     ) -> bool {
-        let parent_type_possible_types = self.get_possible_types(parent_type);
-        let fragment_possible_types = self.get_possible_types(fragment_type);
+        // spreads between object types don't need the possible types of the schema cache
+        if matches!(
+            (parent_type, fragment_type),
+            (
+                TypeDefinitionReference::Object(_),
+                TypeDefinitionReference::Object(_)
+            )
+        ) {
+            return parent_type.name() != fragment_type.name();
+        }
+        let schema_cache = self.cache.schema_cache();
 
         matches!(
-            (parent_type_possible_types, fragment_possible_types),
-            (Some(parent_type_possible_types), Some(fragment_possible_types)) if parent_type_possible_types
-                .intersection(&fragment_possible_types)
-                .next()
-                .is_none(),
+            (
+                schema_cache.possible_type_names(parent_type.name()),
+                schema_cache.possible_type_names(fragment_type.name()),
+            ),
+            (Some(parent_type_possible_types), Some(fragment_possible_types))
+                if parent_type_possible_types.is_disjoint(fragment_possible_types),
         )
     }
 }
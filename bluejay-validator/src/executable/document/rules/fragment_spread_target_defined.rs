@@ -2,6 +2,7 @@ use crate::executable::{
     document::{Error, Path, Rule, Visitor},
     Cache,
 };
+use crate::utils::suggestion_list;
 use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
 use bluejay_core::executable::{ExecutableDocument, FragmentDefinition, FragmentSpread};
 use std::collections::HashSet;
@@ -35,8 +36,13 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> Visitor<'a, E, S>
             .fragment_definition_names
             .contains(fragment_spread.name())
         {
-            self.errors
-                .push(Error::FragmentSpreadTargetUndefined { fragment_spread });
+            self.errors.push(Error::FragmentSpreadTargetUndefined {
+                fragment_spread,
+                suggestions: suggestion_list(
+                    fragment_spread.name(),
+                    self.fragment_definition_names.iter().copied(),
+                ),
+            });
         }
     }
 }
@@ -49,6 +49,19 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> FragmentSpreadsMustNotFormC
             None
         }
     }
+
+    /// Keeps only the cycles reported for one of `fragment_definitions`
+    fn retain_fragment_definitions(&mut self, fragment_definitions: &[&'a E::FragmentDefinition]) {
+        self.errors.retain(|error| match error {
+            Error::FragmentSpreadCycle {
+                fragment_definition,
+                ..
+            } => fragment_definitions
+                .iter()
+                .any(|retained| std::ptr::eq(*retained, *fragment_definition)),
+            _ => true,
+        });
+    }
 }
 
 impl<'a, E: ExecutableDocument, S: SchemaDefinition> Visitor<'a, E, S>
@@ -82,6 +95,22 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> Visitor<'a, E, S>
             .collect();
         Self { errors }
     }
+
+    fn restrict_to_operation(
+        &mut self,
+        _operation_definition: &'a E::OperationDefinition,
+        fragment_definitions: &[&'a E::FragmentDefinition],
+    ) {
+        // every fragment definition in a reachable cycle is itself reachable
+        self.retain_fragment_definitions(fragment_definitions);
+    }
+
+    fn restrict_to_fragment_definitions(
+        &mut self,
+        fragment_definitions: &[&'a E::FragmentDefinition],
+    ) {
+        self.retain_fragment_definitions(fragment_definitions);
+    }
 }
 
 impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
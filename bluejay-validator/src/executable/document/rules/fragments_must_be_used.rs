@@ -23,6 +23,23 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> Visitor<'a, E, S>
         }
     }
 
+    fn restrict_to_operation(
+        &mut self,
+        _operation_definition: &'a E::OperationDefinition,
+        fragment_definitions: &[&'a E::FragmentDefinition],
+    ) {
+        self.unused_fragment_definitions =
+            BTreeMap::from_iter(fragment_definitions.iter().map(|&fd| (fd.name(), fd)));
+    }
+
+    fn restrict_to_fragment_definitions(
+        &mut self,
+        _fragment_definitions: &[&'a E::FragmentDefinition],
+    ) {
+        // the fragment definitions can be spread by operations that are not visited
+        self.unused_fragment_definitions.clear();
+    }
+
     fn visit_fragment_spread(
         &mut self,
         fragment_spread: &'a <E as ExecutableDocument>::FragmentSpread,
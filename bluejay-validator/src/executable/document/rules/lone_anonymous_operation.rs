@@ -7,7 +7,7 @@ use bluejay_core::executable::{ExecutableDocument, OperationDefinition};
 
 pub struct LoneAnonymousOperation<'a, E: ExecutableDocument> {
     anonymous_operations: Vec<&'a E::OperationDefinition>,
-    executable_document: &'a E,
+    operation_count: usize,
 }
 
 impl<'a, E: ExecutableDocument, S: SchemaDefinition> Visitor<'a, E, S>
@@ -16,10 +16,18 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition> Visitor<'a, E, S>
     fn new(executable_document: &'a E, _: &'a S, _: &'a Cache<'a, E, S>) -> Self {
         Self {
             anonymous_operations: Vec::new(),
-            executable_document,
+            operation_count: executable_document.operation_definitions().count(),
         }
     }
 
+    fn restrict_to_operation(
+        &mut self,
+        _operation_definition: &'a E::OperationDefinition,
+        _fragment_definitions: &[&'a E::FragmentDefinition],
+    ) {
+        self.operation_count = 1;
+    }
+
     fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
         if operation_definition.as_ref().name().is_none() {
             self.anonymous_operations.push(operation_definition);
@@ -34,11 +42,10 @@ impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
     type Errors = <Option<Error<'a, E, S>> as IntoIterator>::IntoIter;
 
     fn into_errors(self) -> Self::Errors {
-        (self.executable_document.operation_definitions().count() != 1
-            && !self.anonymous_operations.is_empty())
-        .then_some(Error::NotLoneAnonymousOperation {
-            anonymous_operations: self.anonymous_operations,
-        })
-        .into_iter()
+        (self.operation_count != 1 && !self.anonymous_operations.is_empty())
+            .then_some(Error::NotLoneAnonymousOperation {
+                anonymous_operations: self.anonymous_operations,
+            })
+            .into_iter()
     }
 }
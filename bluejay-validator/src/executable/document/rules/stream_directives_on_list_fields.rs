@@ -0,0 +1,64 @@
+use crate::executable::{
+    document::{Error, Path, Rule, Visitor},
+    Cache,
+};
+use crate::utils::{
+    defines_builtin_directive, find_directive, is_disabled_by_if_argument, STREAM_DIRECTIVE_NAME,
+};
+use bluejay_core::definition::{
+    FieldDefinition, OutputType, SchemaDefinition, ShallowOutputTypeReference,
+};
+use bluejay_core::executable::{ExecutableDocument, Field};
+
+pub struct StreamDirectivesOnListFields<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    /// Whether the schema opted into the builtin `@stream` directive
+    stream_defined: bool,
+    errors: Vec<Error<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Visitor<'a, E, S>
+    for StreamDirectivesOnListFields<'a, E, S>
+{
+    fn new(_: &'a E, schema_definition: &'a S, _: &'a Cache<'a, E, S>) -> Self {
+        Self {
+            stream_defined: defines_builtin_directive(schema_definition, STREAM_DIRECTIVE_NAME),
+            errors: Vec::new(),
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        _: &Path<'a, E>,
+    ) {
+        if !self.stream_defined {
+            return;
+        }
+        if let Some(directive) = find_directive(field.directives(), STREAM_DIRECTIVE_NAME) {
+            if !is_disabled_by_if_argument(directive)
+                && !matches!(
+                    field_definition.r#type().as_shallow_ref(),
+                    ShallowOutputTypeReference::List(..)
+                )
+            {
+                self.errors.push(Error::StreamDirectiveOnNonListField {
+                    directive,
+                    field,
+                    field_definition,
+                });
+            }
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition + 'a> Rule<'a, E, S>
+    for StreamDirectivesOnListFields<'a, E, S>
+{
+    type Error = Error<'a, E, S>;
+    type Errors = std::vec::IntoIter<Error<'a, E, S>>;
+
+    fn into_errors(self) -> Self::Errors {
+        self.errors.into_iter()
+    }
+}
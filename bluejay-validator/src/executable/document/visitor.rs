@@ -9,6 +9,25 @@ pub trait Visitor<'a, E: ExecutableDocument, S: SchemaDefinition> {
         cache: &'a Cache<'a, E, S>,
     ) -> Self;
 
+    /// Called before visiting when only `operation_definition` and the fragment definitions
+    /// it transitively spreads will be visited, for visitors that look at the whole document
+    fn restrict_to_operation(
+        &mut self,
+        _operation_definition: &'a E::OperationDefinition,
+        _fragment_definitions: &[&'a E::FragmentDefinition],
+    ) {
+    }
+
+    /// Called before visiting when only `fragment_definitions` will be visited, without any
+    /// operation definition, for visitors that look at the whole document. The fragment
+    /// definitions can be spread by operations that are not visited, such as those of the other
+    /// documents of a [`Project`](crate::executable::document::Project).
+    fn restrict_to_fragment_definitions(
+        &mut self,
+        _fragment_definitions: &[&'a E::FragmentDefinition],
+    ) {
+    }
+
     fn visit_operation_definition(&mut self, _operation_definition: &'a E::OperationDefinition) {}
 
     fn visit_selection_set(
@@ -110,6 +129,21 @@ macro_rules! impl_visitor {
                     )
                 }
 
+                fn restrict_to_operation(
+                    &mut self,
+                    operation_definition: &'a E::OperationDefinition,
+                    fragment_definitions: &[&'a E::FragmentDefinition],
+                ) {
+                    #(self.N.restrict_to_operation(operation_definition, fragment_definitions);)*
+                }
+
+                fn restrict_to_fragment_definitions(
+                    &mut self,
+                    fragment_definitions: &[&'a E::FragmentDefinition],
+                ) {
+                    #(self.N.restrict_to_fragment_definitions(fragment_definitions);)*
+                }
+
                 fn visit_operation_definition(&mut self, operation_definition: &'a E::OperationDefinition) {
                     #(self.N.visit_operation_definition(operation_definition);)*
                 }
@@ -217,4 +251,4 @@ seq_macro::seq!(N in 2..=10 {
     impl_visitor!(N);
 });
 
-impl_visitor!(26);
+impl_visitor!(29);
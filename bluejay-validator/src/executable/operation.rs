@@ -1,10 +1,19 @@
 mod analyzer;
 pub mod analyzers;
+mod collect_fields;
 mod orchestrator;
+#[cfg(feature = "serde_json")]
+mod response_shape;
 mod variable_values;
 mod visitor;
 
 pub use analyzer::Analyzer;
+pub use collect_fields::{collect_fields, FieldCollector, GroupedFieldSet};
+#[cfg(feature = "serde_json")]
+pub(crate) use collect_fields::{ObjectTypeResolutionError, TYPENAME_FIELD_NAME};
+pub(crate) use orchestrator::resolve_operation;
 pub use orchestrator::{OperationResolutionError, Orchestrator};
+#[cfg(feature = "serde_json")]
+pub use response_shape::{validate_response, ResponseShapeError};
 pub use variable_values::{OperationDefinitionValueEvaluationExt, VariableValues};
 pub use visitor::Visitor;
@@ -1,11 +1,24 @@
+mod coerce_variable_values;
 pub mod complexity_cost;
-mod deprecation;
+pub mod deprecation;
 mod input_size;
+mod introspection;
 mod query_depth;
+mod response_size;
+mod schema_usage;
+pub mod selection_limits;
 mod variable_values_are_valid;
 
-pub use complexity_cost::ComplexityCost;
+pub use coerce_variable_values::{CoerceVariableValues, CoercedVariables};
+pub use complexity_cost::{ComplexityCost, ComplexityCostBreakdown};
 pub use deprecation::Deprecation;
-pub use input_size::InputSize;
+pub use input_size::{InputSize, Offender};
+pub use introspection::{Introspection, IntrospectionLimits, IntrospectionReport};
 pub use query_depth::QueryDepth;
+pub use response_size::{ResponseSize, ResponseSizeEstimate};
+pub use schema_usage::{ArgumentUsage, SchemaCoordinate, SchemaUsage, SchemaUsageReport};
+pub use selection_limits::{
+    AliasCount, DirectiveCount, FragmentSpreadCount, RootFieldCount, TotalFieldCount,
+    UniqueFieldCount,
+};
 pub use variable_values_are_valid::{VariableValueError, VariableValuesAreValid};
@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use crate::{
+    executable::{
+        operation::{analyzers::VariableValueError, Analyzer, VariableValues, Visitor},
+        Cache,
+    },
+    value::{coerce_validated_value, input_coercion::CoerceInput, CoercedValue},
+};
+use bluejay_core::definition::SchemaDefinition;
+use bluejay_core::executable::{ExecutableDocument, VariableDefinition};
+
+/// Implements the CoerceVariableValues algorithm of the specification, producing the coerced
+/// value of every variable of the operation, or the same errors as
+/// [`VariableValuesAreValid`](super::VariableValuesAreValid) if any variable value is invalid.
+pub struct CoerceVariableValues<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues>
+{
+    executable_document: PhantomData<E>,
+    schema_definition: &'a S,
+    indexed_variable_values: HashMap<&'a str, (&'a VV::Key, &'a VV::Value)>,
+    cache: &'a Cache<'a, E, S>,
+    coerced_variables: Vec<(&'a str, CoercedValue)>,
+    errors: Vec<VariableValueError<'a, E, VV>>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for CoerceVariableValues<'a, E, S, VV>
+{
+    type ExtraInfo = ();
+
+    fn new(
+        _: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a VV,
+        cache: &'a Cache<'a, E, S>,
+        _: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            executable_document: PhantomData,
+            schema_definition,
+            indexed_variable_values: variable_values
+                .iter()
+                .map(|(key, value)| (key.as_ref(), (key, value)))
+                .collect(),
+            cache,
+            coerced_variables: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn visit_variable_definition(
+        &mut self,
+        variable_definition: &'a <E as ExecutableDocument>::VariableDefinition,
+    ) {
+        let key_and_value = self
+            .indexed_variable_values
+            .remove(variable_definition.variable());
+        let Some(variable_definition_input_type) = self
+            .cache
+            .variable_definition_input_type(variable_definition.r#type())
+        else {
+            return;
+        };
+        let no_variables = |_: &str| None;
+        match key_and_value {
+            Some((_, value)) => {
+                match self.schema_definition.coerce_const_value(
+                    variable_definition_input_type,
+                    value,
+                    Default::default(),
+                ) {
+                    Ok(()) => self.coerced_variables.push((
+                        variable_definition.variable(),
+                        coerce_validated_value(
+                            self.schema_definition,
+                            variable_definition_input_type,
+                            value,
+                            &no_variables,
+                        ),
+                    )),
+                    Err(errors) => self.errors.push(VariableValueError::InvalidValue {
+                        variable_definition,
+                        value,
+                        errors,
+                    }),
+                }
+            }
+            None => {
+                if let Some(default_value) = variable_definition.default_value() {
+                    self.coerced_variables.push((
+                        variable_definition.variable(),
+                        coerce_validated_value(
+                            self.schema_definition,
+                            variable_definition_input_type,
+                            default_value,
+                            &no_variables,
+                        ),
+                    ));
+                } else if variable_definition.is_required() {
+                    self.errors.push(VariableValueError::MissingValue {
+                        variable_definition,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for CoerceVariableValues<'a, E, S, VV>
+{
+    type Output = Result<CoercedVariables<'a>, Vec<VariableValueError<'a, E, VV>>>;
+
+    fn into_output(mut self) -> Self::Output {
+        self.errors.extend(
+            self.indexed_variable_values
+                .into_values()
+                .map(|(key, value)| VariableValueError::UnusedValue { key, value }),
+        );
+        if self.errors.is_empty() {
+            Ok(CoercedVariables(self.coerced_variables))
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+/// The coerced variable values of an operation, in the order the variables are defined.
+/// Variables that were not provided and have no default value are absent.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CoercedVariables<'a>(Vec<(&'a str, CoercedValue)>);
+
+impl<'a> CoercedVariables<'a> {
+    pub fn get(&self, name: &str) -> Option<&CoercedValue> {
+        self.0
+            .iter()
+            .find_map(|(variable, value)| (*variable == name).then_some(value))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &CoercedValue)> {
+        self.0.iter().map(|(variable, value)| (*variable, value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<'a> VariableValues for CoercedVariables<'a> {
+    type Key = &'a str;
+    type Value = CoercedValue;
+    type Iterator<'b>
+        = std::iter::Map<
+        std::slice::Iter<'b, (&'a str, CoercedValue)>,
+        fn(&'b (&'a str, CoercedValue)) -> (&'b &'a str, &'b CoercedValue),
+    >
+    where
+        Self: 'b;
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.0.iter().map(|(key, value)| (key, value))
+    }
+
+    fn get(&self, key: &str) -> Option<&Self::Value> {
+        CoercedVariables::get(self, key)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<CoercedVariables<'_>> for serde_json::Map<String, serde_json::Value> {
+    fn from(value: CoercedVariables<'_>) -> Self {
+        value
+            .0
+            .into_iter()
+            .map(|(variable, value)| (variable.to_owned(), value.into()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::executable::{operation::Orchestrator, Cache};
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::json;
+
+    use super::CoerceVariableValues;
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        type Query {
+            dogs(commands: [DogCommand!], filter: DogFilter, weight: Float, id: ID): [String!]!
+        }
+
+        enum DogCommand {
+            SIT
+            DOWN
+        }
+
+        input DogFilter {
+            name: String
+            command: DogCommand! = SIT
+            minWeight: Float = 1
+            tags: [String!]
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    fn coerce_variable_values(
+        source: &str,
+        variable_values: serde_json::Value,
+    ) -> Result<serde_json::Value, Vec<String>> {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        Orchestrator::<_, _, _, CoerceVariableValues<_, _, _>>::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variable_values
+                .as_object()
+                .expect("Variables must be an object"),
+            &cache,
+            (),
+        )
+        .unwrap()
+        .map(|coerced_variables| {
+            serde_json::Value::Object(serde_json::Map::from(coerced_variables))
+        })
+        .map_err(|errors| errors.iter().map(|error| error.message()).collect())
+    }
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!(
+            Ok(json!({ "commands": ["DOWN"], "weight": 2.5 })),
+            coerce_variable_values(
+                r#"
+                    query($commands: [DogCommand!] = [DOWN], $weight: Float = 2.5, $id: ID) {
+                        dogs(commands: $commands, weight: $weight, id: $id)
+                    }
+                "#,
+                json!({}),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_explicit_null() {
+        assert_eq!(
+            Ok(json!({ "commands": null })),
+            coerce_variable_values(
+                r#"
+                    query($commands: [DogCommand!] = [DOWN]) {
+                        dogs(commands: $commands)
+                    }
+                "#,
+                json!({ "commands": null }),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_list_promotion_and_enum_resolution() {
+        assert_eq!(
+            Ok(json!({ "commands": ["SIT"] })),
+            coerce_variable_values(
+                r#"
+                    query($commands: [DogCommand!]) {
+                        dogs(commands: $commands)
+                    }
+                "#,
+                json!({ "commands": "SIT" }),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_int_promotion() {
+        let executable_document = ExecutableDocument::parse(
+            r#"
+                query($weight: Float, $id: ID) {
+                    dogs(weight: $weight, id: $id)
+                }
+            "#,
+        )
+        .result
+        .unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variable_values = json!({ "weight": 3, "id": 4 });
+        let coerced_variables = Orchestrator::<_, _, _, CoerceVariableValues<_, _, _>>::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variable_values.as_object().unwrap(),
+            &cache,
+            (),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            Some(&super::CoercedValue::Float(3.0)),
+            coerced_variables.get("weight"),
+        );
+        assert_eq!(
+            Some(&super::CoercedValue::String("4".to_owned())),
+            coerced_variables.get("id"),
+        );
+    }
+
+    #[test]
+    fn test_input_object_defaults() {
+        assert_eq!(
+            Ok(json!({
+                "filter": { "name": "Fido", "command": "SIT", "minWeight": 1.0, "tags": ["good"] },
+            })),
+            coerce_variable_values(
+                r#"
+                    query($filter: DogFilter) {
+                        dogs(filter: $filter)
+                    }
+                "#,
+                json!({ "filter": { "name": "Fido", "tags": "good" } }),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Err(vec![
+                "Invalid value for variable $commands:\n- No member `STAY` on enum DogCommand"
+                    .to_owned()
+            ]),
+            coerce_variable_values(
+                r#"
+                    query($commands: [DogCommand!]) {
+                        dogs(commands: $commands)
+                    }
+                "#,
+                json!({ "commands": ["STAY"] }),
+            ),
+        );
+    }
+}
@@ -3,24 +3,40 @@ use crate::executable::{
     Cache,
 };
 use bluejay_core::definition::{
-    FieldDefinition, ObjectTypeDefinition, OutputType, SchemaDefinition, TypeDefinition,
-    TypeDefinitionReference, UnionMemberType, UnionTypeDefinition,
+    FieldDefinition, OutputType, SchemaDefinition, TypeDefinition, TypeDefinitionReference,
 };
 use bluejay_core::executable::{ExecutableDocument, Field};
 use bluejay_core::AsIter;
-use itertools::{Either, Itertools};
+use itertools::Itertools;
 use std::cmp::max;
 use std::collections::HashMap;
 
 mod arena;
 use arena::{Arena, NodeId};
 
+#[cfg(feature = "serde_json")]
+mod actual_cost;
+#[cfg(feature = "serde_json")]
+pub use actual_cost::{actual_cost, ActualCost, CostReconciliation, FieldCostReconciliation};
+
 mod cost_computer;
 pub use cost_computer::{CostComputer, DefaultCostComputer, FieldMultipliers};
 
+mod cost_directives_cost_computer;
+pub use cost_directives_cost_computer::{
+    CostDirectivesCostComputer, CostDirectivesFieldMultipliers, CostMetric, FieldCost, TypeCost,
+};
+
+mod cost_tree;
+use cost_tree::CostBreakdown;
+pub use cost_tree::{CostNode, CostTree};
+
 mod relay_cost_computer;
 pub use relay_cost_computer::RelayCostComputer;
 
+mod slicing_arguments_are_valid;
+pub use slicing_arguments_are_valid::{SlicingArgumentError, SlicingArgumentsAreValid};
+
 pub struct ComplexityCost<
     'a,
     E: ExecutableDocument,
@@ -29,9 +45,12 @@ pub struct ComplexityCost<
     C: CostComputer<'a, E, S, V> = DefaultCostComputer,
 > {
     schema_definition: &'a S,
+    cache: &'a Cache<'a, E, S>,
     cost_computer: C,
     scopes_arena: Arena<ComplexityScope<'a, S::TypeDefinition, C::FieldMultipliers>>,
     scopes_stack: Vec<Option<NodeId>>,
+    /// Whether to describe where the multipliers come from, only used by the breakdown
+    describe_multipliers: bool,
 }
 
 impl<
@@ -47,16 +66,18 @@ impl<
         operation_definition: &'a E::OperationDefinition,
         schema_definition: &'a S,
         variable_values: &'a V,
-        _: &'a Cache<'a, E, S>,
+        cache: &'a Cache<'a, E, S>,
         _: Self::ExtraInfo,
     ) -> Self {
         let mut scopes_arena = Arena::new();
         let scopes_stack = vec![Some(scopes_arena.add(ComplexityScope::default()))];
         Self {
             schema_definition,
+            cache,
             cost_computer: C::new(operation_definition, schema_definition, variable_values),
             scopes_arena,
             scopes_stack,
+            describe_multipliers: false,
         }
     }
 
@@ -70,9 +91,7 @@ impl<
         if !included {
             return;
         }
-        let cost = self
-            .cost_computer
-            .cost_for_field_definition(field_definition);
+        let cost = self.cost_computer.cost_for_field(field_definition, field);
 
         // Don't grow the costing tree for leaf fields without cost,
         // just hold their position in the traversal stack with a None scope
@@ -94,17 +113,25 @@ impl<
         let next_index = self.scopes_arena.next_id();
 
         // Get a mutable reference to the parent scope in the traversal stack
-        let parent_scope = self
+        let parent_index = self
             .scopes_stack
             .last()
             .copied()
             .flatten()
-            .and_then(|index| self.scopes_arena.get_mut(index))
             .expect("expected a parent complexity scope");
+        let parent_scope = self
+            .scopes_arena
+            .get_mut(parent_index)
+            .expect("invalid complexity scope tree reference");
 
         // Collect any multipliers that the parent scope specifies for this field
         // ie: connection > edges/nodes
         let parent_multiplier = parent_scope.multiplier_for_field(field);
+        let parent_multiplier_description = if self.describe_multipliers {
+            parent_scope.describe_multiplier_for_field(field)
+        } else {
+            None
+        };
 
         // find or create a reference to this field's scope in the parent tree of typed selections
         // ie: parent_scope.typed_selections = { Type => { "field_key" => scopes_db_index, ... } }
@@ -125,6 +152,13 @@ impl<
             let field_multipliers = self
                 .cost_computer
                 .field_multipliers(field_definition, field);
+            // let the parent scope pass down multipliers for deeper descendants
+            let field_multipliers = self
+                .scopes_arena
+                .get(parent_index)
+                .expect("invalid complexity scope tree reference")
+                .field_multipliers
+                .nested_field_multipliers(field, field_multipliers);
 
             self.scopes_arena.add(ComplexityScope {
                 field_multipliers,
@@ -142,6 +176,7 @@ impl<
 
         // repeated scopes have a consistent argument multiplier in valid documents
         scope.multiplier = parent_multiplier;
+        scope.multiplier_description = parent_multiplier_description;
         scope.cost = scope.cost.max(cost);
     }
 
@@ -168,8 +203,85 @@ impl<
 {
     type Output = usize;
 
-    fn into_output(mut self) -> Self::Output {
-        self.result()
+    fn into_output(self) -> Self::Output {
+        self.result::<()>().0
+    }
+}
+
+/// Same as [ComplexityCost], but outputs a [CostTree] breaking the cost down by response path
+/// rather than the total cost alone.
+pub struct ComplexityCostBreakdown<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    V: VariableValues,
+    C: CostComputer<'a, E, S, V> = DefaultCostComputer,
+>(ComplexityCost<'a, E, S, V, C>);
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > Visitor<'a, E, S, V> for ComplexityCostBreakdown<'a, E, S, V, C>
+{
+    type ExtraInfo = ();
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a V,
+        cache: &'a Cache<'a, E, S>,
+        extra_info: Self::ExtraInfo,
+    ) -> Self {
+        let mut complexity_cost = ComplexityCost::new(
+            operation_definition,
+            schema_definition,
+            variable_values,
+            cache,
+            extra_info,
+        );
+        complexity_cost.describe_multipliers = true;
+        Self(complexity_cost)
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        self.0
+            .visit_field(field, field_definition, scoped_type, included);
+    }
+
+    fn leave_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        self.0
+            .leave_field(field, field_definition, scoped_type, included);
+    }
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > Analyzer<'a, E, S, V> for ComplexityCostBreakdown<'a, E, S, V, C>
+{
+    type Output = CostTree<'a>;
+
+    fn into_output(self) -> Self::Output {
+        let (total, fields) = self.0.result();
+        CostTree { total, fields }
     }
 }
 
@@ -181,7 +293,7 @@ impl<
         C: CostComputer<'a, E, S, V>,
     > ComplexityCost<'a, E, S, V, C>
 {
-    fn result(&mut self) -> usize {
+    fn result<B: CostBreakdown<'a>>(&self) -> (usize, B) {
         let root_scope = self
             .scopes_stack
             .first()
@@ -192,10 +304,10 @@ impl<
         self.merged_max_complexity_for_scopes(&[root_scope])
     }
 
-    fn merged_max_complexity_for_scopes(
+    fn merged_max_complexity_for_scopes<B: CostBreakdown<'a>>(
         &self,
         scopes: &[&ComplexityScope<'a, S::TypeDefinition, C::FieldMultipliers>],
-    ) -> usize {
+    ) -> (usize, B) {
         // build a set of all unique possible type definitions
         // with abstract types expanded to encompass all of their possible types
         let possible_type_names = scopes
@@ -228,16 +340,23 @@ impl<
                     })
                     .collect::<Vec<_>>();
 
-                self.merged_max_complexity_for_selections(inner_selections)
+                (
+                    possible_type_name,
+                    self.merged_max_complexity_for_selections::<B>(inner_selections),
+                )
+            })
+            // ties are broken by type name so that the breakdown is deterministic
+            .max_by(|(a_name, (a_cost, _)), (b_name, (b_cost, _))| {
+                a_cost.cmp(b_cost).then_with(|| b_name.cmp(a_name))
             })
-            .max()
-            .unwrap_or(0)
+            .map(|(_, cost_and_breakdown)| cost_and_breakdown)
+            .unwrap_or_default()
     }
 
-    fn merged_max_complexity_for_selections(
+    fn merged_max_complexity_for_selections<B: CostBreakdown<'a>>(
         &self,
         inner_selections: Vec<&InnerSelection<'a>>,
-    ) -> usize {
+    ) -> (usize, B) {
         // build a unique set of field keys from across inner selections.
         // the same field keys may appear in selections on different types,
         // ex: a "metafield" key may be selected on both Product and HasMetafield types.
@@ -246,11 +365,14 @@ impl<
             .flat_map(|child_scope| child_scope.keys())
             .unique();
 
+        let mut breakdown = B::default();
+
         // calculate a maximum possible cost for each unique field key
-        unique_field_keys
+        let cost = unique_field_keys
             .map(|field_key| {
                 let mut base_cost = 0;
                 let mut multiplier = 0;
+                let mut multiplier_description = None;
 
                 // collect child scopes from across composite selections
                 // leaf selections report their costs directly
@@ -264,6 +386,12 @@ impl<
                                 // base_cost and multiplier select their maximums from across merged scopes
                                 // in case a field name has different costs in different scope types.
                                 base_cost = max(base_cost, child_scope.cost);
+                                if multiplier_description.is_none()
+                                    || child_scope.multiplier > multiplier
+                                {
+                                    multiplier_description =
+                                        child_scope.multiplier_description.as_deref();
+                                }
                                 multiplier = max(multiplier, child_scope.multiplier);
 
                                 if !child_scope.typed_selections.is_empty() {
@@ -275,31 +403,36 @@ impl<
                     })
                     .collect::<Vec<&ComplexityScope<'a, S::TypeDefinition, C::FieldMultipliers>>>();
 
-                let children_cost = self.merged_max_complexity_for_scopes(&composite_scopes);
+                let (children_cost, children) =
+                    self.merged_max_complexity_for_scopes::<B>(&composite_scopes);
+                let subtotal = (base_cost + children_cost) * multiplier;
 
-                (base_cost + children_cost) * multiplier
+                breakdown.add(
+                    field_key,
+                    base_cost,
+                    multiplier,
+                    multiplier_description,
+                    subtotal,
+                    children,
+                );
+
+                subtotal
             })
-            .sum()
+            .sum();
+
+        (cost, breakdown)
     }
 
     fn possible_type_names(
         &self,
         ty: &TypeDefinitionReference<'a, S::TypeDefinition>,
     ) -> impl Iterator<Item = &'a str> {
-        match ty {
-            TypeDefinitionReference::Object(_) => Either::Left(Some(ty.name()).into_iter()),
-            TypeDefinitionReference::Interface(itd) => Either::Right(Either::Left(
-                self.schema_definition
-                    .get_interface_implementors(itd)
-                    .map(ObjectTypeDefinition::name),
-            )),
-            TypeDefinitionReference::Union(utd) => Either::Right(Either::Right(
-                utd.union_member_types()
-                    .iter()
-                    .map(|union_member| union_member.name()),
-            )),
-            _ => Either::Left(None.into_iter()),
-        }
+        self.cache
+            .schema_cache()
+            .possible_type_names(ty.name())
+            .into_iter()
+            .flatten()
+            .copied()
     }
 }
 
@@ -313,6 +446,7 @@ struct TypedSelection<'a, T: TypeDefinition> {
 struct ComplexityScope<'a, T: TypeDefinition, F> {
     cost: usize,
     multiplier: usize,
+    multiplier_description: Option<String>,
     typed_selections: HashMap<&'a str, TypedSelection<'a, T>>,
     field_multipliers: F,
 }
@@ -322,6 +456,7 @@ impl<T: TypeDefinition, F: Default> Default for ComplexityScope<'_, T, F> {
         Self {
             cost: 0,
             multiplier: 1,
+            multiplier_description: None,
             typed_selections: HashMap::new(),
             field_multipliers: F::default(),
         }
@@ -335,6 +470,16 @@ impl<T: TypeDefinition, F> ComplexityScope<'_, T, F> {
     {
         self.field_multipliers.multiplier_for_field(field)
     }
+
+    fn describe_multiplier_for_field<E: ExecutableDocument>(
+        &self,
+        field: &E::Field,
+    ) -> Option<String>
+    where
+        F: FieldMultipliers<E>,
+    {
+        self.field_multipliers.describe_multiplier_for_field(field)
+    }
 }
 
 #[cfg(test)]
@@ -1210,4 +1355,77 @@ mod tests {
             1,
         );
     }
+
+    #[test]
+    fn cost_breakdown_by_response_path() {
+        let definition_document: DefinitionDocument<'_, DefaultContext> =
+            DefinitionDocument::parse(TEST_SCHEMA)
+                .result
+                .expect("Schema had parse errors");
+        let schema_definition =
+            SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+        let executable_document = ExecutableDocument::parse(
+            r#"{
+                oneObjectConnection(first: 50) { # 1 + 21 + 21 + 0 = 43
+                    edges { node { twoScalar } } # (0 + 1 + 2) * floor(2 * log(50)) = 21
+                    nodes { twoScalar } # (1 + 2) * floor(2 * log(50)) = 21
+                    pageInfo { hasNextPage } # 0
+                }
+                zeroScalar
+            }"#,
+        )
+        .result
+        .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let variables = serde_json::Map::new();
+        let cost_tree = Orchestrator::<
+            _,
+            _,
+            _,
+            ComplexityCostBreakdown<_, _, _, RelayCostComputer<_, _, _>>,
+        >::analyze(
+            &executable_document,
+            &schema_definition,
+            None,
+            &variables,
+            &cache,
+            (),
+        )
+        .unwrap();
+
+        assert_eq!(43, cost_tree.total);
+        assert_eq!(
+            vec!["oneObjectConnection"],
+            cost_tree
+                .fields
+                .iter()
+                .map(|node| node.response_key)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            Some(&CostNode {
+                response_key: "node",
+                cost: 1,
+                multiplier: 1,
+                multiplier_description: None,
+                subtotal: 3,
+                children: vec![CostNode {
+                    response_key: "twoScalar",
+                    cost: 2,
+                    multiplier: 1,
+                    multiplier_description: None,
+                    subtotal: 2,
+                    children: vec![],
+                }],
+            }),
+            cost_tree.get(&["oneObjectConnection", "edges", "node"]),
+        );
+        assert_eq!(
+            "Total cost: 43\n\
+             43 oneObjectConnection (cost: 1, multiplier: 1)\n\
+             21 oneObjectConnection.edges (cost: 0, multiplier: 7 from first: 50)\n\
+             21 oneObjectConnection.nodes (cost: 1, multiplier: 7 from first: 50)\n",
+            cost_tree.render_top(3),
+        );
+    }
 }
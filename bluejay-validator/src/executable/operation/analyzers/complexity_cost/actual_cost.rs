@@ -0,0 +1,414 @@
+use super::{CostComputer, CostTree};
+use crate::executable::operation::{
+    resolve_operation, FieldCollector, GroupedFieldSet, OperationResolutionError, VariableValues,
+};
+use bluejay_core::definition::{
+    FieldDefinition, FieldsDefinition, ObjectTypeDefinition, OutputType, OutputTypeReference,
+    SchemaDefinition,
+};
+use bluejay_core::executable::{ExecutableDocument, Field, OperationDefinition};
+use bluejay_core::{AsIter, OperationType};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::collections::BTreeMap;
+
+/// The cost of an executed operation, computed from its response by [actual_cost]
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct ActualCost {
+    pub total: usize,
+    /// The cost of each field of the response by dot-separated response path, summed over every
+    /// object the field appears in. Fields without cost are omitted.
+    pub subtotals: BTreeMap<String, usize>,
+}
+
+impl ActualCost {
+    /// Compares the actual cost with the estimated cost of the same operation, as broken down by
+    /// [`ComplexityCostBreakdown`](super::ComplexityCostBreakdown) with the same [CostComputer]
+    pub fn reconcile(&self, estimate: &CostTree) -> CostReconciliation {
+        let mut fields: Vec<FieldCostReconciliation> = estimate
+            .iter()
+            .map(|(path, node)| FieldCostReconciliation {
+                estimated: node.subtotal,
+                actual: self.subtotals.get(&path).copied().unwrap_or_default(),
+                path,
+            })
+            .collect();
+        fields.extend(
+            self.subtotals
+                .iter()
+                .filter(|(path, _)| estimate.get(&path.split('.').collect::<Vec<_>>()).is_none())
+                .map(|(path, &actual)| FieldCostReconciliation {
+                    path: path.clone(),
+                    estimated: 0,
+                    actual,
+                }),
+        );
+
+        CostReconciliation {
+            estimated: estimate.total,
+            actual: self.total,
+            fields,
+        }
+    }
+}
+
+/// The estimated and actual costs of an operation, overall and for each response path
+#[derive(Clone, Debug, PartialEq)]
+pub struct CostReconciliation {
+    pub estimated: usize,
+    pub actual: usize,
+    /// The response paths of the estimate, most expensive first, followed by the paths that
+    /// only have an actual cost
+    pub fields: Vec<FieldCostReconciliation>,
+}
+
+impl CostReconciliation {
+    /// The ratio of the estimated cost to the actual cost, if the actual cost is not zero
+    pub fn ratio(&self) -> Option<f64> {
+        ratio(self.estimated, self.actual)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldCostReconciliation {
+    pub path: String,
+    pub estimated: usize,
+    pub actual: usize,
+}
+
+impl FieldCostReconciliation {
+    /// The ratio of the estimated cost to the actual cost, if the actual cost is not zero
+    pub fn ratio(&self) -> Option<f64> {
+        ratio(self.estimated, self.actual)
+    }
+}
+
+fn ratio(estimated: usize, actual: usize) -> Option<f64> {
+    (actual > 0).then(|| estimated as f64 / actual as f64)
+}
+
+/// Computes the actual cost of an operation from the `data` of its response, with the same
+/// [CostComputer] used to estimate the cost of the operation with
+/// [`ComplexityCost`](super::ComplexityCost).
+///
+/// Rather than the multipliers of the [CostComputer], the cost of each field is summed over
+/// every object it appears in, and lists are costed with their actual number of items (see
+/// [CostComputer::cost_for_list_items]). Fields missing from the response are not costed.
+/// Objects of abstract types are resolved by their `__typename` if it was selected, otherwise
+/// by the first possible type selecting every key of the object.
+pub fn actual_cost<
+    'a,
+    'b,
+    E: ExecutableDocument + 'a,
+    S: SchemaDefinition,
+    V: VariableValues,
+    C: CostComputer<'a, E, S, V>,
+>(
+    executable_document: &'a E,
+    schema_definition: &'a S,
+    operation_name: Option<&'b str>,
+    variable_values: &'a V,
+    data: &JsonValue,
+) -> Result<ActualCost, OperationResolutionError<'b>> {
+    let operation_definition = resolve_operation(executable_document, operation_name)?;
+    let mut computer = ActualCostComputer {
+        schema_definition,
+        cost_computer: C::new(operation_definition, schema_definition, variable_values),
+        field_collector: FieldCollector::new(
+            executable_document,
+            schema_definition,
+            variable_values,
+        ),
+        subtotals: BTreeMap::new(),
+    };
+
+    let operation_definition = operation_definition.as_ref();
+    let root_type = match operation_definition.operation_type() {
+        OperationType::Query => Some(schema_definition.query()),
+        OperationType::Mutation => schema_definition.mutation(),
+        OperationType::Subscription => schema_definition.subscription(),
+    };
+
+    let total = match (root_type, data) {
+        (Some(root_type), JsonValue::Object(object)) => {
+            let grouped_field_set = computer
+                .field_collector
+                .collect_fields(operation_definition.selection_set(), root_type)
+                .clone();
+            computer.selection_set_cost(root_type, &grouped_field_set, object, None)
+        }
+        _ => 0,
+    };
+
+    Ok(ActualCost {
+        total,
+        subtotals: computer.subtotals,
+    })
+}
+
+struct ActualCostComputer<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, V: VariableValues, C>
+{
+    schema_definition: &'a S,
+    cost_computer: C,
+    field_collector: FieldCollector<'a, E, S, V>,
+    subtotals: BTreeMap<String, usize>,
+}
+
+impl<
+        'a,
+        E: ExecutableDocument + 'a,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > ActualCostComputer<'a, E, S, V, C>
+{
+    fn selection_set_cost(
+        &mut self,
+        object_type: &'a S::ObjectTypeDefinition,
+        grouped_field_set: &GroupedFieldSet<'a, E>,
+        object: &JsonMap<String, JsonValue>,
+        path: Option<&str>,
+    ) -> usize {
+        grouped_field_set
+            .iter()
+            .filter_map(|(response_key, fields)| {
+                let value = object.get(response_key)?;
+                let field_definition = object_type.fields_definition().get(fields[0].name())?;
+                let path = match path {
+                    Some(path) => format!("{path}.{response_key}"),
+                    None => response_key.to_owned(),
+                };
+                Some(self.field_cost(field_definition, fields, value, path))
+            })
+            .sum()
+    }
+
+    fn field_cost(
+        &mut self,
+        field_definition: &'a S::FieldDefinition,
+        fields: &[&'a E::Field],
+        value: &JsonValue,
+        path: String,
+    ) -> usize {
+        let field = fields[0];
+        let mut item_count = 0;
+        let children_cost = self.value_cost(
+            field_definition.r#type(),
+            fields,
+            value,
+            &path,
+            &mut item_count,
+        );
+        let cost = match field_definition.r#type().as_ref(self.schema_definition) {
+            OutputTypeReference::List(_, _) => {
+                self.cost_computer
+                    .cost_for_list_items(field_definition, field, item_count)
+            }
+            OutputTypeReference::Base(_, _) => {
+                self.cost_computer.cost_for_field(field_definition, field)
+            }
+        };
+
+        let subtotal = cost + children_cost;
+        if subtotal > 0 {
+            *self.subtotals.entry(path).or_default() += subtotal;
+        }
+        subtotal
+    }
+
+    /// The cost of the children of the value, counting the items of lists along the way
+    fn value_cost(
+        &mut self,
+        output_type: &'a S::OutputType,
+        fields: &[&'a E::Field],
+        value: &JsonValue,
+        path: &str,
+        item_count: &mut usize,
+    ) -> usize {
+        match (output_type.as_ref(self.schema_definition), value) {
+            (_, JsonValue::Null) => 0,
+            (OutputTypeReference::List(inner, _), JsonValue::Array(items)) => items
+                .iter()
+                .map(|item| self.value_cost(inner, fields, item, path, item_count))
+                .sum(),
+            (OutputTypeReference::List(_, _), _) => 0,
+            (OutputTypeReference::Base(base, _), value) => {
+                *item_count += 1;
+                match value {
+                    JsonValue::Object(object) => self
+                        .field_collector
+                        .resolve_object_type(base, fields, object)
+                        .map_or(0, |(object_type, grouped_field_set)| {
+                            self.selection_set_cost(
+                                object_type,
+                                &grouped_field_set,
+                                object,
+                                Some(path),
+                            )
+                        }),
+                    _ => 0,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::actual_cost;
+    use crate::executable::{
+        operation::{
+            analyzers::complexity_cost::{
+                CostDirectivesCostComputer, FieldCostReconciliation, TypeCost,
+            },
+            analyzers::ComplexityCostBreakdown,
+            Orchestrator,
+        },
+        Cache,
+    };
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        directive @cost(weight: Float!) on ARGUMENT_DEFINITION | ENUM | FIELD_DEFINITION | INPUT_FIELD_DEFINITION | OBJECT | SCALAR
+        directive @listSize(assumedSize: Int, slicingArguments: [String!], sizedFields: [String!], requireOneSlicingArgument: Boolean = true) on FIELD_DEFINITION
+
+        type Query {
+            users(first: Int): [User!]! @listSize(slicingArguments: ["first"])
+            node(id: ID!): Node
+        }
+
+        interface Node {
+            id: ID!
+        }
+
+        type User implements Node @cost(weight: 2) {
+            id: ID!
+            name: String!
+            friends: [User!]! @listSize(assumedSize: 10)
+        }
+
+        type Post implements Node {
+            id: ID!
+            title: String!
+            author: User!
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    const QUERY: &str = r#"{
+        users(first: 5) { name friends { id } }
+        node(id: 1) { ... on Post { author { name } } }
+    }"#;
+
+    fn response_data() -> JsonValue {
+        json!({
+            "users": [
+                { "name": "a", "friends": [{ "id": "1" }, { "id": "2" }, { "id": "3" }] },
+                { "name": "b", "friends": [] },
+            ],
+            "node": { "author": { "name": "c" } },
+        })
+    }
+
+    fn field(path: &str, estimated: usize, actual: usize) -> FieldCostReconciliation {
+        FieldCostReconciliation {
+            path: path.to_owned(),
+            estimated,
+            actual,
+        }
+    }
+
+    #[test]
+    fn test_reconcile_field_cost() {
+        let executable_document = ExecutableDocument::parse(QUERY).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variables = JsonMap::new();
+        let estimate = Orchestrator::<
+            _,
+            _,
+            JsonMap<String, JsonValue>,
+            ComplexityCostBreakdown<_, _, _, CostDirectivesCostComputer<_, _, _>>,
+        >::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            &variables,
+            &cache,
+            (),
+        )
+        .unwrap();
+
+        let actual = actual_cost::<_, _, _, CostDirectivesCostComputer<_, _, _>>(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            &variables,
+            &response_data(),
+        )
+        .unwrap();
+
+        // users (2) + 2 * friends (2), node (1) + author (2)
+        assert_eq!(9, actual.total);
+
+        let reconciliation = actual.reconcile(&estimate);
+        assert_eq!(15, reconciliation.estimated);
+        assert_eq!(9, reconciliation.actual);
+        assert_eq!(
+            vec![
+                field("users", 12, 6),
+                field("users.friends", 10, 4),
+                field("node", 3, 3),
+                field("node.author", 2, 2),
+            ],
+            reconciliation.fields,
+        );
+        assert_eq!(Some(2.0), reconciliation.fields[0].ratio());
+    }
+
+    #[test]
+    fn test_type_cost_counts_actual_list_items() {
+        let executable_document = ExecutableDocument::parse(QUERY).result.unwrap();
+        let actual = actual_cost::<_, _, _, CostDirectivesCostComputer<_, _, _, TypeCost>>(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            &JsonMap::new(),
+            &response_data(),
+        )
+        .unwrap();
+
+        // 2 users (2) + 3 friends (2), node (1) + author (2)
+        assert_eq!(13, actual.total);
+        assert_eq!(Some(&6), actual.subtotals.get("users.friends"));
+    }
+
+    #[test]
+    fn test_abstract_types_resolved_by_typename() {
+        let executable_document = ExecutableDocument::parse(
+            "{ node(id: 1) { __typename ... on User { friends { id } } ... on Post { title } } }",
+        )
+        .result
+        .unwrap();
+        let actual = actual_cost::<_, _, _, CostDirectivesCostComputer<_, _, _, TypeCost>>(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            &JsonMap::new(),
+            &json!({ "node": { "__typename": "User", "friends": [{ "id": "1" }] } }),
+        )
+        .unwrap();
+
+        // node (1) + 1 friend (2)
+        assert_eq!(3, actual.total);
+    }
+}
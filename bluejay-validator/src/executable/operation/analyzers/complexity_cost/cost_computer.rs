@@ -13,6 +13,37 @@ pub trait CostComputer<'a, E: ExecutableDocument, S: SchemaDefinition, V: Variab
 
     fn cost_for_field_definition(&self, field_definition: &S::FieldDefinition) -> usize;
 
+    /// The cost of a selection of the field, defaulting to the cost of its definition.
+    /// Override to account for the arguments of the selection.
+    fn cost_for_field(&self, field_definition: &S::FieldDefinition, _field: &E::Field) -> usize {
+        self.cost_for_field_definition(field_definition)
+    }
+
+    /// The cost of a selection of a list field that returned `item_count` items, used to compute
+    /// the actual cost of a response. Defaults to the cost of the selection, for costs that do
+    /// not depend on the size of the list.
+    #[allow(unused_variables)]
+    fn cost_for_list_items(
+        &self,
+        field_definition: &S::FieldDefinition,
+        field: &E::Field,
+        item_count: usize,
+    ) -> usize {
+        self.cost_for_field(field_definition, field)
+    }
+
+    /// The estimated number of items returned by a selection of a list field, e.g. from its
+    /// arguments. Multipliers only apply to the children of a field, so without a list size
+    /// the list itself counts as a single value when estimating the size of a response.
+    #[allow(unused_variables)]
+    fn list_size_for_field(
+        &self,
+        field_definition: &S::FieldDefinition,
+        field: &E::Field,
+    ) -> Option<usize> {
+        None
+    }
+
     fn field_multipliers(
         &self,
         field_definition: &S::FieldDefinition,
@@ -22,6 +53,20 @@ pub trait CostComputer<'a, E: ExecutableDocument, S: SchemaDefinition, V: Variab
 
 pub trait FieldMultipliers<E: ExecutableDocument>: Default {
     fn multiplier_for_field(&self, field: &E::Field) -> usize;
+
+    /// Describes where the multiplier for the field comes from, e.g. `first: 50`,
+    /// for the breakdown output by [`ComplexityCostBreakdown`](super::ComplexityCostBreakdown)
+    #[allow(unused_variables)]
+    fn describe_multiplier_for_field(&self, field: &E::Field) -> Option<String> {
+        None
+    }
+
+    /// The multipliers for the children of `field`, given the multipliers computed for `field`
+    /// itself. Override to apply multipliers to descendants deeper than the direct children.
+    #[allow(unused_variables)]
+    fn nested_field_multipliers(&self, field: &E::Field, field_multipliers: Self) -> Self {
+        field_multipliers
+    }
 }
 
 pub struct DefaultCostComputer;
@@ -0,0 +1,722 @@
+use crate::executable::operation::{
+    analyzers::complexity_cost::{CostComputer, FieldMultipliers},
+    OperationDefinitionValueEvaluationExt, VariableValues,
+};
+use crate::utils::{directive_argument, find_directive};
+use bluejay_core::definition::{
+    ArgumentsDefinition, BaseInputTypeReference, FieldDefinition, HasDirectives,
+    InputFieldsDefinition, InputObjectTypeDefinition, InputType, InputTypeReference,
+    InputValueDefinition, OutputType, OutputTypeReference, SchemaDefinition,
+    TypeDefinitionReference,
+};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use bluejay_core::{
+    Argument, AsIter, Directive, Directives, ObjectValue, Value, ValueReference, Variable,
+};
+use std::marker::PhantomData;
+
+const COST_DIRECTIVE: &str = "cost";
+const WEIGHT_ARGUMENT: &str = "weight";
+const LIST_SIZE_DIRECTIVE: &str = "listSize";
+const ASSUMED_SIZE_ARGUMENT: &str = "assumedSize";
+const SLICING_ARGUMENTS_ARGUMENT: &str = "slicingArguments";
+const SIZED_FIELDS_ARGUMENT: &str = "sizedFields";
+const REQUIRE_ONE_SLICING_ARGUMENT_ARGUMENT: &str = "requireOneSlicingArgument";
+
+/// The cost metric computed by a [CostDirectivesCostComputer]
+pub trait CostMetric {
+    /// Whether the metric is the type cost, otherwise it is the field cost
+    const IS_TYPE_COST: bool;
+}
+
+/// The field cost of the cost directives specification, estimating the work needed to
+/// execute the operation: the weight of each field resolved, including the weights of its
+/// arguments and their input values
+pub struct FieldCost;
+
+impl CostMetric for FieldCost {
+    const IS_TYPE_COST: bool = false;
+}
+
+/// The type cost of the cost directives specification, estimating the size of the response:
+/// the weight of the type of each value returned, with lists counting each of their items
+pub struct TypeCost;
+
+impl CostMetric for TypeCost {
+    const IS_TYPE_COST: bool = true;
+}
+
+/// Implements the [cost directives specification](https://ibm.github.io/graphql-specs/cost-spec.html),
+/// reading `@cost(weight: Float!)` from field, argument, input field, object, scalar and enum
+/// definitions, and `@listSize(assumedSize: Int, slicingArguments: [String!], sizedFields: [String!])`
+/// from field definitions. Weights default to 1 for composite types and to 0 otherwise.
+///
+/// The size of a list is the largest of its slicing arguments, which can be paths into input
+/// objects like `page.first`, falling back to its assumed size. `requireOneSlicingArgument` is
+/// enforced by the [`SlicingArgumentsAreValid`](super::SlicingArgumentsAreValid) analyzer.
+/// Fractional costs are rounded up for each field.
+///
+/// Run one [`ComplexityCost`](super::ComplexityCost) analyzer per [CostMetric] to report both
+/// the type cost and the field cost of an operation.
+pub struct CostDirectivesCostComputer<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    V: VariableValues,
+    M: CostMetric = FieldCost,
+> {
+    operation_definition: &'a E::OperationDefinition,
+    schema_definition: &'a S,
+    variable_values: &'a V,
+    metric: PhantomData<M>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableValues, M: CostMetric>
+    CostComputer<'a, E, S, V> for CostDirectivesCostComputer<'a, E, S, V, M>
+{
+    type FieldMultipliers = CostDirectivesFieldMultipliers<E>;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a V,
+    ) -> Self {
+        Self {
+            operation_definition,
+            schema_definition,
+            variable_values,
+            metric: PhantomData,
+        }
+    }
+
+    fn cost_for_field_definition(&self, field_definition: &S::FieldDefinition) -> usize {
+        Self::round_cost(self.weight_for_field_definition(field_definition))
+    }
+
+    fn cost_for_field(&self, field_definition: &S::FieldDefinition, field: &E::Field) -> usize {
+        if M::IS_TYPE_COST {
+            // a list counts each of its items, unless the items are nested in sized fields
+            let item_count = self
+                .list_size_for_field(field_definition, field)
+                .unwrap_or(1);
+            self.cost_for_list_items(field_definition, field, item_count)
+        } else {
+            Self::round_cost(
+                self.weight_for_field_definition(field_definition)
+                    + self.arguments_cost(field_definition, field),
+            )
+        }
+    }
+
+    fn cost_for_list_items(
+        &self,
+        field_definition: &S::FieldDefinition,
+        field: &E::Field,
+        item_count: usize,
+    ) -> usize {
+        if M::IS_TYPE_COST {
+            Self::round_cost(self.weight_for_field_definition(field_definition) * item_count as f64)
+        } else {
+            self.cost_for_field(field_definition, field)
+        }
+    }
+
+    fn list_size_for_field(
+        &self,
+        field_definition: &S::FieldDefinition,
+        field: &E::Field,
+    ) -> Option<usize> {
+        self.list_size(field_definition, field)
+            .filter(|list_size| list_size.sized_fields.is_none())
+            .map(|list_size| list_size.size)
+    }
+
+    fn field_multipliers(
+        &self,
+        field_definition: &S::FieldDefinition,
+        field: &E::Field,
+    ) -> CostDirectivesFieldMultipliers<E> {
+        let layers = self
+            .list_size(field_definition, field)
+            .map(|list_size| ListSizeLayer {
+                size: list_size.size,
+                description: list_size.description,
+                sized_fields: list_size.sized_fields,
+            })
+            .into_iter()
+            .collect();
+
+        CostDirectivesFieldMultipliers {
+            layers,
+            executable_document: PhantomData,
+        }
+    }
+}
+
+struct ListSize {
+    size: usize,
+    description: String,
+    /// `None` when the field itself is the list
+    sized_fields: Option<Vec<String>>,
+}
+
+impl<E: ExecutableDocument, S: SchemaDefinition, V: VariableValues, M: CostMetric>
+    CostDirectivesCostComputer<'_, E, S, V, M>
+{
+    fn round_cost(cost: f64) -> usize {
+        cost.max(0.0).ceil() as usize
+    }
+
+    fn weight<D: Directives<true>>(directives: Option<&D>) -> Option<f64> {
+        find_directive(directives, COST_DIRECTIVE)
+            .and_then(|directive| directive_argument(directive, WEIGHT_ARGUMENT))
+            .and_then(|argument| match argument.value().as_ref() {
+                ValueReference::Integer(weight) => Some(weight.into()),
+                ValueReference::Float(weight) => Some(weight),
+                ValueReference::String(weight) => weight.parse().ok(),
+                _ => None,
+            })
+    }
+
+    fn weight_for_type_name(&self, name: &str) -> Option<f64> {
+        self.schema_definition.get_type_definition(name).and_then(
+            |type_definition: TypeDefinitionReference<'_, S::TypeDefinition>| {
+                Self::weight(type_definition.directives())
+            },
+        )
+    }
+
+    /// The weight of the field definition itself for the field cost, and of its return type for
+    /// the type cost
+    fn weight_for_field_definition(&self, field_definition: &S::FieldDefinition) -> f64 {
+        let field_weight = if M::IS_TYPE_COST {
+            None
+        } else {
+            Self::weight(field_definition.directives())
+        };
+
+        field_weight
+            .or_else(|| self.weight_for_type_name(field_definition.r#type().base_name()))
+            .unwrap_or_else(|| {
+                if field_definition
+                    .r#type()
+                    .base(self.schema_definition)
+                    .is_composite()
+                {
+                    1.0
+                } else {
+                    0.0
+                }
+            })
+    }
+
+    fn arguments_cost(&self, field_definition: &S::FieldDefinition, field: &E::Field) -> f64 {
+        let Some(arguments_definition) = field_definition.arguments_definition() else {
+            return 0.0;
+        };
+
+        field
+            .arguments()
+            .into_iter()
+            .flat_map(|arguments| arguments.iter())
+            .filter_map(|argument| {
+                arguments_definition
+                    .get(argument.name())
+                    .map(|argument_definition| {
+                        self.input_value_definition_cost(argument_definition, argument.value())
+                    })
+            })
+            .sum()
+    }
+
+    fn input_value_definition_cost<const CONST: bool, IV: Value<CONST>>(
+        &self,
+        input_value_definition: &S::InputValueDefinition,
+        value: &IV,
+    ) -> f64 {
+        if matches!(value.as_ref(), ValueReference::Null) {
+            return 0.0;
+        }
+
+        Self::weight(input_value_definition.directives()).unwrap_or(0.0)
+            + self.input_value_cost(input_value_definition.r#type(), value)
+    }
+
+    fn input_value_cost<const CONST: bool, IV: Value<CONST>>(
+        &self,
+        input_type: &S::InputType,
+        value: &IV,
+    ) -> f64 {
+        match value.as_ref() {
+            ValueReference::Null => 0.0,
+            ValueReference::Variable(variable) => self
+                .variable_values
+                .get(variable.name())
+                .map_or(0.0, |value| self.input_value_cost(input_type, value)),
+            core_value => match input_type.as_ref(self.schema_definition) {
+                InputTypeReference::List(inner, _) => match core_value {
+                    ValueReference::List(values) => values
+                        .iter()
+                        .map(|value| self.input_value_cost(inner, value))
+                        .sum(),
+                    _ => self.input_value_cost(inner, value),
+                },
+                InputTypeReference::Base(base, _) => {
+                    let weight = Self::weight(base.directives()).unwrap_or(0.0);
+                    match (base, core_value) {
+                        (
+                            BaseInputTypeReference::InputObject(iotd),
+                            ValueReference::Object(object),
+                        ) => {
+                            weight
+                                + object
+                                    .iter()
+                                    .filter_map(|(key, value)| {
+                                        iotd.input_field_definitions()
+                                            .get(key.as_ref())
+                                            .map(|ivd| self.input_value_definition_cost(ivd, value))
+                                    })
+                                    .sum::<f64>()
+                        }
+                        _ => weight,
+                    }
+                }
+            },
+        }
+    }
+
+    fn list_size(
+        &self,
+        field_definition: &S::FieldDefinition,
+        field: &E::Field,
+    ) -> Option<ListSize> {
+        let directive = find_directive(field_definition.directives(), LIST_SIZE_DIRECTIVE)?;
+
+        let sized_fields = Self::string_list_argument(directive, SIZED_FIELDS_ARGUMENT)
+            .filter(|sized_fields| !sized_fields.is_empty())
+            .or_else(|| {
+                // only lists can be sized without pointing at nested fields
+                matches!(
+                    field_definition.r#type().as_ref(self.schema_definition),
+                    OutputTypeReference::List(_, _)
+                )
+                .then_some(Vec::new())
+            })?;
+        let sized_fields = (!sized_fields.is_empty()).then_some(sized_fields);
+
+        let slicing_argument = Self::string_list_argument(directive, SLICING_ARGUMENTS_ARGUMENT)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|path| {
+                self.slicing_argument_value(field_definition, field, &path)
+                    .map(|size| (path, size))
+            })
+            .max_by_key(|(_, size)| *size);
+
+        let (size, description) =
+            match slicing_argument {
+                Some((path, size)) => (size, format!("{path}: {size}")),
+                None => {
+                    let assumed_size = directive_argument(directive, ASSUMED_SIZE_ARGUMENT)
+                        .and_then(|argument| match argument.value().as_ref() {
+                            ValueReference::Integer(size) => Some(size.max(0) as usize),
+                            _ => None,
+                        })?;
+                    (
+                        assumed_size,
+                        format!("{ASSUMED_SIZE_ARGUMENT}: {assumed_size}"),
+                    )
+                }
+            };
+
+        Some(ListSize {
+            size,
+            description,
+            sized_fields,
+        })
+    }
+
+    /// The slicing arguments given a value by `field`, when the `@listSize` directive of its
+    /// definition lists slicing arguments and requires exactly one of them to be given, as it
+    /// does unless `requireOneSlicingArgument` is `false`. When the field gives none of them,
+    /// the slicing arguments with a default value count as given instead.
+    pub(crate) fn required_slicing_arguments(
+        &self,
+        field_definition: &S::FieldDefinition,
+        field: &E::Field,
+    ) -> Option<Vec<String>> {
+        let directive = find_directive(field_definition.directives(), LIST_SIZE_DIRECTIVE)?;
+        let require_one_slicing_argument =
+            directive_argument(directive, REQUIRE_ONE_SLICING_ARGUMENT_ARGUMENT).is_none_or(
+                |argument| !matches!(argument.value().as_ref(), ValueReference::Boolean(false)),
+            );
+        if !require_one_slicing_argument {
+            return None;
+        }
+        let slicing_arguments = Self::string_list_argument(directive, SLICING_ARGUMENTS_ARGUMENT)
+            .filter(|slicing_arguments| !slicing_arguments.is_empty())?;
+
+        let (given, not_given): (Vec<String>, Vec<String>) = slicing_arguments
+            .into_iter()
+            .partition(|path| self.given_slicing_argument_value(field, path).is_some());
+        if !given.is_empty() {
+            return Some(given);
+        }
+        Some(
+            not_given
+                .into_iter()
+                .filter(|path| {
+                    self.default_slicing_argument_value(field_definition, path)
+                        .is_some()
+                })
+                .collect(),
+        )
+    }
+
+    fn string_list_argument<D: Directive<true>>(
+        directive: &D,
+        argument_name: &str,
+    ) -> Option<Vec<String>> {
+        directive_argument(directive, argument_name).and_then(|argument| {
+            match argument.value().as_ref() {
+                ValueReference::List(values) => Some(
+                    values
+                        .iter()
+                        .filter_map(|value| match value.as_ref() {
+                            ValueReference::String(s) => Some(s.to_owned()),
+                            _ => None,
+                        })
+                        .collect(),
+                ),
+                _ => None,
+            }
+        })
+    }
+
+    /// The value of a slicing argument like `first` or `page.first`, falling back to the
+    /// default value of the argument
+    fn slicing_argument_value(
+        &self,
+        field_definition: &S::FieldDefinition,
+        field: &E::Field,
+        path: &str,
+    ) -> Option<usize> {
+        self.given_slicing_argument_value(field, path)
+            .or_else(|| self.default_slicing_argument_value(field_definition, path))
+    }
+
+    /// The value `field` gives to a slicing argument like `first` or `page.first`
+    fn given_slicing_argument_value(&self, field: &E::Field, path: &str) -> Option<usize> {
+        let (argument_name, nested_path) = Self::split_slicing_argument_path(path);
+
+        field
+            .arguments()
+            .and_then(|arguments| {
+                arguments
+                    .iter()
+                    .find(|argument| argument.name() == argument_name)
+            })
+            .and_then(|argument| self.int_at_path(argument.value(), &nested_path))
+            .map(|size| size.max(0) as usize)
+    }
+
+    /// The default value of a slicing argument like `first` or `page.first`
+    fn default_slicing_argument_value(
+        &self,
+        field_definition: &S::FieldDefinition,
+        path: &str,
+    ) -> Option<usize> {
+        let (argument_name, nested_path) = Self::split_slicing_argument_path(path);
+
+        field_definition
+            .arguments_definition()
+            .and_then(|arguments_definition| arguments_definition.get(argument_name))
+            .and_then(InputValueDefinition::default_value)
+            .and_then(|default_value| self.int_at_path(default_value, &nested_path))
+            .map(|size| size.max(0) as usize)
+    }
+
+    /// Splits a slicing argument path like `page.first` into the name of the argument and the
+    /// path within its value
+    fn split_slicing_argument_path(path: &str) -> (&str, Vec<&str>) {
+        let mut segments = path.split('.');
+        let argument_name = segments.next().unwrap_or_default();
+        (argument_name, segments.collect())
+    }
+
+    fn int_at_path<const CONST: bool, IV: Value<CONST>>(
+        &self,
+        value: &IV,
+        path: &[&str],
+    ) -> Option<i32> {
+        match (value.as_ref(), path.split_first()) {
+            (ValueReference::Integer(int), None) => Some(int),
+            (ValueReference::Variable(variable), None) => self
+                .operation_definition
+                .evaluate_int(variable, self.variable_values),
+            (ValueReference::Variable(variable), Some(_)) => self
+                .variable_values
+                .get(variable.name())
+                .and_then(|value| self.int_at_path(value, path)),
+            (ValueReference::Object(object), Some((key, rest))) => object
+                .iter()
+                .find(|(k, _)| k.as_ref() == *key)
+                .and_then(|(_, value)| self.int_at_path(value, rest)),
+            _ => None,
+        }
+    }
+}
+
+struct ListSizeLayer {
+    size: usize,
+    description: String,
+    /// The dot-separated paths of the descendants sized by the layer, relative to the scope
+    /// holding it, or `None` when it sizes every child
+    sized_fields: Option<Vec<String>>,
+}
+
+impl ListSizeLayer {
+    fn applies_to(&self, field_name: &str) -> bool {
+        self.sized_fields.as_ref().is_none_or(|sized_fields| {
+            sized_fields
+                .iter()
+                .any(|sized_field| sized_field == field_name)
+        })
+    }
+}
+
+pub struct CostDirectivesFieldMultipliers<E: ExecutableDocument> {
+    layers: Vec<ListSizeLayer>,
+    executable_document: PhantomData<E>,
+}
+
+impl<E: ExecutableDocument> Default for CostDirectivesFieldMultipliers<E> {
+    fn default() -> Self {
+        Self {
+            layers: Vec::new(),
+            executable_document: PhantomData,
+        }
+    }
+}
+
+impl<E: ExecutableDocument> FieldMultipliers<E> for CostDirectivesFieldMultipliers<E> {
+    fn multiplier_for_field(&self, field: &E::Field) -> usize {
+        self.layers
+            .iter()
+            .filter(|layer| layer.applies_to(field.name()))
+            .map(|layer| layer.size)
+            .product()
+    }
+
+    fn describe_multiplier_for_field(&self, field: &E::Field) -> Option<String> {
+        let descriptions: Vec<&str> = self
+            .layers
+            .iter()
+            .filter(|layer| layer.applies_to(field.name()))
+            .map(|layer| layer.description.as_str())
+            .collect();
+        (!descriptions.is_empty()).then(|| descriptions.join(" * "))
+    }
+
+    fn nested_field_multipliers(&self, field: &E::Field, mut field_multipliers: Self) -> Self {
+        let prefix = format!("{}.", field.name());
+        field_multipliers
+            .layers
+            .extend(self.layers.iter().filter_map(|layer| {
+                let sized_fields: Vec<String> = layer
+                    .sized_fields
+                    .as_ref()?
+                    .iter()
+                    .filter_map(|sized_field| sized_field.strip_prefix(&prefix))
+                    .map(ToOwned::to_owned)
+                    .collect();
+                (!sized_fields.is_empty()).then(|| ListSizeLayer {
+                    size: layer.size,
+                    description: layer.description.clone(),
+                    sized_fields: Some(sized_fields),
+                })
+            }));
+        field_multipliers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CostDirectivesCostComputer, FieldCost, TypeCost};
+    use crate::executable::{
+        operation::{
+            analyzers::{ComplexityCost, ComplexityCostBreakdown},
+            Orchestrator,
+        },
+        Cache,
+    };
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        directive @cost(weight: Float!) on ARGUMENT_DEFINITION | ENUM | FIELD_DEFINITION | INPUT_FIELD_DEFINITION | OBJECT | SCALAR
+        directive @listSize(assumedSize: Int, slicingArguments: [String!], sizedFields: [String!], requireOneSlicingArgument: Boolean = true) on FIELD_DEFINITION
+
+        scalar Cursor @cost(weight: 2)
+
+        type Query {
+            users(first: Int, last: Int): [User!]! @listSize(slicingArguments: ["first", "last"], requireOneSlicingArgument: false)
+            search(filter: SearchFilter!, page: PageArguments = { size: 20 }): SearchResult! @cost(weight: 5) @listSize(slicingArguments: ["page.size"], sizedFields: ["page.edges"])
+            tags: [String!]! @listSize(assumedSize: 50)
+        }
+
+        input SearchFilter {
+            term: String! @cost(weight: 3)
+            after: Cursor
+        }
+
+        input PageArguments {
+            size: Int
+        }
+
+        type SearchResult {
+            page: Page!
+        }
+
+        type Page {
+            edges: [Edge!]!
+        }
+
+        type Edge {
+            cursor: Cursor!
+            node: User!
+        }
+
+        type User @cost(weight: 2) {
+            id: ID!
+            name: String!
+            friends: [User!]! @listSize(assumedSize: 10)
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    type Computer<'a, M> = CostDirectivesCostComputer<
+        'a,
+        ExecutableDocument<'a>,
+        SchemaDefinition<'static>,
+        JsonMap<String, JsonValue>,
+        M,
+    >;
+
+    /// Returns the field cost and the type cost of the operation
+    fn costs(source: &str, variables: JsonValue) -> (usize, usize) {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        Orchestrator::<
+            _,
+            _,
+            JsonMap<String, JsonValue>,
+            (
+                ComplexityCost<_, _, _, Computer<FieldCost>>,
+                ComplexityCost<_, _, _, Computer<TypeCost>>,
+            ),
+        >::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variables.as_object().expect("Variables must be an object"),
+            &cache,
+            ((), ()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_lists_sized_by_slicing_arguments_and_assumed_size() {
+        // field cost: users (2) + 5 * friends (2)
+        // type cost: 5 users (2) + 5 * 10 friends (2)
+        assert_eq!(
+            (12, 110),
+            costs(
+                "{ users(first: 5, last: 3) { name friends { id } } }",
+                json!({}),
+            ),
+        );
+        assert_eq!(
+            (0, 0),
+            costs("{ tags }", json!({})),
+            "leaf lists without weights are free",
+        );
+    }
+
+    #[test]
+    fn test_nested_sized_fields_and_argument_costs() {
+        // field cost: search (5) + filter.term (3) + filter.after (2) + page (1)
+        //   + 3 * (edges (1) + cursor (2) + node (2))
+        // type cost: search (1) + page (1) + 3 * (edges (1) + cursor (2) + node (2))
+        assert_eq!(
+            (26, 17),
+            costs(
+                r#"query($size: Int) {
+                    search(filter: { term: "a", after: "x" }, page: { size: $size }) {
+                        page { edges { cursor node { name } } }
+                    }
+                }"#,
+                json!({ "size": 3 }),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_slicing_argument_default_value_and_input_variables() {
+        // field cost: search (5) + filter.term (3) + page (1) + 20 * (edges (1) + cursor (2))
+        // type cost: search (1) + page (1) + 20 * (edges (1) + cursor (2))
+        assert_eq!(
+            (69, 62),
+            costs(
+                r#"query($filter: SearchFilter!) {
+                    search(filter: $filter) { page { edges { cursor } } }
+                }"#,
+                json!({ "filter": { "term": "a" } }),
+            ),
+        );
+    }
+
+    #[test]
+    fn test_breakdown_describes_list_sizes() {
+        let executable_document = ExecutableDocument::parse(
+            r#"{ search(filter: { term: "a" }, page: { size: 4 }) { page { edges { cursor } } } }"#,
+        )
+        .result
+        .unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variables = JsonMap::new();
+        let cost_tree = Orchestrator::<
+            _,
+            _,
+            JsonMap<String, JsonValue>,
+            ComplexityCostBreakdown<_, _, _, Computer<FieldCost>>,
+        >::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            &variables,
+            &cache,
+            (),
+        )
+        .unwrap();
+
+        let edges = cost_tree.get(&["search", "page", "edges"]).unwrap();
+        assert_eq!(4, edges.multiplier);
+        assert_eq!(
+            Some("page.size: 4"),
+            edges.multiplier_description.as_deref()
+        );
+        assert_eq!(5 + 3 + 1 + 4 * 3, cost_tree.total);
+    }
+}
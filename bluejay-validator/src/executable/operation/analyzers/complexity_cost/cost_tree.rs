@@ -0,0 +1,128 @@
+use std::fmt::Write;
+
+/// A breakdown of the cost computed by [`ComplexityCost`](super::ComplexityCost), keyed by
+/// response path. Leaf fields without cost are omitted, and selections on abstract types
+/// show the fields of the most expensive possible type.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct CostTree<'a> {
+    pub total: usize,
+    pub fields: Vec<CostNode<'a>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CostNode<'a> {
+    pub response_key: &'a str,
+    /// The cost of the field itself
+    pub cost: usize,
+    /// The multiplier applied to the field and its children
+    pub multiplier: usize,
+    /// Where the multiplier comes from, e.g. `first: 50`
+    pub multiplier_description: Option<String>,
+    /// `(cost + sum of the children subtotals) * multiplier`
+    pub subtotal: usize,
+    pub children: Vec<CostNode<'a>>,
+}
+
+impl<'a> CostTree<'a> {
+    /// Returns the node at the response path, e.g. `["products", "edges"]`
+    pub fn get(&self, path: &[&str]) -> Option<&CostNode<'a>> {
+        let (first, rest) = path.split_first()?;
+        rest.iter().try_fold(
+            self.fields
+                .iter()
+                .find(|node| node.response_key == *first)?,
+            |node, response_key| {
+                node.children
+                    .iter()
+                    .find(|child| child.response_key == *response_key)
+            },
+        )
+    }
+
+    /// Iterates over every node depth-first, alongside its dot-separated response path
+    pub fn iter(&self) -> impl Iterator<Item = (String, &CostNode<'a>)> {
+        let mut stack: Vec<(String, &CostNode<'a>)> = self
+            .fields
+            .iter()
+            .rev()
+            .map(|node| (node.response_key.to_owned(), node))
+            .collect();
+        std::iter::from_fn(move || {
+            let (path, node) = stack.pop()?;
+            stack.extend(
+                node.children
+                    .iter()
+                    .rev()
+                    .map(|child| (format!("{path}.{}", child.response_key), child)),
+            );
+            Some((path, node))
+        })
+    }
+
+    /// Renders the total cost followed by the `n` most expensive response paths, one per line
+    pub fn render_top(&self, n: usize) -> String {
+        let mut nodes: Vec<_> = self.iter().collect();
+        nodes.sort_by(|(a_path, a), (b_path, b)| {
+            b.subtotal.cmp(&a.subtotal).then_with(|| a_path.cmp(b_path))
+        });
+
+        let mut output = format!("Total cost: {}\n", self.total);
+        nodes.into_iter().take(n).for_each(|(path, node)| {
+            let _ = write!(
+                output,
+                "{} {path} (cost: {}, multiplier: {}",
+                node.subtotal, node.cost, node.multiplier,
+            );
+            if let Some(multiplier_description) = &node.multiplier_description {
+                let _ = write!(output, " from {multiplier_description}");
+            }
+            output.push_str(")\n");
+        });
+        output
+    }
+}
+
+/// Collects the breakdown of the cost while it is computed, so that computing the total
+/// alone does not pay for building the tree
+pub(super) trait CostBreakdown<'a>: Default {
+    fn add(
+        &mut self,
+        response_key: &'a str,
+        cost: usize,
+        multiplier: usize,
+        multiplier_description: Option<&str>,
+        subtotal: usize,
+        children: Self,
+    );
+}
+
+impl CostBreakdown<'_> for () {
+    fn add(&mut self, _: &str, _: usize, _: usize, _: Option<&str>, _: usize, _: Self) {}
+}
+
+impl<'a> CostBreakdown<'a> for Vec<CostNode<'a>> {
+    fn add(
+        &mut self,
+        response_key: &'a str,
+        cost: usize,
+        multiplier: usize,
+        multiplier_description: Option<&str>,
+        subtotal: usize,
+        children: Self,
+    ) {
+        let node = CostNode {
+            response_key,
+            cost,
+            multiplier,
+            multiplier_description: multiplier_description.map(ToOwned::to_owned),
+            subtotal,
+            children,
+        };
+        // keep the most expensive fields first, independently of selection order
+        let index = self.partition_point(|other| {
+            (other.subtotal, std::cmp::Reverse(other.response_key))
+                >= (subtotal, std::cmp::Reverse(response_key))
+        });
+        self.insert(index, node);
+    }
+}
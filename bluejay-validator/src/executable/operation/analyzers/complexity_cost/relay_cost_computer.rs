@@ -96,24 +96,25 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableValues> CostComp
 
         match kind {
             Some(CONNECTION_COST_KIND) => {
-                let (first_size, last_size) = (
-                    self.extract_field_sizing_argument(field, CONNECTION_FIRST_ARGUMENT),
-                    self.extract_field_sizing_argument(field, CONNECTION_LAST_ARGUMENT),
-                );
+                let sizing_argument = [CONNECTION_FIRST_ARGUMENT, CONNECTION_LAST_ARGUMENT]
+                    .into_iter()
+                    .filter_map(|argument_name| {
+                        self.extract_field_sizing_argument(field, argument_name)
+                            .map(|size| (argument_name, size))
+                    })
+                    .max_by_key(|(_, size)| *size);
 
-                let static_size = first_size.into_iter().chain(last_size).max().unwrap_or(0);
+                let static_size = sizing_argument.map_or(0, |(_, size)| size);
 
                 let multiplier = Self::multiplier_for_static_size(static_size);
 
                 RelayFieldMultipliers {
                     connection_multiplier: Some(multiplier),
+                    sizing_argument,
                     executable_document: PhantomData,
                 }
             }
-            _ => RelayFieldMultipliers {
-                connection_multiplier: None,
-                executable_document: PhantomData,
-            },
+            _ => RelayFieldMultipliers::default(),
         }
     }
 }
@@ -149,6 +150,8 @@ impl<E: ExecutableDocument, S: SchemaDefinition, V: VariableValues> RelayCostCom
 
 pub struct RelayFieldMultipliers<E: ExecutableDocument> {
     connection_multiplier: Option<usize>,
+    /// The name and value of the slicing argument the connection multiplier is derived from
+    sizing_argument: Option<(&'static str, usize)>,
     executable_document: PhantomData<E>,
 }
 
@@ -156,6 +159,7 @@ impl<E: ExecutableDocument> Default for RelayFieldMultipliers<E> {
     fn default() -> Self {
         Self {
             connection_multiplier: None,
+            sizing_argument: None,
             executable_document: PhantomData,
         }
     }
@@ -171,4 +175,15 @@ impl<E: ExecutableDocument> FieldMultipliers<E> for RelayFieldMultipliers<E> {
             _ => 1,
         }
     }
+
+    fn describe_multiplier_for_field(&self, field: &E::Field) -> Option<String> {
+        self.connection_multiplier?;
+        match field.name() {
+            CONNECTION_EDGES_FIELD | CONNECTION_NODES_FIELD => Some(match self.sizing_argument {
+                Some((argument_name, size)) => format!("{argument_name}: {size}"),
+                None => format!("no {CONNECTION_FIRST_ARGUMENT} or {CONNECTION_LAST_ARGUMENT}"),
+            }),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,202 @@
+use crate::executable::{
+    operation::{
+        analyzers::complexity_cost::{CostComputer, CostDirectivesCostComputer},
+        Analyzer, VariableValues, Visitor,
+    },
+    Cache,
+};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field};
+
+/// Reports the included selections of list fields whose `@listSize` directive requires exactly
+/// one slicing argument, as `requireOneSlicingArgument` does by default in the
+/// [cost directives specification](https://ibm.github.io/graphql-specs/cost-spec.html), but
+/// that give none or more than one. Without a slicing argument, the cost computed by
+/// [`CostDirectivesCostComputer`] falls back to the assumed size of the list.
+pub struct SlicingArgumentsAreValid<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    V: VariableValues,
+> {
+    cost_computer: CostDirectivesCostComputer<'a, E, S, V>,
+    errors: Vec<SlicingArgumentError<'a, E, S>>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableValues> Visitor<'a, E, S, V>
+    for SlicingArgumentsAreValid<'a, E, S, V>
+{
+    type ExtraInfo = ();
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a V,
+        _: &'a Cache<'a, E, S>,
+        _: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            cost_computer: CostDirectivesCostComputer::new(
+                operation_definition,
+                schema_definition,
+                variable_values,
+            ),
+            errors: Vec::new(),
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a E::Field,
+        field_definition: &'a S::FieldDefinition,
+        _: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+        if let Some(slicing_arguments) = self
+            .cost_computer
+            .required_slicing_arguments(field_definition, field)
+            .filter(|slicing_arguments| slicing_arguments.len() != 1)
+        {
+            self.errors.push(SlicingArgumentError {
+                field,
+                field_definition,
+                slicing_arguments,
+            });
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableValues> Analyzer<'a, E, S, V>
+    for SlicingArgumentsAreValid<'a, E, S, V>
+{
+    type Output = Vec<SlicingArgumentError<'a, E, S>>;
+
+    fn into_output(self) -> Self::Output {
+        self.errors
+    }
+}
+
+/// A selection of a list field that must give exactly one slicing argument
+#[derive(Debug)]
+pub struct SlicingArgumentError<'a, E: ExecutableDocument, S: SchemaDefinition> {
+    pub field: &'a E::Field,
+    pub field_definition: &'a S::FieldDefinition,
+    /// The slicing arguments given by the selection, either none or more than one
+    pub slicing_arguments: Vec<String>,
+}
+
+impl<E: ExecutableDocument, S: SchemaDefinition> SlicingArgumentError<'_, E, S> {
+    pub fn message(&self) -> String {
+        let given = if self.slicing_arguments.is_empty() {
+            "none".to_owned()
+        } else {
+            self.slicing_arguments
+                .iter()
+                .map(|slicing_argument| format!("`{slicing_argument}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        format!(
+            "Field `{}` must be given exactly one slicing argument, but was given {given}",
+            self.field.name(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SlicingArgumentsAreValid;
+    use crate::executable::{operation::Orchestrator, Cache};
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::{json, Value as JsonValue};
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        directive @listSize(assumedSize: Int, slicingArguments: [String!], sizedFields: [String!], requireOneSlicingArgument: Boolean = true) on FIELD_DEFINITION
+
+        type Query {
+            members(first: Int, last: Int): [User!]! @listSize(slicingArguments: ["first", "last"], assumedSize: 100)
+            users(first: Int, last: Int): [User!]! @listSize(slicingArguments: ["first", "last"], requireOneSlicingArgument: false)
+            search(page: PageArguments = { size: 20 }): [User!]! @listSize(slicingArguments: ["page.size"])
+            tags: [String!]! @listSize(assumedSize: 50)
+        }
+
+        input PageArguments {
+            size: Int
+        }
+
+        type User {
+            id: ID!
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    fn validate_slicing_arguments(source: &str, variables: JsonValue) -> Vec<String> {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        Orchestrator::<_, _, _, SlicingArgumentsAreValid<_, _, _>>::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variables.as_object().expect("Variables must be an object"),
+            &cache,
+            (),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|error| error.message())
+        .collect()
+    }
+
+    #[test]
+    fn test_one_slicing_argument_required() {
+        assert_eq!(
+            vec!["Field `members` must be given exactly one slicing argument, but was given none"],
+            validate_slicing_arguments("{ members { id } }", json!({})),
+        );
+        assert_eq!(
+            vec![
+                "Field `members` must be given exactly one slicing argument, but was given `first`, `last`"
+            ],
+            validate_slicing_arguments("{ members(first: 1, last: 2) { id } }", json!({})),
+        );
+        assert!(validate_slicing_arguments("{ members(last: 2) { id } }", json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_slicing_argument_variables() {
+        let source = "query($first: Int) { members(first: $first) { id } }";
+        assert!(validate_slicing_arguments(source, json!({ "first": 10 })).is_empty());
+        assert_eq!(
+            1,
+            validate_slicing_arguments(source, json!({ "first": null })).len(),
+        );
+        assert_eq!(1, validate_slicing_arguments(source, json!({})).len());
+    }
+
+    #[test]
+    fn test_slicing_arguments_not_required() {
+        assert!(validate_slicing_arguments(
+            "{ users { id } tags search { id } members @skip(if: true) { id } }",
+            json!({})
+        )
+        .is_empty());
+        assert!(validate_slicing_arguments(
+            "{ search(page: { size: 5 }) { id } users(first: 1, last: 2) { id } }",
+            json!({})
+        )
+        .is_empty());
+    }
+}
@@ -1,30 +1,154 @@
 use crate::executable::{
-    operation::{Analyzer, VariableValues, Visitor},
+    operation::{analyzers::SchemaCoordinate, Analyzer, VariableValues, Visitor},
     Cache,
 };
+use crate::Path;
 use bluejay_core::definition::{
-    BaseInputTypeReference, EnumTypeDefinition, EnumValueDefinition, HasDirectives,
+    ArgumentsDefinition, BaseInputTypeReference, DirectiveDefinition, DirectiveLocation,
+    EnumTypeDefinition, EnumValueDefinition, FieldDefinition, HasDirectives,
     InputObjectTypeDefinition, InputType, InputTypeReference, InputValueDefinition,
-    SchemaDefinition,
+    SchemaDefinition, TypeDefinitionReference,
 };
 use bluejay_core::executable::{ExecutableDocument, Field, VariableDefinition};
 use bluejay_core::{Argument, AsIter, Directive, ObjectValue, Value, ValueReference};
+#[cfg(feature = "parser-integration")]
+use bluejay_parser::{
+    ast::executable::ExecutableDocument as ParserExecutableDocument,
+    error::{Annotation, Error as ParserError, ExtensionValue, Severity as ParserSeverity},
+    HasSpan,
+};
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 /// The deprecated usage we encountered.
 pub enum UsageType {
     Argument,
+    DirectiveArgument,
     EnumValue,
     InputField,
     Field,
     Variable,
 }
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct Offender<'a> {
+/// The element of the executable document in which a deprecated element of the schema is used
+pub enum OffenderNode<'a, E: ExecutableDocument> {
+    /// A selected field that is deprecated, or that omits an argument whose default value
+    /// contains a deprecated enum value or input field
+    Field(&'a E::Field),
+    /// An argument of a field or a directive that is deprecated, or whose value contains a
+    /// deprecated enum value or input field
+    Argument(&'a E::Argument<false>),
+    /// A directive that omits an argument whose default value contains a deprecated enum value
+    /// or input field
+    Directive(&'a E::Directive<false>),
+    /// A variable definition whose value or default value contains a deprecated enum value or
+    /// input field
+    VariableDefinition(&'a E::VariableDefinition),
+}
+
+impl<E: ExecutableDocument> Clone for OffenderNode<'_, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: ExecutableDocument> Copy for OffenderNode<'_, E> {}
+
+impl<E: ExecutableDocument> std::fmt::Debug for OffenderNode<'_, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (variant, node): (&str, *const ()) = match self {
+            Self::Field(field) => ("Field", (*field as *const E::Field).cast()),
+            Self::Argument(argument) => {
+                ("Argument", (*argument as *const E::Argument<false>).cast())
+            }
+            Self::Directive(directive) => (
+                "Directive",
+                (*directive as *const E::Directive<false>).cast(),
+            ),
+            Self::VariableDefinition(variable_definition) => (
+                "VariableDefinition",
+                (*variable_definition as *const E::VariableDefinition).cast(),
+            ),
+        };
+        f.debug_tuple(variant).field(&node).finish()
+    }
+}
+
+/// Nodes are equal when they are the same node of the same executable document
+impl<E: ExecutableDocument> PartialEq for OffenderNode<'_, E> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Field(a), Self::Field(b)) => std::ptr::eq(*a, *b),
+            (Self::Argument(a), Self::Argument(b)) => std::ptr::eq(*a, *b),
+            (Self::Directive(a), Self::Directive(b)) => std::ptr::eq(*a, *b),
+            (Self::VariableDefinition(a), Self::VariableDefinition(b)) => std::ptr::eq(*a, *b),
+            _ => false,
+        }
+    }
+}
+
+pub struct Offender<'a, E: ExecutableDocument> {
     pub reason: &'a str,
     pub offense_type: UsageType,
     pub name: &'a str,
+    /// The schema coordinate of the deprecated element, e.g. `Query.user(id:)`
+    pub coordinate: SchemaCoordinate<'a>,
+    /// The schema coordinate of the element the deprecated element was used through: the type
+    /// of a field, the field or directive of an argument, or the argument whose value contains
+    /// an enum value or input field. `None` for values of variables.
+    pub parent: Option<SchemaCoordinate<'a>>,
+    /// The response path of the field that is deprecated, that is given the argument or that the
+    /// directive is applied to, and of the enclosing field for directives applied to fragments.
+    /// Empty for values of variables.
+    pub path: Path<'a>,
+    pub node: OffenderNode<'a, E>,
+}
+
+impl<E: ExecutableDocument> Clone for Offender<'_, E> {
+    fn clone(&self) -> Self {
+        Self {
+            reason: self.reason,
+            offense_type: self.offense_type,
+            name: self.name,
+            coordinate: self.coordinate,
+            parent: self.parent,
+            path: self.path.clone(),
+            node: self.node,
+        }
+    }
+}
+
+impl<E: ExecutableDocument> std::fmt::Debug for Offender<'_, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Offender")
+            .field("reason", &self.reason)
+            .field("offense_type", &self.offense_type)
+            .field("name", &self.name)
+            .field("coordinate", &self.coordinate)
+            .field("parent", &self.parent)
+            .field("path", &self.path)
+            .field("node", &self.node)
+            .finish()
+    }
+}
+
+impl<E: ExecutableDocument> PartialEq for Offender<'_, E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.reason == other.reason
+            && self.offense_type == other.offense_type
+            && self.name == other.name
+            && self.coordinate == other.coordinate
+            && self.parent == other.parent
+            && self.path == other.path
+            && self.node == other.node
+    }
+}
+
+/// Where the value being searched for deprecations was found
+struct ValueUsage<'a, E: ExecutableDocument> {
+    name: &'a str,
+    node: OffenderNode<'a, E>,
+    parent: Option<SchemaCoordinate<'a>>,
+    path: Path<'a>,
 }
 
 /// The [Deprecation] analyzer will go over all ast-nodes of type Field, EnumValue, Argument and InputField
@@ -32,10 +156,13 @@ pub struct Offender<'a> {
 /// it will be added ot the list of [Offender].
 /// This method will output the list of [Offender].
 pub struct Deprecation<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
-    offenders: Vec<Offender<'a>>,
+    offenders: Vec<Offender<'a, E>>,
     schema_definition: &'a S,
     cache: &'a Cache<'a, E, S>,
     variable_values: &'a VV,
+    paths: Vec<Path<'a>>,
+    /// The input fields whose default values are being searched, to stop at cyclic defaults
+    input_field_defaults: Vec<SchemaCoordinate<'a>>,
 }
 
 const DEPRECATED_DIRECTIVE: &str = "deprecated";
@@ -57,6 +184,8 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
             schema_definition,
             cache,
             variable_values,
+            paths: vec![],
+            input_field_defaults: Vec::new(),
         }
     }
 
@@ -68,67 +197,126 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor
             .cache
             .variable_definition_input_type(variable_definition.r#type())
         {
+            let usage = ValueUsage {
+                name: variable_definition.variable(),
+                node: OffenderNode::VariableDefinition(variable_definition),
+                parent: None,
+                path: Path::default(),
+            };
             if let Some(value) = self
                 .variable_values
                 .get(variable_definition.variable().as_ref())
             {
-                self.find_deprecations_for_value(input_type, value, variable_definition.variable());
+                self.find_deprecations_for_value(input_type, value, &usage);
             }
             if let Some(default_value) = variable_definition.default_value() {
-                self.find_deprecations_for_value(
-                    input_type,
-                    default_value,
-                    variable_definition.variable(),
-                );
+                self.find_deprecations_for_value(input_type, default_value, &usage);
             }
         }
     }
 
+    fn visit_variable_directive(
+        &mut self,
+        directive: &'a <E as ExecutableDocument>::Directive<false>,
+        location: DirectiveLocation,
+        _included: bool,
+    ) {
+        // directives of fields are visited before the field, so they are searched in `visit_field`
+        // where the path of the field is known
+        if location != DirectiveLocation::Field {
+            self.find_deprecations_for_directive(directive, self.current_path());
+        }
+    }
+
     fn visit_field(
         &mut self,
         field: &'a <E as ExecutableDocument>::Field,
         field_definition: &'a <S as SchemaDefinition>::FieldDefinition,
-        _scoped_type: bluejay_core::definition::TypeDefinitionReference<
-            'a,
-            <S as SchemaDefinition>::TypeDefinition,
-        >,
+        scoped_type: TypeDefinitionReference<'a, <S as SchemaDefinition>::TypeDefinition>,
         included: bool,
     ) {
-        if !included {
-            return;
+        let path = self.current_path().push(field.response_name());
+        self.paths.push(path.clone());
+        if let Some(directives) = field.directives() {
+            directives.iter().for_each(|directive| {
+                self.find_deprecations_for_directive(directive, path.clone());
+            });
         }
+        let coordinate = SchemaCoordinate::Field {
+            type_name: scoped_type.name(),
+            field_name: field_definition.name(),
+        };
+        self.find_deprecations_for_omitted_arguments(
+            field_definition.arguments_definition(),
+            field.arguments(),
+            coordinate,
+            OffenderNode::Field(field),
+            path.clone(),
+        );
 
-        if let Some(reason) =
-            get_deprecation_reason::<<S as SchemaDefinition>::FieldDefinition>(field_definition)
-        {
-            self.offenders.push(Offender {
-                name: field.name(),
-                offense_type: UsageType::Field,
-                reason,
-            });
+        if included {
+            if let Some(reason) =
+                get_deprecation_reason::<<S as SchemaDefinition>::FieldDefinition>(field_definition)
+            {
+                self.offenders.push(Offender {
+                    name: field.name(),
+                    offense_type: UsageType::Field,
+                    reason,
+                    coordinate,
+                    parent: Some(SchemaCoordinate::Type {
+                        type_name: scoped_type.name(),
+                    }),
+                    path: path.clone(),
+                    node: OffenderNode::Field(field),
+                });
+            }
         }
+
+        self.find_deprecations_for_given_arguments(
+            field_definition.arguments_definition(),
+            field.arguments(),
+            coordinate,
+            path,
+        );
     }
 
-    fn visit_variable_argument(
+    fn leave_field(
         &mut self,
-        argument: &'a <E as ExecutableDocument>::Argument<false>,
-        input_value_definition: &'a <S as SchemaDefinition>::InputValueDefinition,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a <S as SchemaDefinition>::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, <S as SchemaDefinition>::TypeDefinition>,
+        _included: bool,
     ) {
-        if let Some(reason) = get_deprecation_reason::<<S as SchemaDefinition>::InputValueDefinition>(
-            input_value_definition,
-        ) {
-            self.offenders.push(Offender {
-                name: argument.name(),
-                offense_type: UsageType::Argument,
-                reason,
-            });
-        }
+        self.paths.pop();
+    }
+}
 
-        self.find_deprecations_for_value(
-            input_value_definition.r#type(),
-            argument.value(),
-            argument.name(),
-        );
+/// The usage type and schema coordinate of the argument named `argument_name` of `owner`, a field
+/// or a directive
+fn argument_coordinate<'a>(
+    owner: SchemaCoordinate<'a>,
+    argument_name: &'a str,
+) -> Option<(UsageType, SchemaCoordinate<'a>)> {
+    match owner {
+        SchemaCoordinate::Directive { directive_name } => Some((
+            UsageType::DirectiveArgument,
+            SchemaCoordinate::DirectiveArgument {
+                directive_name,
+                argument_name,
+            },
+        )),
+        SchemaCoordinate::Field {
+            type_name,
+            field_name,
+        } => Some((
+            UsageType::Argument,
+            SchemaCoordinate::Argument {
+                type_name,
+                field_name,
+                argument_name,
+            },
+        )),
+        _ => None,
     }
 }
 
@@ -159,6 +347,128 @@ fn get_deprecation_reason<N: HasDirectives>(ast_item: &N) -> Option<&str> {
 }
 
 impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Deprecation<'a, E, S, VV> {
+    fn current_path(&self) -> Path<'a> {
+        self.paths.last().cloned().unwrap_or_default()
+    }
+
+    /// Searches the arguments of `directive`, given or omitted, applied at `path`
+    fn find_deprecations_for_directive(
+        &mut self,
+        directive: &'a E::Directive<false>,
+        path: Path<'a>,
+    ) {
+        let Some(directive_definition) = self
+            .schema_definition
+            .get_directive_definition(directive.name())
+        else {
+            return;
+        };
+        let owner = SchemaCoordinate::Directive {
+            directive_name: directive.name(),
+        };
+        self.find_deprecations_for_omitted_arguments(
+            directive_definition.arguments_definition(),
+            directive.arguments(),
+            owner,
+            OffenderNode::Directive(directive),
+            path.clone(),
+        );
+        self.find_deprecations_for_given_arguments(
+            directive_definition.arguments_definition(),
+            directive.arguments(),
+            owner,
+            path,
+        );
+    }
+
+    /// Searches the `arguments` given to `owner` that are defined in `arguments_definition`
+    fn find_deprecations_for_given_arguments(
+        &mut self,
+        arguments_definition: Option<&'a S::ArgumentsDefinition>,
+        arguments: Option<&'a E::Arguments<false>>,
+        owner: SchemaCoordinate<'a>,
+        path: Path<'a>,
+    ) {
+        let (Some(arguments_definition), Some(arguments)) = (arguments_definition, arguments)
+        else {
+            return;
+        };
+        arguments.iter().for_each(|argument| {
+            let Some(input_value_definition) = arguments_definition.get(argument.name()) else {
+                return;
+            };
+            let Some((offense_type, coordinate)) =
+                argument_coordinate(owner, input_value_definition.name())
+            else {
+                return;
+            };
+
+            if let Some(reason) =
+                get_deprecation_reason::<S::InputValueDefinition>(input_value_definition)
+            {
+                self.offenders.push(Offender {
+                    name: argument.name(),
+                    offense_type,
+                    reason,
+                    coordinate,
+                    parent: Some(owner),
+                    path: path.clone(),
+                    node: OffenderNode::Argument(argument),
+                });
+            }
+
+            self.find_deprecations_for_value(
+                input_value_definition.r#type(),
+                argument.value(),
+                &ValueUsage {
+                    name: argument.name(),
+                    node: OffenderNode::Argument(argument),
+                    parent: Some(coordinate),
+                    path: path.clone(),
+                },
+            );
+        });
+    }
+
+    /// Searches the default values of the arguments of `owner` that are not given in
+    /// `arguments`, as they are used in their place
+    fn find_deprecations_for_omitted_arguments(
+        &mut self,
+        arguments_definition: Option<&'a S::ArgumentsDefinition>,
+        arguments: Option<&'a E::Arguments<false>>,
+        owner: SchemaCoordinate<'a>,
+        node: OffenderNode<'a, E>,
+        path: Path<'a>,
+    ) {
+        arguments_definition
+            .into_iter()
+            .flat_map(|arguments_definition| arguments_definition.iter())
+            .filter(|argument_definition| {
+                arguments.is_none_or(|arguments| {
+                    !arguments
+                        .iter()
+                        .any(|argument| argument.name() == argument_definition.name())
+                })
+            })
+            .for_each(|argument_definition| {
+                if let (Some(default_value), Some((_, coordinate))) = (
+                    argument_definition.default_value(),
+                    argument_coordinate(owner, argument_definition.name()),
+                ) {
+                    self.find_deprecations_for_value(
+                        argument_definition.r#type(),
+                        default_value,
+                        &ValueUsage {
+                            name: argument_definition.name(),
+                            node,
+                            parent: Some(coordinate),
+                            path: path.clone(),
+                        },
+                    );
+                }
+            });
+    }
+
     fn find_deprecations_for_value<
         const CONST: bool,
         I: InputType<
@@ -171,14 +481,14 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
         &mut self,
         input_type: &'a I,
         value: &'a V,
-        name: &'a str,
+        usage: &ValueUsage<'a, E>,
     ) {
         match input_type.as_ref(self.schema_definition) {
             InputTypeReference::List(inner_list_type, _) => match value.as_ref() {
                 ValueReference::List(list_value) => list_value.iter().for_each(|list_item| {
-                    self.find_deprecations_for_value(inner_list_type, list_item, name)
+                    self.find_deprecations_for_value(inner_list_type, list_item, usage)
                 }),
-                _ => self.find_deprecations_for_value(inner_list_type, value, name),
+                _ => self.find_deprecations_for_value(inner_list_type, value, usage),
             },
             InputTypeReference::Base(base_input_type, _) => match base_input_type {
                 BaseInputTypeReference::Enum(etd) => {
@@ -192,18 +502,26 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
                         _ => None,
                     };
                     if let Some(enum_value) = enum_value {
-                        if let Some(deprecation_reason) = etd
+                        if let Some((enum_value_definition, deprecation_reason)) = etd
                             .enum_value_definitions()
                             .iter()
                             .find(|evd| evd.name() == enum_value)
                             .and_then(|found_enum_value| {
                                 get_deprecation_reason::<S::EnumValueDefinition>(found_enum_value)
+                                    .map(|reason| (found_enum_value, reason))
                             })
                         {
                             self.offenders.push(Offender {
-                                name,
+                                name: usage.name,
                                 offense_type: UsageType::EnumValue,
                                 reason: deprecation_reason,
+                                coordinate: SchemaCoordinate::EnumValue {
+                                    type_name: etd.name(),
+                                    value_name: enum_value_definition.name(),
+                                },
+                                parent: usage.parent,
+                                path: usage.path.clone(),
+                                node: usage.node,
                             });
                         }
                     }
@@ -227,14 +545,37 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
                                             name: input_field_definition.name(),
                                             offense_type: UsageType::InputField,
                                             reason,
+                                            coordinate: SchemaCoordinate::InputField {
+                                                type_name: iotd.name(),
+                                                field_name: input_field_definition.name(),
+                                            },
+                                            parent: usage.parent,
+                                            path: usage.path.clone(),
+                                            node: usage.node,
                                         });
                                     }
 
                                     self.find_deprecations_for_value(
                                         input_field_definition.r#type(),
                                         value,
-                                        name,
+                                        usage,
                                     )
+                                } else if let Some(default_value) =
+                                    input_field_definition.default_value()
+                                {
+                                    let coordinate = SchemaCoordinate::InputField {
+                                        type_name: iotd.name(),
+                                        field_name: input_field_definition.name(),
+                                    };
+                                    if !self.input_field_defaults.contains(&coordinate) {
+                                        self.input_field_defaults.push(coordinate);
+                                        self.find_deprecations_for_value(
+                                            input_field_definition.r#type(),
+                                            default_value,
+                                            usage,
+                                        );
+                                        self.input_field_defaults.pop();
+                                    }
                                 }
                             });
                     }
@@ -248,24 +589,70 @@ impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Depreca
 impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
     for Deprecation<'a, E, S, VV>
 {
-    type Output = Vec<Offender<'a>>;
+    type Output = Vec<Offender<'a, E>>;
 
     fn into_output(self) -> Self::Output {
         self.offenders
     }
 }
 
+#[cfg(feature = "parser-integration")]
+impl<'a> From<Offender<'a, ParserExecutableDocument<'a>>> for ParserError {
+    fn from(value: Offender<'a, ParserExecutableDocument<'a>>) -> Self {
+        let Offender {
+            reason,
+            coordinate,
+            path,
+            node,
+            ..
+        } = value;
+        let span = match node {
+            OffenderNode::Field(field) => field.name().span(),
+            OffenderNode::Argument(argument) => argument.span(),
+            OffenderNode::Directive(directive) => directive.span(),
+            OffenderNode::VariableDefinition(variable_definition) => {
+                variable_definition.variable().span()
+            }
+        };
+        let path: Vec<String> = path.to_vec();
+        Self::new(
+            format!("`{coordinate}` is deprecated: {reason}"),
+            Some(Annotation::new(
+                format!("Uses deprecated `{coordinate}`"),
+                span.clone(),
+            )),
+            Vec::new(),
+        )
+        .with_code("DEPRECATED_USAGE")
+        .with_extensions(
+            [
+                (
+                    "schemaCoordinate",
+                    ExtensionValue::from(coordinate.to_string()),
+                ),
+                ("deprecationReason", reason.to_owned().into()),
+            ]
+            .into_iter()
+            .chain((!path.is_empty()).then(|| ("path", path.into()))),
+        )
+        .with_severity(ParserSeverity::Warning)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Deprecation, Offender};
+    use super::{Deprecation, Offender, OffenderNode};
     use crate::executable::{
         operation::{analyzers::deprecation::UsageType, Orchestrator},
         Cache,
     };
-    use bluejay_parser::ast::{
-        definition::{DefinitionDocument, SchemaDefinition as ParserSchemaDefinition},
-        executable::ExecutableDocument as ParserExecutableDocument,
-        Parse,
+    use bluejay_parser::{
+        ast::{
+            definition::{DefinitionDocument, SchemaDefinition as ParserSchemaDefinition},
+            executable::ExecutableDocument as ParserExecutableDocument,
+            Parse,
+        },
+        error::{Error as ParserError, ExtensionValue},
     };
     use once_cell::sync::Lazy;
     use serde_json::{Map as JsonMap, Value as JsonValue};
@@ -291,10 +678,30 @@ mod tests {
             nested: TestInput
         }
 
+        input DefaultedInput {
+            scope: TestEnum = DEPRECATED
+            nested: TestInput = { deprecated_input_field: "default" }
+            next: DefaultedInput = {}
+        }
+
+        directive @cached(
+            ttl: Int @deprecated(reason: "directive_arg")
+            scope: TestEnum
+        ) on FIELD
+
+        directive @sampled(scope: TestEnum = DEPRECATED) on FIELD
+
+        type Item {
+            legacy_name: String! @deprecated(reason: "item_field")
+            price(currency: String @deprecated(reason: "item_arg")): Int!
+        }
+
         type Query {
           valid_field: String!
           test_field: String! @deprecated(reason: "field")
           test_enum(deprecated_enum: TestEnum): String!
+          test_default_enum(deprecated_enum: TestEnum = DEPRECATED): String!
+          test_defaulted_input(input: DefaultedInput): String!
           test_arg(
             deprecated_arg: String @deprecated(reason: "arg")
           ): String!
@@ -303,6 +710,7 @@ mod tests {
           ): String!
           test_nested_input(nested_input: NestedInput): String!
           test_nested_input_list(nested_input: [NestedInput]): String!
+          items: [Item!]!
         }
         schema {
           query: Query
@@ -315,21 +723,49 @@ mod tests {
     static TEST_SCHEMA_DEFINITION: Lazy<ParserSchemaDefinition<'static>> =
         Lazy::new(|| ParserSchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
 
-    fn validate_deprecations(query: &str, variables: serde_json::Value, expected: Vec<Offender>) {
-        let executable_document = ParserExecutableDocument::parse(query)
-            .result
-            .unwrap_or_else(|_| panic!("Document had parse errors"));
-        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
-        let variables = variables.as_object().expect("Variables must be an object");
-        let deprecations = DeprecationAnalyzer::analyze(
-            &executable_document,
+    /// The parts of an [Offender] that don't depend on where it was found
+    #[derive(Debug, PartialEq)]
+    struct ExpectedOffender<'a> {
+        reason: &'a str,
+        offense_type: UsageType,
+        name: &'a str,
+    }
+
+    fn analyze_deprecations<'a>(
+        executable_document: &'a ParserExecutableDocument<'a>,
+        cache: &'a Cache<'a, ParserExecutableDocument<'a>, ParserSchemaDefinition<'static>>,
+        variables: &'a JsonMap<String, JsonValue>,
+    ) -> Vec<Offender<'a, ParserExecutableDocument<'a>>> {
+        DeprecationAnalyzer::analyze(
+            executable_document,
             &*TEST_SCHEMA_DEFINITION,
             None,
             variables,
-            &cache,
+            cache,
             (),
         )
-        .unwrap();
+        .unwrap()
+    }
+
+    fn validate_deprecations(
+        query: &str,
+        variables: serde_json::Value,
+        expected: Vec<ExpectedOffender>,
+    ) {
+        let executable_document = ParserExecutableDocument::parse(query)
+            .result
+            .unwrap_or_else(|_| panic!("Document had parse errors"));
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variables = variables.as_object().expect("Variables must be an object");
+        let deprecations: Vec<ExpectedOffender> =
+            analyze_deprecations(&executable_document, &cache, variables)
+                .iter()
+                .map(|offender| ExpectedOffender {
+                    reason: offender.reason,
+                    offense_type: offender.offense_type,
+                    name: offender.name,
+                })
+                .collect();
         assert_eq!(deprecations, expected);
     }
 
@@ -338,7 +774,7 @@ mod tests {
         validate_deprecations(
             r#"query { test_field }"#,
             serde_json::json!({}),
-            vec![Offender {
+            vec![ExpectedOffender {
                 name: "test_field",
                 reason: "field",
                 offense_type: UsageType::Field,
@@ -356,7 +792,7 @@ mod tests {
         validate_deprecations(
             r#"query ($test: TestEnum) { test_enum(deprecated_enum: $test) }"#,
             serde_json::json!({ "test": "DEPRECATED" }),
-            vec![Offender {
+            vec![ExpectedOffender {
                 name: "test",
                 reason: "enum_value",
                 offense_type: UsageType::EnumValue,
@@ -369,7 +805,7 @@ mod tests {
         validate_deprecations(
             r#"query { test_enum(deprecated_enum: DEPRECATED) }"#,
             serde_json::json!({}),
-            vec![Offender {
+            vec![ExpectedOffender {
                 name: "deprecated_enum",
                 reason: "enum_value",
                 offense_type: UsageType::EnumValue,
@@ -382,7 +818,7 @@ mod tests {
         validate_deprecations(
             r#"query { test_arg(deprecated_arg: "x") }"#,
             serde_json::json!({}),
-            vec![Offender {
+            vec![ExpectedOffender {
                 name: "deprecated_arg",
                 reason: "arg",
                 offense_type: UsageType::Argument,
@@ -395,7 +831,7 @@ mod tests {
         validate_deprecations(
             r#"query($test: String) { test_arg(deprecated_arg: $test) }"#,
             serde_json::json!({ "test": "x" }),
-            vec![Offender {
+            vec![ExpectedOffender {
                 name: "deprecated_arg",
                 reason: "arg",
                 offense_type: UsageType::Argument,
@@ -408,7 +844,7 @@ mod tests {
         validate_deprecations(
             r#"query { test_input(input: { deprecated_input_field: "x" }) }"#,
             serde_json::json!({}),
-            vec![Offender {
+            vec![ExpectedOffender {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
@@ -421,7 +857,7 @@ mod tests {
         validate_deprecations(
             r#"query($input: TestInput) { test_input(input: $input) }"#,
             serde_json::json!({ "input": { "deprecated_input_field": "x" } }),
-            vec![Offender {
+            vec![ExpectedOffender {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
@@ -436,7 +872,7 @@ mod tests {
             serde_json::json!({
                 "test": "x"
             }),
-            vec![Offender {
+            vec![ExpectedOffender {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
@@ -449,7 +885,7 @@ mod tests {
         validate_deprecations(
             r#"query { test_nested_input(nested_input: { nested: { deprecated_input_field: "x" } }) }"#,
             serde_json::json!({}),
-            vec![Offender {
+            vec![ExpectedOffender {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
@@ -462,7 +898,7 @@ mod tests {
         validate_deprecations(
             r#"query { test_nested_input_list(nested_input: [{ nested: { deprecated_input_field: "x" } }]) }"#,
             serde_json::json!({}),
-            vec![Offender {
+            vec![ExpectedOffender {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
@@ -475,11 +911,245 @@ mod tests {
         validate_deprecations(
             r#"query($test: [NestedInput]) { test_nested_input_list(nested_input: $test) }"#,
             serde_json::json!({ "test": [{ "nested": { "deprecated_input_field": "x" } }] }),
-            vec![Offender {
+            vec![ExpectedOffender {
                 name: "deprecated_input_field",
                 reason: "input_field",
                 offense_type: UsageType::InputField,
             }],
         );
     }
+
+    #[test]
+    fn directive_arg_deprecation() {
+        validate_deprecations(
+            r#"query { valid_field @cached(ttl: 10, scope: DEPRECATED) }"#,
+            serde_json::json!({}),
+            vec![
+                ExpectedOffender {
+                    name: "ttl",
+                    reason: "directive_arg",
+                    offense_type: UsageType::DirectiveArgument,
+                },
+                ExpectedOffender {
+                    name: "scope",
+                    reason: "enum_value",
+                    offense_type: UsageType::EnumValue,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn default_value_deprecation() {
+        validate_deprecations(
+            r#"query($test: [NestedInput] = [{ nested: { deprecated_input_field: "x" } }], $enum: TestEnum = DEPRECATED) { test_nested_input_list(nested_input: $test) test_enum(deprecated_enum: $enum) }"#,
+            serde_json::json!({}),
+            vec![
+                ExpectedOffender {
+                    name: "deprecated_input_field",
+                    reason: "input_field",
+                    offense_type: UsageType::InputField,
+                },
+                ExpectedOffender {
+                    name: "enum",
+                    reason: "enum_value",
+                    offense_type: UsageType::EnumValue,
+                },
+            ],
+        );
+    }
+
+    #[test]
+    fn offender_locations() {
+        let executable_document = ParserExecutableDocument::parse(
+            r#"query($enum: TestEnum = DEPRECATED) {
+                items { name: legacy_name price(currency: "CAD") @cached(ttl: 60) }
+                test_enum(deprecated_enum: $enum) @cached(scope: DEPRECATED)
+            }"#,
+        )
+        .result
+        .unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variables = JsonMap::new();
+        let offenders = analyze_deprecations(&executable_document, &cache, &variables);
+
+        let locations: Vec<(String, Option<String>, Vec<String>, &str)> = offenders
+            .iter()
+            .map(|offender| {
+                let node_name = match offender.node {
+                    OffenderNode::Field(_) => "field",
+                    OffenderNode::Argument(_) => "argument",
+                    OffenderNode::Directive(_) => "directive",
+                    OffenderNode::VariableDefinition(_) => "variable_definition",
+                };
+                (
+                    offender.coordinate.to_string(),
+                    offender.parent.map(|parent| parent.to_string()),
+                    offender.path.to_vec(),
+                    node_name,
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    "Item.legacy_name".to_string(),
+                    Some("Item".to_string()),
+                    vec!["items".to_string(), "name".to_string()],
+                    "field",
+                ),
+                (
+                    "@cached(ttl:)".to_string(),
+                    Some("@cached".to_string()),
+                    vec!["items".to_string(), "price".to_string()],
+                    "argument",
+                ),
+                (
+                    "Item.price(currency:)".to_string(),
+                    Some("Item.price".to_string()),
+                    vec!["items".to_string(), "price".to_string()],
+                    "argument",
+                ),
+                (
+                    "TestEnum.DEPRECATED".to_string(),
+                    Some("@cached(scope:)".to_string()),
+                    vec!["test_enum".to_string()],
+                    "argument",
+                ),
+                (
+                    "TestEnum.DEPRECATED".to_string(),
+                    None,
+                    Vec::new(),
+                    "variable_definition",
+                ),
+            ],
+            locations,
+        );
+    }
+
+    #[test]
+    fn schema_default_value_deprecation() {
+        let executable_document = ParserExecutableDocument::parse(
+            r#"query {
+                test_default_enum
+                given: test_default_enum(deprecated_enum: null)
+                valid_field @sampled
+                test_defaulted_input(input: {})
+            }"#,
+        )
+        .result
+        .unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variables = JsonMap::new();
+        let offenders = analyze_deprecations(&executable_document, &cache, &variables);
+
+        let locations: Vec<(String, String, Vec<String>, &str)> = offenders
+            .iter()
+            .map(|offender| {
+                let node_name = match offender.node {
+                    OffenderNode::Field(_) => "field",
+                    OffenderNode::Argument(_) => "argument",
+                    OffenderNode::Directive(_) => "directive",
+                    OffenderNode::VariableDefinition(_) => "variable_definition",
+                };
+                (
+                    offender.coordinate.to_string(),
+                    offender.parent.unwrap().to_string(),
+                    offender.path.to_vec(),
+                    node_name,
+                )
+            })
+            .collect();
+        let input_offenders = || {
+            ["TestEnum.DEPRECATED", "TestInput.deprecated_input_field"].map(|coordinate| {
+                (
+                    coordinate.to_string(),
+                    "Query.test_defaulted_input(input:)".to_string(),
+                    vec!["test_defaulted_input".to_string()],
+                    "argument",
+                )
+            })
+        };
+        // the default of `DefaultedInput.next` refers back to `DefaultedInput`, so it is only
+        // searched once
+        let expected: Vec<_> = [
+            (
+                "TestEnum.DEPRECATED".to_string(),
+                "Query.test_default_enum(deprecated_enum:)".to_string(),
+                vec!["test_default_enum".to_string()],
+                "field",
+            ),
+            (
+                "TestEnum.DEPRECATED".to_string(),
+                "@sampled(scope:)".to_string(),
+                vec!["valid_field".to_string()],
+                "directive",
+            ),
+        ]
+        .into_iter()
+        .chain(input_offenders())
+        .chain(input_offenders())
+        .collect();
+        assert_eq!(expected, locations);
+    }
+
+    #[test]
+    fn offender_clone_debug_and_eq() {
+        let executable_document =
+            ParserExecutableDocument::parse("query { test_field test_arg(deprecated_arg: \"x\") }")
+                .result
+                .unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variables = JsonMap::new();
+        let offenders = analyze_deprecations(&executable_document, &cache, &variables);
+
+        assert_eq!(2, offenders.len());
+        assert_eq!(offenders, offenders.clone());
+        assert_ne!(offenders[0], offenders[1]);
+        let debug = format!("{:?}", offenders[0]);
+        assert!(debug.starts_with("Offender { reason: \"field\", offense_type: Field"));
+        assert!(debug.contains("node: Field("));
+    }
+
+    #[test]
+    fn offender_into_parser_error() {
+        let query = "query { items { price(currency: \"CAD\") } }";
+        let executable_document = ParserExecutableDocument::parse(query).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variables = JsonMap::new();
+        let offenders = analyze_deprecations(&executable_document, &cache, &variables);
+
+        let graphql_errors = ParserError::into_graphql_errors(query, offenders);
+        assert_eq!(1, graphql_errors.len());
+        let graphql_error = &graphql_errors[0];
+        assert_eq!(
+            "Uses deprecated `Item.price(currency:)`",
+            graphql_error.message
+        );
+        assert_eq!(
+            (1, 23),
+            (
+                graphql_error.locations[0].line,
+                graphql_error.locations[0].col
+            ),
+        );
+        assert_eq!(
+            Some("DEPRECATED_USAGE"),
+            graphql_error
+                .extensions
+                .get("code")
+                .and_then(ExtensionValue::as_str),
+        );
+        assert_eq!(
+            Some(["items".to_owned(), "price".to_owned()].as_slice()),
+            graphql_error
+                .extensions
+                .get("path")
+                .and_then(ExtensionValue::as_list),
+        );
+
+        let offenders = analyze_deprecations(&executable_document, &cache, &variables);
+        let formatted = ParserError::format_errors(query, None, offenders);
+        assert!(formatted.starts_with("Warning: `Item.price(currency:)` is deprecated: item_arg"));
+    }
 }
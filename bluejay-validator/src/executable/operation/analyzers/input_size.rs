@@ -8,9 +8,11 @@ use bluejay_core::{
     Argument, AsIter, ObjectValue, Value, ValueReference, Variable,
 };
 
-#[derive(Clone)]
-/// Represents an argument or input-field that exceeds
-/// the maximum allowed list-size.
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Represents a part of the operation that exceeds a limit, named by its path, along with
+/// its size: an argument or input-field exceeding the maximum allowed list-size for
+/// [InputSize], or a path contributing to a count for the
+/// [selection limits](super::selection_limits).
 pub struct Offender {
     pub size: usize,
     pub name: String,
@@ -0,0 +1,414 @@
+use super::selection_limits::{Counter, LimitReport, Offender, ResponsePath};
+use crate::executable::{
+    operation::{Analyzer, VariableValues, Visitor},
+    Cache,
+};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field, OperationDefinition};
+use bluejay_core::OperationType;
+use std::cmp::max;
+
+const TYPE_INTROSPECTION_TYPE_NAME: &str = "__Type";
+const FIELD_INTROSPECTION_TYPE_NAME: &str = "__Field";
+const DIRECTIVE_INTROSPECTION_TYPE_NAME: &str = "__Directive";
+/// The fields that lead to another `__Type`, directly or through the `type` of `__InputValue`,
+/// with the introspection types defining them, in the order of [IntrospectionLimits::max_depths]
+const NESTED_TYPE_FIELDS: [(&[&str], &str); 6] = [
+    (&[TYPE_INTROSPECTION_TYPE_NAME], "fields"),
+    (&[TYPE_INTROSPECTION_TYPE_NAME], "interfaces"),
+    (&[TYPE_INTROSPECTION_TYPE_NAME], "possibleTypes"),
+    (&[TYPE_INTROSPECTION_TYPE_NAME], "ofType"),
+    (&[TYPE_INTROSPECTION_TYPE_NAME], "inputFields"),
+    (
+        &[
+            FIELD_INTROSPECTION_TYPE_NAME,
+            DIRECTIVE_INTROSPECTION_TYPE_NAME,
+        ],
+        "args",
+    ),
+];
+const INTROSPECTION_ROOT_FIELD_NAMES: [&str; 2] = ["__schema", "__type"];
+const TYPENAME_FIELD_NAME: &str = "__typename";
+
+/// The limits enforced by the [Introspection] analyzer. Nesting depths count how many times
+/// the same introspection field appears within a single path, e.g.
+/// `fields { type { fields { name } } }` has a `fields` depth of 2.
+#[derive(Clone, Copy, Debug)]
+pub struct IntrospectionLimits {
+    pub max_fields_depth: usize,
+    pub max_interfaces_depth: usize,
+    pub max_possible_types_depth: usize,
+    pub max_of_type_depth: usize,
+    pub max_input_fields_depth: usize,
+    /// Maximum nesting of the `args` of `__Field` and `__Directive`
+    pub max_args_depth: usize,
+    /// Maximum number of `__schema` and `__type` root fields
+    pub max_root_fields: usize,
+}
+
+impl IntrospectionLimits {
+    fn max_depths(&self) -> [usize; NESTED_TYPE_FIELDS.len()] {
+        [
+            self.max_fields_depth,
+            self.max_interfaces_depth,
+            self.max_possible_types_depth,
+            self.max_of_type_depth,
+            self.max_input_fields_depth,
+            self.max_args_depth,
+        ]
+    }
+}
+
+impl Default for IntrospectionLimits {
+    /// Allows the introspection queries sent by common GraphQL clients and tools
+    fn default() -> Self {
+        Self {
+            max_fields_depth: 1,
+            max_interfaces_depth: 1,
+            max_possible_types_depth: 1,
+            max_of_type_depth: 9,
+            max_input_fields_depth: 1,
+            max_args_depth: 1,
+            max_root_fields: 1,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntrospectionReport {
+    /// The `__schema` and `__type` root fields, with offenders when there are more
+    /// than [IntrospectionLimits::max_root_fields]
+    pub root_fields: LimitReport,
+    /// The first field of each path that nests a `__Type` field deeper than allowed,
+    /// sized by the deepest nesting reached below it
+    pub offenders: Vec<Offender>,
+    /// Whether the operation is a query selecting only introspection root fields
+    /// (`__schema`, `__type` and `__typename`)
+    pub is_pure_introspection: bool,
+}
+
+impl IntrospectionReport {
+    pub fn exceeds_limits(&self) -> bool {
+        self.root_fields.exceeds_limit() || !self.offenders.is_empty()
+    }
+}
+
+/// The [Introspection] analyzer limits how deeply introspection selections can nest the
+/// `fields`, `interfaces`, `possibleTypes`, `ofType` and `inputFields` fields of `__Type` and
+/// the `args` of `__Field` and `__Directive`, and counts the
+/// introspection root fields of the operation. Only selections included in the response
+/// are considered.
+pub struct Introspection<'a> {
+    limits: IntrospectionLimits,
+    path: ResponsePath<'a>,
+    depths: [usize; NESTED_TYPE_FIELDS.len()],
+    open_offenders: [Option<usize>; NESTED_TYPE_FIELDS.len()],
+    offenders: Vec<Offender>,
+    root_fields: Counter,
+    is_query: bool,
+    has_root_fields: bool,
+    has_non_introspection_root_fields: bool,
+}
+
+impl<'a> Introspection<'a> {
+    fn nested_type_field_index<T: TypeDefinition>(
+        field_name: &str,
+        scoped_type: TypeDefinitionReference<'a, T>,
+    ) -> Option<usize> {
+        NESTED_TYPE_FIELDS
+            .iter()
+            .position(|(type_names, nested_type_field_name)| {
+                *nested_type_field_name == field_name && type_names.contains(&scoped_type.name())
+            })
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for Introspection<'a>
+{
+    type ExtraInfo = IntrospectionLimits;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        _: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        limits: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            limits,
+            path: ResponsePath::new::<E, S>(operation_definition, schema_definition),
+            depths: [0; NESTED_TYPE_FIELDS.len()],
+            open_offenders: [None; NESTED_TYPE_FIELDS.len()],
+            offenders: Vec::new(),
+            root_fields: Counter::new(limits.max_root_fields),
+            is_query: operation_definition.as_ref().operation_type() == OperationType::Query,
+            has_root_fields: false,
+            has_non_introspection_root_fields: false,
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        if self.path.is_root() {
+            self.has_root_fields = true;
+            if INTROSPECTION_ROOT_FIELD_NAMES.contains(&field.name()) {
+                self.root_fields.increment(self.path.child(field.name()));
+            } else if field.name() != TYPENAME_FIELD_NAME {
+                self.has_non_introspection_root_fields = true;
+            }
+        }
+
+        if let Some(index) = Self::nested_type_field_index(field.name(), scoped_type) {
+            self.depths[index] += 1;
+            let depth = self.depths[index];
+            if depth > self.limits.max_depths()[index] {
+                match self.open_offenders[index] {
+                    Some(offender_index) => {
+                        let offender = &mut self.offenders[offender_index];
+                        offender.size = max(offender.size, depth);
+                    }
+                    None => {
+                        self.open_offenders[index] = Some(self.offenders.len());
+                        self.offenders.push(Offender {
+                            size: depth,
+                            name: self.path.child(field.response_name()),
+                        });
+                    }
+                }
+            }
+        }
+
+        self.path.push(field.response_name());
+    }
+
+    fn leave_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        self.path.pop();
+
+        if let Some(index) = Self::nested_type_field_index(field.name(), scoped_type) {
+            if self.depths[index] == self.limits.max_depths()[index] + 1 {
+                self.open_offenders[index] = None;
+            }
+            self.depths[index] -= 1;
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for Introspection<'a>
+{
+    type Output = IntrospectionReport;
+
+    fn into_output(self) -> Self::Output {
+        IntrospectionReport {
+            root_fields: self.root_fields.into_report(),
+            offenders: self.offenders,
+            is_pure_introspection: self.is_query
+                && self.has_root_fields
+                && !self.has_non_introspection_root_fields,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Introspection, IntrospectionLimits, IntrospectionReport};
+    use crate::executable::{
+        operation::{analyzers::selection_limits::Offender, Orchestrator},
+        Cache,
+    };
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::{Map as JsonMap, Value as JsonValue};
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        type Query {
+            ping: String!
+        }
+
+        type Mutation {
+            ping: String!
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    fn analyze(source: &str, limits: IntrospectionLimits) -> IntrospectionReport {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        Orchestrator::<_, _, JsonMap<String, JsonValue>, Introspection>::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            &JsonMap::new(),
+            &cache,
+            limits,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_client_introspection_query() {
+        let report = analyze(
+            r#"query IntrospectionQuery {
+                __schema {
+                    queryType { name }
+                    types { ...FullType }
+                }
+            }
+            fragment FullType on __Type {
+                kind
+                name
+                fields(includeDeprecated: true) { name args { ...InputValue } type { ...TypeRef } }
+                inputFields { ...InputValue }
+                interfaces { ...TypeRef }
+                possibleTypes { ...TypeRef }
+            }
+            fragment InputValue on __InputValue {
+                name
+                type { ...TypeRef }
+            }
+            fragment TypeRef on __Type {
+                kind name ofType { kind name ofType { kind name ofType { kind name } } }
+            }"#,
+            IntrospectionLimits::default(),
+        );
+
+        assert!(report.is_pure_introspection);
+        assert!(!report.exceeds_limits());
+        assert_eq!(1, report.root_fields.count);
+    }
+
+    #[test]
+    fn test_nested_fields() {
+        let report = analyze(
+            r#"{
+                __type(name: "Query") {
+                    fields { type { fields { type { fields { name } } } } }
+                    ofType { ofType { ofType { name } } }
+                }
+            }"#,
+            IntrospectionLimits {
+                max_of_type_depth: 2,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            vec![
+                Offender {
+                    size: 3,
+                    name: "Query.__type.fields.type.fields".to_owned(),
+                },
+                Offender {
+                    size: 3,
+                    name: "Query.__type.ofType.ofType.ofType".to_owned(),
+                },
+            ],
+            report.offenders,
+        );
+        assert!(report.exceeds_limits());
+    }
+
+    #[test]
+    fn test_nested_input_values() {
+        let report = analyze(
+            r#"{
+                __type(name: "Query") {
+                    inputFields { type { inputFields { type { name } } } }
+                    fields { args { type { fields { args { name } } } } }
+                }
+                __schema { directives { args { type { inputFields { name } } } } }
+            }"#,
+            IntrospectionLimits {
+                max_fields_depth: 2,
+                max_root_fields: 2,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            vec![
+                Offender {
+                    size: 2,
+                    name: "Query.__type.inputFields.type.inputFields".to_owned(),
+                },
+                Offender {
+                    size: 2,
+                    name: "Query.__type.fields.args.type.fields.args".to_owned(),
+                },
+            ],
+            report.offenders,
+        );
+    }
+
+    #[test]
+    fn test_root_fields() {
+        let report = analyze(
+            r#"{
+                a: __type(name: "Query") { name }
+                b: __type(name: "Mutation") { name }
+                __typename
+            }"#,
+            IntrospectionLimits::default(),
+        );
+
+        assert_eq!(
+            vec![Offender {
+                size: 2,
+                name: "Query.__type".to_owned(),
+            }],
+            report.root_fields.offenders,
+        );
+        assert!(report.is_pure_introspection);
+    }
+
+    #[test]
+    fn test_mixed_operations_are_not_pure_introspection() {
+        assert!(
+            !analyze(
+                "{ ping __schema { queryType { name } } }",
+                IntrospectionLimits::default()
+            )
+            .is_pure_introspection
+        );
+        assert!(
+            !analyze("mutation { __typename }", IntrospectionLimits::default())
+                .is_pure_introspection
+        );
+        assert!(
+            analyze(
+                "{ __schema { queryType { name } } ping @skip(if: true) }",
+                IntrospectionLimits::default()
+            )
+            .is_pure_introspection
+        );
+    }
+}
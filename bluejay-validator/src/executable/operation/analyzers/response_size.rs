@@ -0,0 +1,341 @@
+use crate::executable::{
+    operation::{
+        analyzers::complexity_cost::{CostComputer, DefaultCostComputer, FieldMultipliers},
+        Analyzer, VariableValues, Visitor,
+    },
+    Cache,
+};
+use bluejay_core::definition::{
+    FieldDefinition, OutputType, SchemaDefinition, TypeDefinitionReference,
+};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use std::cmp::max;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// The upper bound on the size of a response estimated by [ResponseSize]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct ResponseSizeEstimate {
+    /// The number of objects in the response, not counting the root object
+    pub objects: usize,
+    /// The number of scalar and enum values in the response
+    pub leaf_values: usize,
+}
+
+/// The [ResponseSize] analyzer estimates an upper bound on the number of objects and leaf values
+/// in the response to an operation. The size of lists comes from the same [CostComputer] used
+/// to compute the [`ComplexityCost`](super::ComplexityCost) of the operation: its multipliers
+/// for nested fields, like the `edges` of a connection, and its list sizes for lists sized by
+/// their own arguments, like `@listSize`. Lists without a size count as a single item.
+///
+/// Fields selected more than once with the same response key are only counted once, with the
+/// largest multipliers of their selections, such as when the selections are on different types
+/// with different arguments.
+pub struct ResponseSize<
+    'a,
+    E: ExecutableDocument,
+    S: SchemaDefinition,
+    V: VariableValues,
+    C: CostComputer<'a, E, S, V> = DefaultCostComputer,
+> {
+    schema_definition: &'a S,
+    cost_computer: C,
+    scopes: Vec<ResponseScope<C::FieldMultipliers>>,
+    scope_indices: HashMap<(usize, &'a str), usize>,
+    scopes_stack: Vec<usize>,
+    estimate: ResponseSizeEstimate,
+}
+
+struct ResponseScope<F> {
+    /// How many times the field appears in the response
+    instances: usize,
+    /// How many values the field holds in the response, counting each item of lists
+    items: usize,
+    /// The multipliers of every selection merged into the scope, of which the children use the
+    /// largest
+    field_multipliers: Vec<F>,
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > Visitor<'a, E, S, V> for ResponseSize<'a, E, S, V, C>
+{
+    type ExtraInfo = ();
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        variable_values: &'a V,
+        _: &'a Cache<'a, E, S>,
+        _: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            schema_definition,
+            cost_computer: C::new(operation_definition, schema_definition, variable_values),
+            scopes: vec![ResponseScope {
+                instances: 1,
+                items: 1,
+                field_multipliers: vec![Default::default()],
+            }],
+            scope_indices: HashMap::new(),
+            scopes_stack: vec![0],
+            estimate: ResponseSizeEstimate::default(),
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if !included {
+            return;
+        }
+
+        let parent_index = *self
+            .scopes_stack
+            .last()
+            .expect("expected a parent response scope");
+        let parent_scope = &self.scopes[parent_index];
+        let instances = parent_scope.instances
+            * parent_scope
+                .field_multipliers
+                .iter()
+                .map(|field_multipliers| field_multipliers.multiplier_for_field(field))
+                .max()
+                .unwrap_or(1);
+        let items = instances
+            * self
+                .cost_computer
+                .list_size_for_field(field_definition, field)
+                .unwrap_or(1);
+
+        // let the parent scope pass down multipliers for deeper descendants
+        let field_multipliers: Vec<C::FieldMultipliers> = parent_scope
+            .field_multipliers
+            .iter()
+            .map(|parent_field_multipliers| {
+                parent_field_multipliers.nested_field_multipliers(
+                    field,
+                    self.cost_computer
+                        .field_multipliers(field_definition, field),
+                )
+            })
+            .collect();
+
+        let counter = if field_definition
+            .r#type()
+            .base(self.schema_definition)
+            .is_composite()
+        {
+            &mut self.estimate.objects
+        } else {
+            &mut self.estimate.leaf_values
+        };
+
+        let scope_index = match self
+            .scope_indices
+            .entry((parent_index, field.response_name()))
+        {
+            Entry::Occupied(entry) => {
+                // keep the largest estimate of fields merged into the same response key
+                let scope = &mut self.scopes[*entry.get()];
+                if items > scope.items {
+                    *counter += items - scope.items;
+                    scope.items = items;
+                }
+                scope.instances = max(scope.instances, instances);
+                scope.field_multipliers.extend(field_multipliers);
+                *entry.get()
+            }
+            Entry::Vacant(entry) => {
+                *counter += items;
+                self.scopes.push(ResponseScope {
+                    instances,
+                    items,
+                    field_multipliers,
+                });
+                *entry.insert(self.scopes.len() - 1)
+            }
+        };
+
+        self.scopes_stack.push(scope_index);
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.scopes_stack.pop().unwrap();
+        }
+    }
+}
+
+impl<
+        'a,
+        E: ExecutableDocument,
+        S: SchemaDefinition,
+        V: VariableValues,
+        C: CostComputer<'a, E, S, V>,
+    > Analyzer<'a, E, S, V> for ResponseSize<'a, E, S, V, C>
+{
+    type Output = ResponseSizeEstimate;
+
+    fn into_output(self) -> Self::Output {
+        self.estimate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ResponseSize, ResponseSizeEstimate};
+    use crate::executable::{
+        operation::{
+            analyzers::complexity_cost::{CostDirectivesCostComputer, DefaultCostComputer},
+            Orchestrator,
+        },
+        Cache,
+    };
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::{Map as JsonMap, Value as JsonValue};
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        directive @listSize(assumedSize: Int, slicingArguments: [String!], sizedFields: [String!], requireOneSlicingArgument: Boolean = true) on FIELD_DEFINITION
+
+        type Query {
+            users(first: Int): [User!]! @listSize(slicingArguments: ["first"])
+            node(id: ID!): Node
+            tags: [String!]! @listSize(assumedSize: 20)
+        }
+
+        interface Node {
+            id: ID!
+        }
+
+        type User implements Node {
+            id: ID!
+            name: String!
+            friends: [User!]! @listSize(assumedSize: 10)
+            related(first: Int): [Post!]! @listSize(slicingArguments: ["first"])
+        }
+
+        type Post implements Node {
+            id: ID!
+            title: String!
+            author: User!
+            related(first: Int): [Post!]! @listSize(slicingArguments: ["first"])
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    /// Returns the estimates with list sizes from `@listSize`, and without list sizes
+    fn estimates(source: &str) -> (ResponseSizeEstimate, ResponseSizeEstimate) {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        Orchestrator::<
+            _,
+            _,
+            JsonMap<String, JsonValue>,
+            (
+                ResponseSize<_, _, _, CostDirectivesCostComputer<_, _, _>>,
+                ResponseSize<_, _, _, DefaultCostComputer>,
+            ),
+        >::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            &JsonMap::new(),
+            &cache,
+            ((), ()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_list_sizes() {
+        let (sized, without_list_sizes) = estimates(
+            r#"{
+                users(first: 5) { name friends { id } }
+                node(id: 1) { id ... on Post { title author { name } } }
+                tags
+            }"#,
+        );
+
+        // users: 5 objects with 5 names, 50 friends with 50 ids
+        // node: 2 objects and 3 leaves, tags: 20 leaves
+        assert_eq!(
+            ResponseSizeEstimate {
+                objects: 57,
+                leaf_values: 78,
+            },
+            sized,
+        );
+        assert_eq!(
+            ResponseSizeEstimate {
+                objects: 4,
+                leaf_values: 6,
+            },
+            without_list_sizes,
+        );
+    }
+
+    #[test]
+    fn test_repeated_response_keys_count_once() {
+        let (sized, _) = estimates(
+            r#"{
+                users(first: 5) { name }
+                users(first: 5) { name friends { id } }
+                ...on Query { tags }
+                tags
+            }"#,
+        );
+
+        assert_eq!(
+            ResponseSizeEstimate {
+                objects: 55,
+                leaf_values: 75,
+            },
+            sized,
+        );
+    }
+
+    #[test]
+    fn test_merged_selections_use_largest_multipliers() {
+        for source in [
+            "{ node(id: 1) { ... on User { related(first: 2) { id } } ... on Post { related(first: 10) { id } } } }",
+            "{ node(id: 1) { ... on Post { related(first: 10) { id } } ... on User { related(first: 2) { id } } } }",
+        ] {
+            let (sized, _) = estimates(source);
+
+            // node: 1 object, related: 10 objects with 10 ids
+            assert_eq!(
+                ResponseSizeEstimate {
+                    objects: 11,
+                    leaf_values: 10,
+                },
+                sized,
+                "{source}",
+            );
+        }
+    }
+}
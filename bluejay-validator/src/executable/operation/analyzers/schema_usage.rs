@@ -0,0 +1,498 @@
+use crate::executable::{
+    operation::{Analyzer, VariableValues, Visitor},
+    Cache,
+};
+use bluejay_core::definition::{
+    ArgumentsDefinition, BaseInputTypeReference, DirectiveDefinition, DirectiveLocation,
+    EnumTypeDefinition, EnumValueDefinition, FieldDefinition, InputFieldsDefinition,
+    InputObjectTypeDefinition, InputType, InputTypeReference, InputValueDefinition,
+    ObjectTypeDefinition, OutputType, SchemaDefinition, TypeDefinitionReference,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, OperationDefinition, VariableDefinition, VariableType,
+};
+use bluejay_core::{
+    Argument, AsIter, Directive, ObjectValue, OperationType, Value, ValueReference,
+};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A reference to an element of a schema, displayed in the syntax of schema coordinates,
+/// e.g. `Query.user(id:)` or `@include(if:)`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SchemaCoordinate<'a> {
+    Type {
+        type_name: &'a str,
+    },
+    Field {
+        type_name: &'a str,
+        field_name: &'a str,
+    },
+    Argument {
+        type_name: &'a str,
+        field_name: &'a str,
+        argument_name: &'a str,
+    },
+    EnumValue {
+        type_name: &'a str,
+        value_name: &'a str,
+    },
+    InputField {
+        type_name: &'a str,
+        field_name: &'a str,
+    },
+    Directive {
+        directive_name: &'a str,
+    },
+    DirectiveArgument {
+        directive_name: &'a str,
+        argument_name: &'a str,
+    },
+}
+
+impl fmt::Display for SchemaCoordinate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Type { type_name } => write!(f, "{type_name}"),
+            Self::Field {
+                type_name,
+                field_name,
+            }
+            | Self::InputField {
+                type_name,
+                field_name,
+            } => write!(f, "{type_name}.{field_name}"),
+            Self::Argument {
+                type_name,
+                field_name,
+                argument_name,
+            } => write!(f, "{type_name}.{field_name}({argument_name}:)"),
+            Self::EnumValue {
+                type_name,
+                value_name,
+            } => write!(f, "{type_name}.{value_name}"),
+            Self::Directive { directive_name } => write!(f, "@{directive_name}"),
+            Self::DirectiveArgument {
+                directive_name,
+                argument_name,
+            } => write!(f, "@{directive_name}({argument_name}:)"),
+        }
+    }
+}
+
+/// How the values of an argument were supplied
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct ArgumentUsage {
+    /// The number of times the argument was given a literal value, which may contain variables
+    /// when it is a list or an input object
+    pub literal: usize,
+    /// The number of times the argument was given a variable
+    pub variable: usize,
+}
+
+/// The output of the [SchemaUsage] analyzer
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct SchemaUsageReport<'a> {
+    /// The number of times each schema element is referenced by the operation
+    pub coordinates: BTreeMap<SchemaCoordinate<'a>, usize>,
+    /// How the values of each argument and directive argument referenced by the operation were
+    /// supplied
+    pub arguments: BTreeMap<SchemaCoordinate<'a>, ArgumentUsage>,
+}
+
+/// The [SchemaUsage] analyzer collects the schema coordinates of the elements of the schema that
+/// an operation references, with the number of times each one is referenced:
+/// - the root operation type, the output types of selected fields and the input types of
+///   variables, arguments and input fields
+/// - selected fields, and the arguments they are given
+/// - enum values and input fields used in literal values, including variable defaults
+/// - directives, and the arguments they are given
+///
+/// Fields and directives are counted whether or not they are excluded by `@skip` or `@include`,
+/// as the operation would no longer be valid without them, and fields of fragments are counted
+/// every time the fragment is spread. Variable values are not taken into account.
+pub struct SchemaUsage<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> {
+    schema_definition: &'a S,
+    cache: &'a Cache<'a, E, S>,
+    report: SchemaUsageReport<'a>,
+    variable_values: std::marker::PhantomData<VV>,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for SchemaUsage<'a, E, S, VV>
+{
+    type ExtraInfo = ();
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        _: &'a VV,
+        cache: &'a Cache<'a, E, S>,
+        _: Self::ExtraInfo,
+    ) -> Self {
+        let mut instance = Self {
+            schema_definition,
+            cache,
+            report: SchemaUsageReport::default(),
+            variable_values: std::marker::PhantomData,
+        };
+        let root_type = match operation_definition.as_ref().operation_type() {
+            OperationType::Query => Some(schema_definition.query()),
+            OperationType::Mutation => schema_definition.mutation(),
+            OperationType::Subscription => schema_definition.subscription(),
+        };
+        if let Some(root_type) = root_type {
+            instance.record(SchemaCoordinate::Type {
+                type_name: root_type.name(),
+            });
+        }
+        instance
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        field_definition: &'a S::FieldDefinition,
+        scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        _included: bool,
+    ) {
+        // meta-fields like `__typename` have no schema coordinate
+        if field_definition.name().starts_with("__") {
+            return;
+        }
+
+        let type_name = scoped_type.name();
+        let field_name = field_definition.name();
+        self.record(SchemaCoordinate::Field {
+            type_name,
+            field_name,
+        });
+        self.record(SchemaCoordinate::Type {
+            type_name: field_definition.r#type().base_name(),
+        });
+
+        if let Some((arguments, arguments_definition)) = field
+            .arguments()
+            .zip(field_definition.arguments_definition())
+        {
+            arguments.iter().for_each(|argument| {
+                if let Some(input_value_definition) = arguments_definition.get(argument.name()) {
+                    self.record_argument::<false>(
+                        SchemaCoordinate::Argument {
+                            type_name,
+                            field_name,
+                            argument_name: input_value_definition.name(),
+                        },
+                        argument,
+                        input_value_definition,
+                    );
+                }
+            });
+        }
+    }
+
+    fn visit_variable_directive(
+        &mut self,
+        directive: &'a <E as ExecutableDocument>::Directive<false>,
+        _location: DirectiveLocation,
+        _included: bool,
+    ) {
+        self.record_directive::<false>(directive);
+    }
+
+    fn visit_variable_definition(
+        &mut self,
+        variable_definition: &'a <E as ExecutableDocument>::VariableDefinition,
+    ) {
+        self.record(SchemaCoordinate::Type {
+            type_name: variable_definition.r#type().as_ref().name(),
+        });
+        if let Some(directives) = variable_definition.directives() {
+            directives
+                .iter()
+                .for_each(|directive| self.record_directive::<true>(directive));
+        }
+        if let Some((input_type, default_value)) = self
+            .cache
+            .variable_definition_input_type(variable_definition.r#type())
+            .zip(variable_definition.default_value())
+        {
+            self.record_value(input_type, default_value);
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> SchemaUsage<'a, E, S, VV> {
+    fn record(&mut self, coordinate: SchemaCoordinate<'a>) {
+        *self.report.coordinates.entry(coordinate).or_default() += 1;
+    }
+
+    fn record_directive<const CONST: bool>(&mut self, directive: &'a E::Directive<CONST>) {
+        let Some(directive_definition) = self
+            .schema_definition
+            .get_directive_definition(directive.name())
+        else {
+            return;
+        };
+        let directive_name = directive_definition.name();
+        self.record(SchemaCoordinate::Directive { directive_name });
+
+        if let Some((arguments, arguments_definition)) = directive
+            .arguments()
+            .zip(directive_definition.arguments_definition())
+        {
+            arguments.iter().for_each(|argument| {
+                if let Some(input_value_definition) = arguments_definition.get(argument.name()) {
+                    self.record_argument::<CONST>(
+                        SchemaCoordinate::DirectiveArgument {
+                            directive_name,
+                            argument_name: input_value_definition.name(),
+                        },
+                        argument,
+                        input_value_definition,
+                    );
+                }
+            });
+        }
+    }
+
+    fn record_argument<const CONST: bool>(
+        &mut self,
+        coordinate: SchemaCoordinate<'a>,
+        argument: &'a E::Argument<CONST>,
+        input_value_definition: &'a S::InputValueDefinition,
+    ) {
+        self.record(coordinate);
+        let argument_usage = self.report.arguments.entry(coordinate).or_default();
+        if matches!(argument.value().as_ref(), ValueReference::Variable(_)) {
+            argument_usage.variable += 1;
+        } else {
+            argument_usage.literal += 1;
+        }
+        self.record_value(input_value_definition.r#type(), argument.value());
+    }
+
+    fn record_value<
+        const CONST: bool,
+        I: InputType<
+            CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
+            InputObjectTypeDefinition = S::InputObjectTypeDefinition,
+            EnumTypeDefinition = S::EnumTypeDefinition,
+        >,
+        V: Value<CONST>,
+    >(
+        &mut self,
+        input_type: &'a I,
+        value: &'a V,
+    ) {
+        match (input_type.as_ref(self.schema_definition), value.as_ref()) {
+            (_, ValueReference::Variable(_) | ValueReference::Null) => {}
+            (InputTypeReference::List(inner, _), ValueReference::List(items)) => {
+                items.iter().for_each(|item| self.record_value(inner, item))
+            }
+            (InputTypeReference::List(inner, _), _) => self.record_value(inner, value),
+            (InputTypeReference::Base(base, _), value_reference) => {
+                self.record(SchemaCoordinate::Type {
+                    type_name: base.name(),
+                });
+                match (base, value_reference) {
+                    (BaseInputTypeReference::Enum(etd), ValueReference::Enum(name)) => {
+                        self.record_enum_value(etd, name)
+                    }
+                    (BaseInputTypeReference::Enum(etd), ValueReference::String(name))
+                        if V::can_coerce_string_value_to_enum() =>
+                    {
+                        self.record_enum_value(etd, name)
+                    }
+                    (BaseInputTypeReference::InputObject(iotd), ValueReference::Object(object)) => {
+                        object.iter().for_each(|(key, value)| {
+                            if let Some(input_field_definition) =
+                                iotd.input_field_definitions().get(key.as_ref())
+                            {
+                                self.record(SchemaCoordinate::InputField {
+                                    type_name: iotd.name(),
+                                    field_name: input_field_definition.name(),
+                                });
+                                self.record_value(input_field_definition.r#type(), value);
+                            }
+                        })
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn record_enum_value(&mut self, enum_type_definition: &'a S::EnumTypeDefinition, name: &str) {
+        if let Some(enum_value_definition) = enum_type_definition
+            .enum_value_definitions()
+            .iter()
+            .find(|evd| evd.name() == name)
+        {
+            self.record(SchemaCoordinate::EnumValue {
+                type_name: enum_type_definition.name(),
+                value_name: enum_value_definition.name(),
+            });
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for SchemaUsage<'a, E, S, VV>
+{
+    type Output = SchemaUsageReport<'a>;
+
+    fn into_output(self) -> Self::Output {
+        self.report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArgumentUsage, SchemaUsage};
+    use crate::executable::{operation::Orchestrator, Cache};
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::{Map as JsonMap, Value as JsonValue};
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        directive @tag(name: String!) on FIELD | VARIABLE_DEFINITION
+
+        enum Role { ADMIN MEMBER }
+
+        input UserFilter {
+            role: Role
+            names: [String!]
+        }
+
+        type Query {
+            users(filter: UserFilter, first: Int): [User!]!
+        }
+
+        type User {
+            name: String!
+            role: Role!
+            friends(role: Role): [User!]!
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    /// The displayed coordinates with their counts, and the argument usages
+    type DisplayedUsage = (Vec<(String, usize)>, Vec<(String, ArgumentUsage)>);
+
+    fn usage(source: &str) -> DisplayedUsage {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let variables = JsonMap::new();
+        let report = Orchestrator::<
+            _,
+            _,
+            JsonMap<String, JsonValue>,
+            SchemaUsage<_, _, JsonMap<String, JsonValue>>,
+        >::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            &variables,
+            &cache,
+            (),
+        )
+        .unwrap();
+        (
+            report
+                .coordinates
+                .iter()
+                .map(|(coordinate, &count)| (coordinate.to_string(), count))
+                .collect(),
+            report
+                .arguments
+                .iter()
+                .map(|(coordinate, &usage)| (coordinate.to_string(), usage))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_schema_usage() {
+        let (coordinates, arguments) = usage(
+            r#"query($first: Int = 10, $role: Role @tag(name: "role")) {
+                users(filter: { role: ADMIN, names: ["a", "b"] }, first: $first) {
+                    __typename
+                    name @tag(name: "name")
+                    ...friendFields
+                    ...friendFields
+                }
+            }
+
+            fragment friendFields on User {
+                friends(role: $role) { role }
+            }"#,
+        );
+
+        assert_eq!(
+            vec![
+                ("Int".to_owned(), 2),
+                ("Query".to_owned(), 1),
+                ("Role".to_owned(), 4),
+                ("String".to_owned(), 5),
+                ("User".to_owned(), 3),
+                ("UserFilter".to_owned(), 1),
+                ("Query.users".to_owned(), 1),
+                ("User.friends".to_owned(), 2),
+                ("User.name".to_owned(), 1),
+                ("User.role".to_owned(), 2),
+                ("Query.users(filter:)".to_owned(), 1),
+                ("Query.users(first:)".to_owned(), 1),
+                ("User.friends(role:)".to_owned(), 2),
+                ("Role.ADMIN".to_owned(), 1),
+                ("UserFilter.names".to_owned(), 1),
+                ("UserFilter.role".to_owned(), 1),
+                ("@tag".to_owned(), 2),
+                ("@tag(name:)".to_owned(), 2),
+            ],
+            coordinates,
+        );
+        assert_eq!(
+            vec![
+                (
+                    "Query.users(filter:)".to_owned(),
+                    ArgumentUsage {
+                        literal: 1,
+                        variable: 0
+                    },
+                ),
+                (
+                    "Query.users(first:)".to_owned(),
+                    ArgumentUsage {
+                        literal: 0,
+                        variable: 1
+                    },
+                ),
+                (
+                    "User.friends(role:)".to_owned(),
+                    ArgumentUsage {
+                        literal: 0,
+                        variable: 2
+                    },
+                ),
+                (
+                    "@tag(name:)".to_owned(),
+                    ArgumentUsage {
+                        literal: 2,
+                        variable: 0
+                    },
+                ),
+            ],
+            arguments,
+        );
+    }
+}
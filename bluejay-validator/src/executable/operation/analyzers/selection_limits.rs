@@ -0,0 +1,381 @@
+use bluejay_core::definition::{ObjectTypeDefinition, SchemaDefinition};
+use bluejay_core::executable::{ExecutableDocument, OperationDefinition};
+use bluejay_core::OperationType;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+mod alias_count;
+pub use alias_count::AliasCount;
+
+mod directive_count;
+pub use directive_count::DirectiveCount;
+
+mod field_count;
+pub use field_count::{TotalFieldCount, UniqueFieldCount};
+
+mod fragment_spread_count;
+pub use fragment_spread_count::FragmentSpreadCount;
+
+mod root_field_count;
+pub use root_field_count::RootFieldCount;
+
+/// The offenders of the selection limit analyzers are the paths in the operation that
+/// contributed to a count exceeding its limit, along with their share of the count
+pub use super::Offender;
+
+/// The output of the selection limit analyzers. Only selections included
+/// in the response are counted, and fragments are counted every time they are spread.
+/// When the count exceeds the limit passed as extra info, the offenders that
+/// contributed to it are listed, largest first.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct LimitReport {
+    pub count: usize,
+    pub offenders: Vec<Offender>,
+}
+
+impl LimitReport {
+    pub fn exceeds_limit(&self) -> bool {
+        !self.offenders.is_empty()
+    }
+}
+
+/// Accumulates a count, grouped by the path that contributed to it
+pub(super) struct Counter {
+    limit: usize,
+    count: usize,
+    sizes: Vec<(String, usize)>,
+    indices: HashMap<String, usize>,
+}
+
+impl Counter {
+    pub(super) fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            count: 0,
+            sizes: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    pub(super) fn increment(&mut self, name: String) {
+        self.count += 1;
+        match self.indices.get(&name) {
+            Some(&index) => self.sizes[index].1 += 1,
+            None => {
+                self.indices.insert(name.clone(), self.sizes.len());
+                self.sizes.push((name, 1));
+            }
+        }
+    }
+
+    pub(super) fn into_report(self) -> LimitReport {
+        let Self {
+            limit,
+            count,
+            mut sizes,
+            ..
+        } = self;
+
+        if count <= limit {
+            return LimitReport {
+                count,
+                offenders: Vec::new(),
+            };
+        }
+
+        sizes.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        LimitReport {
+            count,
+            offenders: sizes
+                .into_iter()
+                .map(|(name, size)| Offender { size, name })
+                .collect(),
+        }
+    }
+}
+
+/// Tracks the response path of the field being visited, rooted at the name
+/// of the root operation type, e.g. `Query.user.friends`
+pub(super) struct ResponsePath<'a> {
+    root: &'a str,
+    response_keys: Vec<&'a str>,
+}
+
+impl<'a> ResponsePath<'a> {
+    pub(super) fn new<E: ExecutableDocument, S: SchemaDefinition>(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+    ) -> Self {
+        let root = match operation_definition.as_ref().operation_type() {
+            OperationType::Query => Some(schema_definition.query().name()),
+            OperationType::Mutation => schema_definition.mutation().map(ObjectTypeDefinition::name),
+            OperationType::Subscription => schema_definition
+                .subscription()
+                .map(ObjectTypeDefinition::name),
+        };
+
+        Self {
+            root: root.unwrap_or_default(),
+            response_keys: Vec::new(),
+        }
+    }
+
+    pub(super) fn push(&mut self, response_key: &'a str) {
+        self.response_keys.push(response_key);
+    }
+
+    pub(super) fn pop(&mut self) {
+        self.response_keys.pop();
+    }
+
+    pub(super) fn is_root(&self) -> bool {
+        self.response_keys.is_empty()
+    }
+
+    /// The path of a child of the current path
+    pub(super) fn child(&self, name: &str) -> String {
+        format!("{self}.{name}")
+    }
+}
+
+impl Display for ResponsePath<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.root)?;
+        self.response_keys
+            .iter()
+            .try_for_each(|response_key| write!(f, ".{response_key}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AliasCount, DirectiveCount, FragmentSpreadCount, LimitReport, Offender, RootFieldCount,
+        TotalFieldCount, UniqueFieldCount,
+    };
+    use crate::executable::{operation::Orchestrator, Cache};
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        directive @tag(name: String!) repeatable on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT | QUERY
+
+        type Query {
+            user(id: ID!): User
+            node(id: ID!): User
+            ping: String!
+        }
+
+        type User {
+            id: ID!
+            name: String!
+            friends: [User!]!
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    /// Reports of, in order: aliases, total fields, unique fields, root fields,
+    /// fragment spreads and directives
+    type Reports = (
+        LimitReport,
+        LimitReport,
+        LimitReport,
+        LimitReport,
+        LimitReport,
+        LimitReport,
+    );
+
+    fn analyze(source: &str, variables: JsonValue, limit: usize) -> Reports {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        Orchestrator::<
+            _,
+            _,
+            JsonMap<String, JsonValue>,
+            (
+                AliasCount,
+                TotalFieldCount,
+                UniqueFieldCount,
+                RootFieldCount,
+                FragmentSpreadCount,
+                DirectiveCount,
+            ),
+        >::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variables.as_object().expect("Variables must be an object"),
+            &cache,
+            (limit, limit, limit, limit, limit, limit),
+        )
+        .unwrap()
+    }
+
+    fn offender(name: &str, size: usize) -> Offender {
+        Offender {
+            size,
+            name: name.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_within_limits() {
+        let (aliases, total_fields, unique_fields, root_fields, fragment_spreads, directives) =
+            analyze("{ a: ping user(id: 1) { name } }", json!({}), 10);
+
+        assert_eq!(
+            LimitReport {
+                count: 1,
+                offenders: vec![],
+            },
+            aliases,
+        );
+        assert_eq!(3, total_fields.count);
+        assert_eq!(3, unique_fields.count);
+        assert_eq!(2, root_fields.count);
+        assert_eq!(0, fragment_spreads.count);
+        assert_eq!(0, directives.count);
+        assert!(!total_fields.exceeds_limit());
+    }
+
+    #[test]
+    fn test_aliases() {
+        let (aliases, ..) = analyze(
+            r#"{
+                user(id: 1) { a: name b: name c: id }
+                p: ping
+            }"#,
+            json!({}),
+            2,
+        );
+
+        assert_eq!(
+            LimitReport {
+                count: 4,
+                offenders: vec![
+                    offender("Query.user.name", 2),
+                    offender("Query.user.id", 1),
+                    offender("Query.ping", 1)
+                ],
+            },
+            aliases,
+        );
+    }
+
+    #[test]
+    fn test_field_counts() {
+        let (_, total_fields, unique_fields, ..) = analyze(
+            r#"{
+                user(id: 1) { name name friends { id } ...userFields }
+                ping
+            }
+            fragment userFields on User { name id }"#,
+            json!({}),
+            3,
+        );
+
+        assert_eq!(
+            LimitReport {
+                count: 8,
+                offenders: vec![
+                    offender("Query.user", 5),
+                    offender("Query", 2),
+                    offender("Query.user.friends", 1)
+                ],
+            },
+            total_fields,
+        );
+        assert_eq!(
+            LimitReport {
+                count: 6,
+                offenders: vec![
+                    offender("Query.user", 3),
+                    offender("Query", 2),
+                    offender("Query.user.friends", 1)
+                ],
+            },
+            unique_fields,
+        );
+    }
+
+    #[test]
+    fn test_root_fields() {
+        let (.., root_fields, _, _) = analyze(
+            r#"query($skip: Boolean!) {
+                a: node(id: 1) { id }
+                b: node(id: 2) { id }
+                c: node(id: 3) @skip(if: $skip) { id }
+                ... { ping }
+            }"#,
+            json!({ "skip": true }),
+            2,
+        );
+
+        assert_eq!(
+            LimitReport {
+                count: 3,
+                offenders: vec![offender("Query.node", 2), offender("Query.ping", 1)],
+            },
+            root_fields,
+        );
+    }
+
+    #[test]
+    fn test_fragment_spreads_after_expansion() {
+        let (.., fragment_spreads, _) = analyze(
+            r#"{
+                user(id: 1) { ...friendFields friends { ...friendFields } }
+            }
+            fragment friendFields on User { ...nameFields }
+            fragment nameFields on User { name }"#,
+            json!({}),
+            3,
+        );
+
+        assert_eq!(
+            LimitReport {
+                count: 4,
+                offenders: vec![offender("Query.user", 2), offender("Query.user.friends", 2)],
+            },
+            fragment_spreads,
+        );
+    }
+
+    #[test]
+    fn test_directives() {
+        let (.., directives) = analyze(
+            r#"query @tag(name: "op") {
+                user(id: 1) @tag(name: "a") @tag(name: "b") {
+                    name @tag(name: "c")
+                    ... @tag(name: "d") { id }
+                }
+                ping @skip(if: true) @tag(name: "e")
+            }"#,
+            json!({}),
+            4,
+        );
+
+        assert_eq!(
+            LimitReport {
+                count: 5,
+                offenders: vec![
+                    offender("Query.user@tag", 3),
+                    offender("Query@tag", 1),
+                    offender("Query.user.name@tag", 1),
+                ],
+            },
+            directives,
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use super::{Counter, LimitReport, ResponsePath};
+use crate::executable::{
+    operation::{Analyzer, VariableValues, Visitor},
+    Cache,
+};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field};
+
+/// The [AliasCount] analyzer counts the aliased fields of an operation.
+/// Offenders are the field paths that were aliased, e.g. `Query.user.friends`
+/// when `friends` is selected under several aliases.
+pub struct AliasCount<'a> {
+    path: ResponsePath<'a>,
+    counter: Counter,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for AliasCount<'a>
+{
+    type ExtraInfo = usize;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        _: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        max_aliases: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            path: ResponsePath::new::<E, S>(operation_definition, schema_definition),
+            counter: Counter::new(max_aliases),
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            if field.alias().is_some() {
+                self.counter.increment(self.path.child(field.name()));
+            }
+            self.path.push(field.response_name());
+        }
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.path.pop();
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for AliasCount<'a>
+{
+    type Output = LimitReport;
+
+    fn into_output(self) -> Self::Output {
+        self.counter.into_report()
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Counter, LimitReport, ResponsePath};
+use crate::executable::{
+    operation::{Analyzer, VariableValues, Visitor},
+    Cache,
+};
+use bluejay_core::definition::{DirectiveLocation, SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use bluejay_core::{AsIter, Directive};
+
+/// The [DirectiveCount] analyzer counts the directives applied to the operation and to its
+/// fields, fragment spreads and inline fragments.
+/// Offenders are the paths the directives are applied to, suffixed with the directive name,
+/// e.g. `Query.user@include`. Directives on fragment spreads and inline fragments are
+/// attributed to the path of the enclosing selection set.
+pub struct DirectiveCount<'a> {
+    path: ResponsePath<'a>,
+    counter: Counter,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for DirectiveCount<'a>
+{
+    type ExtraInfo = usize;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        _: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        max_directives: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            path: ResponsePath::new::<E, S>(operation_definition, schema_definition),
+            counter: Counter::new(max_directives),
+        }
+    }
+
+    fn visit_variable_directive(
+        &mut self,
+        directive: &'a <E as ExecutableDocument>::Directive<false>,
+        location: DirectiveLocation,
+        included: bool,
+    ) {
+        // field directives are counted when visiting the field, so they are attributed to its path
+        if included && location != DirectiveLocation::Field {
+            self.counter
+                .increment(format!("{}@{}", self.path, directive.name()));
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.path.push(field.response_name());
+            if let Some(directives) = field.directives() {
+                directives.iter().for_each(|directive| {
+                    self.counter
+                        .increment(format!("{}@{}", self.path, directive.name()))
+                });
+            }
+        }
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.path.pop();
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for DirectiveCount<'a>
+{
+    type Output = LimitReport;
+
+    fn into_output(self) -> Self::Output {
+        self.counter.into_report()
+    }
+}
@@ -0,0 +1,139 @@
+use super::{Counter, LimitReport, ResponsePath};
+use crate::executable::{
+    operation::{Analyzer, VariableValues, Visitor},
+    Cache,
+};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field};
+use std::collections::HashSet;
+
+/// The [TotalFieldCount] analyzer counts every field selection of an operation,
+/// including selections repeated within a selection set or through fragments.
+/// Offenders are the paths of the selection sets containing the fields, e.g. `Query.user`.
+pub struct TotalFieldCount<'a> {
+    path: ResponsePath<'a>,
+    counter: Counter,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for TotalFieldCount<'a>
+{
+    type ExtraInfo = usize;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        _: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        max_fields: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            path: ResponsePath::new::<E, S>(operation_definition, schema_definition),
+            counter: Counter::new(max_fields),
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.counter.increment(self.path.to_string());
+            self.path.push(field.response_name());
+        }
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.path.pop();
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for TotalFieldCount<'a>
+{
+    type Output = LimitReport;
+
+    fn into_output(self) -> Self::Output {
+        self.counter.into_report()
+    }
+}
+
+/// The [UniqueFieldCount] analyzer counts the distinct response paths of an operation,
+/// i.e. the fields that remain after selections with the same response key are merged.
+/// Offenders are the paths of the selection sets containing the fields, e.g. `Query.user`.
+pub struct UniqueFieldCount<'a> {
+    path: ResponsePath<'a>,
+    visited_paths: HashSet<String>,
+    counter: Counter,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for UniqueFieldCount<'a>
+{
+    type ExtraInfo = usize;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        _: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        max_fields: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            path: ResponsePath::new::<E, S>(operation_definition, schema_definition),
+            visited_paths: HashSet::new(),
+            counter: Counter::new(max_fields),
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            if self
+                .visited_paths
+                .insert(self.path.child(field.response_name()))
+            {
+                self.counter.increment(self.path.to_string());
+            }
+            self.path.push(field.response_name());
+        }
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.path.pop();
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for UniqueFieldCount<'a>
+{
+    type Output = LimitReport;
+
+    fn into_output(self) -> Self::Output {
+        self.counter.into_report()
+    }
+}
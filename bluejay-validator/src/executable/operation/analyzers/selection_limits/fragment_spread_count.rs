@@ -0,0 +1,78 @@
+use super::{Counter, LimitReport, ResponsePath};
+use crate::executable::{
+    operation::{Analyzer, VariableValues, Visitor},
+    Cache,
+};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field};
+
+/// The [FragmentSpreadCount] analyzer counts the fragment spreads of an operation after
+/// expansion, so a spread within a fragment is counted every time that fragment is spread.
+/// Offenders are the paths of the selection sets the fragments are spread in, e.g. `Query.user`.
+pub struct FragmentSpreadCount<'a> {
+    path: ResponsePath<'a>,
+    counter: Counter,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for FragmentSpreadCount<'a>
+{
+    type ExtraInfo = usize;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        _: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        max_fragment_spreads: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            path: ResponsePath::new::<E, S>(operation_definition, schema_definition),
+            counter: Counter::new(max_fragment_spreads),
+        }
+    }
+
+    fn visit_fragment_spread(
+        &mut self,
+        _fragment_spread: &'a <E as ExecutableDocument>::FragmentSpread,
+        included: bool,
+    ) {
+        if included {
+            self.counter.increment(self.path.to_string());
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.path.push(field.response_name());
+        }
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.path.pop();
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for FragmentSpreadCount<'a>
+{
+    type Output = LimitReport;
+
+    fn into_output(self) -> Self::Output {
+        self.counter.into_report()
+    }
+}
@@ -0,0 +1,71 @@
+use super::{Counter, LimitReport, ResponsePath};
+use crate::executable::{
+    operation::{Analyzer, VariableValues, Visitor},
+    Cache,
+};
+use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::executable::{ExecutableDocument, Field};
+
+/// The [RootFieldCount] analyzer counts the root field selections of an operation.
+/// Offenders are the root fields, e.g. `Query.node` when `node` is selected
+/// under many aliases.
+pub struct RootFieldCount<'a> {
+    path: ResponsePath<'a>,
+    counter: Counter,
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Visitor<'a, E, S, VV>
+    for RootFieldCount<'a>
+{
+    type ExtraInfo = usize;
+
+    fn new(
+        operation_definition: &'a E::OperationDefinition,
+        schema_definition: &'a S,
+        _: &'a VV,
+        _: &'a Cache<'a, E, S>,
+        max_root_fields: Self::ExtraInfo,
+    ) -> Self {
+        Self {
+            path: ResponsePath::new::<E, S>(operation_definition, schema_definition),
+            counter: Counter::new(max_root_fields),
+        }
+    }
+
+    fn visit_field(
+        &mut self,
+        field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            if self.path.is_root() {
+                self.counter.increment(self.path.child(field.name()));
+            }
+            self.path.push(field.response_name());
+        }
+    }
+
+    fn leave_field(
+        &mut self,
+        _field: &'a <E as ExecutableDocument>::Field,
+        _field_definition: &'a S::FieldDefinition,
+        _scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+        included: bool,
+    ) {
+        if included {
+            self.path.pop();
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument, S: SchemaDefinition, VV: VariableValues> Analyzer<'a, E, S, VV>
+    for RootFieldCount<'a>
+{
+    type Output = LimitReport;
+
+    fn into_output(self) -> Self::Output {
+        self.counter.into_report()
+    }
+}
@@ -0,0 +1,531 @@
+use crate::executable::operation::VariableValues;
+#[cfg(feature = "serde_json")]
+use bluejay_core::definition::{BaseOutputTypeReference, UnionMemberType};
+use bluejay_core::definition::{
+    InterfaceImplementation, ObjectTypeDefinition, SchemaDefinition, TypeDefinitionReference,
+    UnionMemberTypes, UnionTypeDefinition,
+};
+use bluejay_core::executable::{
+    ExecutableDocument, Field, FragmentDefinition, FragmentSpread, InlineFragment, Selection,
+    SelectionReference,
+};
+use bluejay_core::{AsIter, Indexed, SelectionInclusion, Value};
+#[cfg(feature = "serde_json")]
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::collections::{HashMap, HashSet};
+
+pub(crate) const TYPENAME_FIELD_NAME: &str = "__typename";
+
+/// The result of the CollectFields algorithm of the specification: the fields of a selection
+/// set grouped by response key, in the order that each response key first appears.
+pub struct GroupedFieldSet<'a, E: ExecutableDocument> {
+    fields: Vec<(&'a str, Vec<&'a E::Field>)>,
+    indices: HashMap<&'a str, usize>,
+}
+
+impl<E: ExecutableDocument> Clone for GroupedFieldSet<'_, E> {
+    fn clone(&self) -> Self {
+        Self {
+            fields: self.fields.clone(),
+            indices: self.indices.clone(),
+        }
+    }
+}
+
+impl<'a, E: ExecutableDocument> GroupedFieldSet<'a, E> {
+    fn new() -> Self {
+        Self {
+            fields: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    fn push(&mut self, field: &'a E::Field) {
+        let response_key = field.response_name();
+        match self.indices.get(response_key) {
+            Some(&index) => self.fields[index].1.push(field),
+            None => {
+                self.indices.insert(response_key, self.fields.len());
+                self.fields.push((response_key, vec![field]));
+            }
+        }
+    }
+
+    /// The fields with the given response key, if any
+    pub fn get(&self, response_key: &str) -> Option<&[&'a E::Field]> {
+        self.indices
+            .get(response_key)
+            .map(|&index| self.fields[index].1.as_slice())
+    }
+
+    /// Iterates over the response keys and their fields, in order
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &[&'a E::Field])> {
+        self.fields
+            .iter()
+            .map(|(response_key, fields)| (*response_key, fields.as_slice()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+/// Runs the CollectFields algorithm of the specification, caching the grouped field set of
+/// each (selection set, object type) pair so that selection sets shared through fragments are
+/// only collected once.
+///
+/// `@skip` and `@include` are evaluated against `variable_values`, which should be the coerced
+/// variable values of the operation (see
+/// [`CoerceVariableValues`](crate::executable::operation::analyzers::CoerceVariableValues))
+/// so that variable defaults are taken into account.
+pub struct FieldCollector<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, VV: VariableValues> {
+    selection_set_collector: SelectionSetCollector<'a, E, S, VV>,
+    grouped_field_sets: HashMap<(Indexed<'a, E::SelectionSet>, &'a str), GroupedFieldSet<'a, E>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, VV: VariableValues>
+    FieldCollector<'a, E, S, VV>
+{
+    pub fn new(
+        executable_document: &'a E,
+        schema_definition: &'a S,
+        variable_values: &'a VV,
+    ) -> Self {
+        Self {
+            selection_set_collector: SelectionSetCollector::new(
+                executable_document,
+                schema_definition,
+                variable_values,
+            ),
+            grouped_field_sets: HashMap::new(),
+        }
+    }
+
+    /// Returns the grouped field set of `selection_set` for `object_type`
+    pub fn collect_fields(
+        &mut self,
+        selection_set: &'a E::SelectionSet,
+        object_type: &'a S::ObjectTypeDefinition,
+    ) -> &GroupedFieldSet<'a, E> {
+        let selection_set_collector = &self.selection_set_collector;
+        self.grouped_field_sets
+            .entry((Indexed(selection_set), object_type.name()))
+            .or_insert_with(|| selection_set_collector.collect_fields(selection_set, object_type))
+    }
+
+    /// Runs the CollectSubfields algorithm of the specification, merging the grouped field sets
+    /// of the selection sets of `fields`, which must all share the same response key
+    pub fn collect_subfields(
+        &mut self,
+        object_type: &'a S::ObjectTypeDefinition,
+        fields: &[&'a E::Field],
+    ) -> GroupedFieldSet<'a, E> {
+        let mut grouped_field_set = GroupedFieldSet::new();
+        fields.iter().for_each(|field| {
+            if let Some(selection_set) = field.selection_set() {
+                self.collect_fields(selection_set, object_type)
+                    .iter()
+                    .for_each(|(_, fields)| {
+                        fields
+                            .iter()
+                            .copied()
+                            .for_each(|field| grouped_field_set.push(field))
+                    });
+            }
+        });
+        grouped_field_set
+    }
+}
+
+/// Why [`FieldCollector::resolve_object_type`] could not resolve the object type of a response
+/// object
+#[cfg(feature = "serde_json")]
+pub(crate) enum ObjectTypeResolutionError<'b> {
+    /// The `__typename` of the object is not a possible type
+    TypenameNotPossibleType(&'b str),
+    /// `__typename` is not in the object and no possible type selects exactly its keys
+    Unresolved,
+}
+
+#[cfg(feature = "serde_json")]
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, VV: VariableValues>
+    FieldCollector<'a, E, S, VV>
+{
+    /// Resolves the object type of a response `object` for a value of type `base`, along with
+    /// the grouped field set of `fields` for that type. For an abstract type, this is the
+    /// possible type named by the `__typename` of the object, or without one, the first possible
+    /// type whose response keys are exactly the keys of the object.
+    pub(crate) fn resolve_object_type<'b>(
+        &mut self,
+        base: BaseOutputTypeReference<'a, S::OutputType>,
+        fields: &[&'a E::Field],
+        object: &'b JsonMap<String, JsonValue>,
+    ) -> Result<(&'a S::ObjectTypeDefinition, GroupedFieldSet<'a, E>), ObjectTypeResolutionError<'b>>
+    {
+        let schema_definition = self.selection_set_collector.schema_definition;
+        let possible_types: Vec<&'a S::ObjectTypeDefinition> = match base {
+            BaseOutputTypeReference::Object(otd) => {
+                return Ok((otd, self.collect_subfields(otd, fields)));
+            }
+            BaseOutputTypeReference::Interface(itd) => {
+                schema_definition.get_interface_implementors(itd).collect()
+            }
+            BaseOutputTypeReference::Union(utd) => utd
+                .union_member_types()
+                .iter()
+                .map(|union_member| union_member.member_type(schema_definition))
+                .collect(),
+            BaseOutputTypeReference::BuiltinScalar(_)
+            | BaseOutputTypeReference::CustomScalar(_)
+            | BaseOutputTypeReference::Enum(_) => Vec::new(),
+        };
+
+        match object.get(TYPENAME_FIELD_NAME).and_then(JsonValue::as_str) {
+            Some(typename) => possible_types
+                .into_iter()
+                .find(|object_type| object_type.name() == typename)
+                .map(|object_type| (object_type, self.collect_subfields(object_type, fields)))
+                .ok_or(ObjectTypeResolutionError::TypenameNotPossibleType(typename)),
+            None => possible_types
+                .into_iter()
+                .find_map(|object_type| {
+                    let grouped_field_set = self.collect_subfields(object_type, fields);
+                    (grouped_field_set.len() == object.len()
+                        && object
+                            .keys()
+                            .all(|response_key| grouped_field_set.get(response_key).is_some()))
+                    .then_some((object_type, grouped_field_set))
+                })
+                .ok_or(ObjectTypeResolutionError::Unresolved),
+        }
+    }
+}
+
+struct SelectionSetCollector<
+    'a,
+    E: ExecutableDocument + 'a,
+    S: SchemaDefinition,
+    VV: VariableValues,
+> {
+    schema_definition: &'a S,
+    variable_values: &'a VV,
+    fragment_definitions: HashMap<&'a str, &'a E::FragmentDefinition>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, VV: VariableValues>
+    SelectionSetCollector<'a, E, S, VV>
+{
+    fn new(executable_document: &'a E, schema_definition: &'a S, variable_values: &'a VV) -> Self {
+        Self {
+            schema_definition,
+            variable_values,
+            fragment_definitions: executable_document
+                .fragment_definitions()
+                .map(|fragment_definition| (fragment_definition.name(), fragment_definition))
+                .collect(),
+        }
+    }
+
+    fn collect_fields(
+        &self,
+        selection_set: &'a E::SelectionSet,
+        object_type: &'a S::ObjectTypeDefinition,
+    ) -> GroupedFieldSet<'a, E> {
+        let mut grouped_field_set = GroupedFieldSet::new();
+        self.collect_fields_into(
+            selection_set,
+            object_type,
+            &mut HashSet::new(),
+            &mut grouped_field_set,
+        );
+        grouped_field_set
+    }
+
+    fn collect_fields_into(
+        &self,
+        selection_set: &'a E::SelectionSet,
+        object_type: &'a S::ObjectTypeDefinition,
+        visited_fragments: &mut HashSet<&'a str>,
+        grouped_field_set: &mut GroupedFieldSet<'a, E>,
+    ) {
+        selection_set
+            .iter()
+            .for_each(|selection| match selection.as_ref() {
+                SelectionReference::Field(field) => {
+                    if self.is_included(field.directives()) {
+                        grouped_field_set.push(field);
+                    }
+                }
+                SelectionReference::FragmentSpread(fragment_spread) => {
+                    let fragment_name = fragment_spread.name();
+                    if !self.is_included(fragment_spread.directives())
+                        || !visited_fragments.insert(fragment_name)
+                    {
+                        return;
+                    }
+                    if let Some(fragment_definition) = self.fragment_definitions.get(fragment_name)
+                    {
+                        if self.does_fragment_type_apply(
+                            object_type,
+                            fragment_definition.type_condition(),
+                        ) {
+                            self.collect_fields_into(
+                                fragment_definition.selection_set(),
+                                object_type,
+                                visited_fragments,
+                                grouped_field_set,
+                            );
+                        }
+                    }
+                }
+                SelectionReference::InlineFragment(inline_fragment) => {
+                    if !self.is_included(inline_fragment.directives()) {
+                        return;
+                    }
+                    if inline_fragment
+                        .type_condition()
+                        .is_none_or(|type_condition| {
+                            self.does_fragment_type_apply(object_type, type_condition)
+                        })
+                    {
+                        self.collect_fields_into(
+                            inline_fragment.selection_set(),
+                            object_type,
+                            visited_fragments,
+                            grouped_field_set,
+                        );
+                    }
+                }
+            });
+    }
+
+    fn does_fragment_type_apply(
+        &self,
+        object_type: &'a S::ObjectTypeDefinition,
+        fragment_type_name: &str,
+    ) -> bool {
+        match self
+            .schema_definition
+            .get_type_definition(fragment_type_name)
+        {
+            Some(TypeDefinitionReference::Object(otd)) => otd.name() == object_type.name(),
+            Some(TypeDefinitionReference::Interface(_)) => object_type
+                .interface_implementations()
+                .is_some_and(|interface_implementations| {
+                    interface_implementations
+                        .iter()
+                        .any(|interface_implementation| {
+                            interface_implementation.name() == fragment_type_name
+                        })
+                }),
+            Some(TypeDefinitionReference::Union(utd)) => {
+                utd.union_member_types().contains_type(object_type.name())
+            }
+            _ => false,
+        }
+    }
+
+    fn is_included(&self, directives: Option<&'a E::Directives<false>>) -> bool {
+        !SelectionInclusion::evaluate(directives, |variable| {
+            self.variable_values
+                .get(variable)
+                .and_then(|value| value.as_ref().as_boolean().copied())
+        })
+        .is_excluded()
+    }
+}
+
+/// Runs the CollectFields algorithm of the specification for a single selection set. Use a
+/// [`FieldCollector`] to cache the results when collecting many selection sets of the same
+/// document.
+pub fn collect_fields<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, VV: VariableValues>(
+    schema_definition: &'a S,
+    executable_document: &'a E,
+    selection_set: &'a E::SelectionSet,
+    object_type: &'a S::ObjectTypeDefinition,
+    variable_values: &'a VV,
+) -> GroupedFieldSet<'a, E> {
+    SelectionSetCollector::new(executable_document, schema_definition, variable_values)
+        .collect_fields(selection_set, object_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect_fields, FieldCollector};
+    use bluejay_core::definition::{
+        SchemaDefinition as CoreSchemaDefinition, TypeDefinitionReference,
+    };
+    use bluejay_core::executable::{OperationDefinition, Selection, SelectionReference};
+    use bluejay_core::AsIter;
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::json;
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        type Query {
+            pet: Pet
+        }
+
+        interface Pet {
+            name: String!
+        }
+
+        type Dog implements Pet {
+            name: String!
+            barkVolume: Int
+            owner: Human
+        }
+
+        type Cat implements Pet {
+            name: String!
+            meowVolume: Int
+        }
+
+        type Human {
+            name: String!
+            age: Int
+        }
+
+        union CatOrDog = Cat | Dog
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    fn object_type(
+        name: &str,
+    ) -> &'static <SchemaDefinition<'static> as CoreSchemaDefinition>::ObjectTypeDefinition {
+        match TEST_SCHEMA_DEFINITION.get_type_definition(name) {
+            Some(TypeDefinitionReference::Object(otd)) => otd,
+            _ => panic!("No object type named {name}"),
+        }
+    }
+
+    const SOURCE: &str = r#"
+        query($withOwner: Boolean!) {
+            pet {
+                name
+                ... on Dog {
+                    barkVolume
+                    owner @include(if: $withOwner) { name }
+                }
+                ... on Cat { meowVolume }
+                ...catOrDogFields
+                alias: name
+                ... @skip(if: true) { skipped: name }
+            }
+        }
+
+        fragment catOrDogFields on CatOrDog {
+            ... on Dog { name owner { age } }
+            ...catOrDogFields
+        }
+    "#;
+
+    fn grouped_response_keys(
+        object_type_name: &str,
+        variables: serde_json::Value,
+    ) -> Vec<(String, usize)> {
+        let executable_document = ExecutableDocument::parse(SOURCE).result.unwrap();
+        let operation_definition = executable_document.operation_definitions().first().unwrap();
+        let pet_field = match operation_definition
+            .as_ref()
+            .selection_set()
+            .iter()
+            .next()
+            .unwrap()
+            .as_ref()
+        {
+            SelectionReference::Field(field) => field,
+            _ => unreachable!(),
+        };
+        collect_fields(
+            &*TEST_SCHEMA_DEFINITION,
+            &executable_document,
+            pet_field.selection_set().unwrap(),
+            object_type(object_type_name),
+            variables.as_object().unwrap(),
+        )
+        .iter()
+        .map(|(response_key, fields)| (response_key.to_owned(), fields.len()))
+        .collect()
+    }
+
+    #[test]
+    fn test_collect_fields() {
+        assert_eq!(
+            vec![
+                ("name".to_owned(), 2),
+                ("barkVolume".to_owned(), 1),
+                ("owner".to_owned(), 2),
+                ("alias".to_owned(), 1),
+            ],
+            grouped_response_keys("Dog", json!({ "withOwner": true })),
+        );
+        assert_eq!(
+            vec![
+                ("name".to_owned(), 2),
+                ("barkVolume".to_owned(), 1),
+                ("owner".to_owned(), 1),
+                ("alias".to_owned(), 1),
+            ],
+            grouped_response_keys("Dog", json!({ "withOwner": false })),
+        );
+        assert_eq!(
+            vec![
+                ("name".to_owned(), 1),
+                ("meowVolume".to_owned(), 1),
+                ("alias".to_owned(), 1),
+            ],
+            grouped_response_keys("Cat", json!({ "withOwner": true })),
+        );
+    }
+
+    #[test]
+    fn test_field_collector_caches_and_collects_subfields() {
+        let executable_document = ExecutableDocument::parse(SOURCE).result.unwrap();
+        let variables = json!({ "withOwner": true });
+        let variables = variables.as_object().unwrap();
+        let mut field_collector =
+            FieldCollector::new(&executable_document, &*TEST_SCHEMA_DEFINITION, variables);
+        let operation_definition = executable_document.operation_definitions().first().unwrap();
+        let query = TEST_SCHEMA_DEFINITION.query();
+
+        let root_fields =
+            field_collector.collect_fields(operation_definition.as_ref().selection_set(), query);
+        assert_eq!(1, root_fields.len());
+        let pet_fields = root_fields.get("pet").unwrap().to_vec();
+        let cached_root_fields: *const _ =
+            field_collector.collect_fields(operation_definition.as_ref().selection_set(), query);
+        assert!(std::ptr::eq(
+            cached_root_fields,
+            field_collector.collect_fields(operation_definition.as_ref().selection_set(), query),
+        ));
+
+        let dog_fields = field_collector.collect_subfields(object_type("Dog"), &pet_fields);
+        let owner_fields = dog_fields.get("owner").unwrap().to_vec();
+        assert_eq!(2, owner_fields.len());
+
+        let owner_subfields =
+            field_collector.collect_subfields(object_type("Human"), &owner_fields);
+        assert_eq!(
+            vec!["name", "age"],
+            owner_subfields
+                .iter()
+                .map(|(response_key, _)| response_key)
+                .collect::<Vec<_>>(),
+        );
+        assert!(owner_fields.iter().all(|field| field.name() == "owner"));
+    }
+}
@@ -2,6 +2,10 @@ use crate::executable::{
     operation::{Analyzer, OperationDefinitionValueEvaluationExt, VariableValues, Visitor},
     Cache,
 };
+use bluejay_core::definition::{
+    FieldDefinition, FieldsDefinition, ObjectTypeDefinition, OutputType, SchemaDefinition,
+    TypeDefinitionReference,
+};
 use bluejay_core::{
     definition::{ArgumentsDefinition, DirectiveDefinition, DirectiveLocation},
     executable::{
@@ -9,14 +13,7 @@ use bluejay_core::{
         OperationDefinition, Selection, SelectionReference,
     },
 };
-use bluejay_core::{
-    definition::{
-        FieldDefinition, FieldsDefinition, ObjectTypeDefinition, OutputType, SchemaDefinition,
-        TypeDefinitionReference,
-    },
-    ValueReference,
-};
-use bluejay_core::{Argument, AsIter, Directive, OperationType, Value};
+use bluejay_core::{Argument, AsIter, Directive, OperationType, SelectionInclusion};
 use std::borrow::Cow;
 use std::collections::HashSet;
 
@@ -43,10 +40,6 @@ impl<
         V: Visitor<'a, E, S, VV>,
     > Orchestrator<'a, E, S, VV, V>
 {
-    const SKIP_DIRECTIVE_NAME: &'static str = "skip";
-    const INCLUDE_DIRECTIVE_NAME: &'static str = "include";
-    const SKIP_INCLUDE_CONDITION_ARGUMENT: &'static str = "if";
-
     fn new(
         operation_definition: &'a E::OperationDefinition,
         schema_definition: &'a S,
@@ -78,17 +71,22 @@ impl<
         &mut self,
         directives: &'a E::Directives<false>,
         location: DirectiveLocation,
+        included: bool,
     ) {
         directives
             .iter()
-            .for_each(|directive| self.visit_variable_directive(directive, location));
+            .for_each(|directive| self.visit_variable_directive(directive, location, included));
     }
 
     fn visit_variable_directive(
         &mut self,
         directive: &'a E::Directive<false>,
-        _location: DirectiveLocation,
+        location: DirectiveLocation,
+        included: bool,
     ) {
+        self.visitor
+            .visit_variable_directive(directive, location, included);
+
         if let Some(arguments) = directive.arguments() {
             if let Some(arguments_definition) = self
                 .schema_definition
@@ -121,6 +119,7 @@ impl<
                 core_operation_definition
                     .operation_type()
                     .associated_directive_location(),
+                true,
             )
         }
 
@@ -178,13 +177,13 @@ impl<
         owner_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         included: bool,
     ) {
-        if let Some(directives) = field.directives() {
-            self.visit_variable_directives(directives, DirectiveLocation::Field);
-        }
         let included = included
             && field
                 .directives()
                 .is_none_or(|directives| self.evaluate_selection_inclusion(directives));
+        if let Some(directives) = field.directives() {
+            self.visit_variable_directives(directives, DirectiveLocation::Field, included);
+        }
 
         self.visitor
             .visit_field(field, field_definition, owner_type, included);
@@ -239,14 +238,13 @@ impl<
         scoped_type: TypeDefinitionReference<'a, S::TypeDefinition>,
         included: bool,
     ) {
-        if let Some(directives) = inline_fragment.directives() {
-            self.visit_variable_directives(directives, DirectiveLocation::InlineFragment);
-        }
-
         let included = included
             && inline_fragment
                 .directives()
                 .is_none_or(|directives| self.evaluate_selection_inclusion(directives));
+        if let Some(directives) = inline_fragment.directives() {
+            self.visit_variable_directives(directives, DirectiveLocation::InlineFragment, included);
+        }
 
         let fragment_type = if let Some(type_condition) = inline_fragment.type_condition() {
             self.schema_definition.get_type_definition(type_condition)
@@ -260,14 +258,16 @@ impl<
     }
 
     fn visit_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, included: bool) {
-        if let Some(directives) = fragment_spread.directives() {
-            self.visit_variable_directives(directives, DirectiveLocation::FragmentSpread);
-        }
-
         let included = included
             && fragment_spread
                 .directives()
                 .is_none_or(|directives| self.evaluate_selection_inclusion(directives));
+        if let Some(directives) = fragment_spread.directives() {
+            self.visit_variable_directives(directives, DirectiveLocation::FragmentSpread, included);
+        }
+
+        self.visitor
+            .visit_fragment_spread(fragment_spread, included);
         if self
             .currently_spread_fragments
             .insert(fragment_spread.name())
@@ -292,45 +292,11 @@ impl<
     }
 
     fn evaluate_selection_inclusion(&mut self, directives: &'a E::Directives<false>) -> bool {
-        let skip_directive_value = self.evaluate_boolean_directive_argument_value(
-            directives,
-            Self::SKIP_DIRECTIVE_NAME,
-            Self::SKIP_INCLUDE_CONDITION_ARGUMENT,
-        );
-
-        let include_directive_value = self.evaluate_boolean_directive_argument_value(
-            directives,
-            Self::INCLUDE_DIRECTIVE_NAME,
-            Self::SKIP_INCLUDE_CONDITION_ARGUMENT,
-        );
-
-        !matches!(
-            (skip_directive_value, include_directive_value),
-            (Some(true), _) | (_, Some(false))
-        )
-    }
-
-    fn evaluate_boolean_directive_argument_value(
-        &self,
-        directives: &'a E::Directives<false>,
-        directive_name: &str,
-        arg_name: &str,
-    ) -> Option<bool> {
-        directives
-            .iter()
-            .find(|directive| directive.name() == directive_name)
-            .and_then(|directive| {
-                directive
-                    .arguments()
-                    .and_then(|arguments| arguments.iter().find(|arg| arg.name() == arg_name))
-                    .and_then(|argument| match argument.value().as_ref() {
-                        ValueReference::Boolean(val) => Some(val),
-                        ValueReference::Variable(v) => self
-                            .operation_definition
-                            .evaluate_bool(v, self.variable_values),
-                        _ => None,
-                    })
-            })
+        !SelectionInclusion::evaluate(Some(directives), |variable| {
+            self.operation_definition
+                .evaluate_bool(&variable, self.variable_values)
+        })
+        .is_excluded()
     }
 
     pub fn analyze<'b>(
@@ -344,25 +310,7 @@ impl<
     where
         V: Analyzer<'a, E, S, VV>,
     {
-        let operation_definition = match operation_name {
-            Some(operation_name) => executable_document
-                .operation_definitions()
-                .find(|operation_definition| {
-                    operation_definition.as_ref().name() == Some(operation_name)
-                })
-                .ok_or(OperationResolutionError::NoOperationWithName {
-                    name: operation_name,
-                })?,
-            None => {
-                let [operation_definition]: [&'a E::OperationDefinition; 1] = executable_document
-                    .operation_definitions()
-                    .collect::<Vec<_>>()
-                    .as_slice()
-                    .try_into()
-                    .map_err(|_| OperationResolutionError::AnonymousNotEligible)?;
-                operation_definition
-            }
-        };
+        let operation_definition = resolve_operation(executable_document, operation_name)?;
         let mut instance = Self::new(
             operation_definition,
             schema_definition,
@@ -375,9 +323,49 @@ impl<
     }
 }
 
+/// Finds the operation named `operation_name`, or the only operation of the document when no
+/// name is given, following `GetOperation` from the spec
+pub(crate) fn resolve_operation<'a, 'b, E: ExecutableDocument>(
+    executable_document: &'a E,
+    operation_name: Option<&'b str>,
+) -> Result<&'a E::OperationDefinition, OperationResolutionError<'b>> {
+    match operation_name {
+        Some(operation_name) => {
+            let mut operation_definitions =
+                executable_document
+                    .operation_definitions()
+                    .filter(|operation_definition| {
+                        operation_definition.as_ref().name() == Some(operation_name)
+                    });
+            let operation_definition = operation_definitions.next().ok_or(
+                OperationResolutionError::NoOperationWithName {
+                    name: operation_name,
+                },
+            )?;
+            match operation_definitions.next() {
+                Some(_) => Err(OperationResolutionError::AmbiguousOperationName {
+                    name: operation_name,
+                }),
+                None => Ok(operation_definition),
+            }
+        }
+        None => {
+            let [operation_definition]: [&'a E::OperationDefinition; 1] = executable_document
+                .operation_definitions()
+                .collect::<Vec<_>>()
+                .as_slice()
+                .try_into()
+                .map_err(|_| OperationResolutionError::AnonymousNotEligible)?;
+            Ok(operation_definition)
+        }
+    }
+}
+
+#[non_exhaustive]
 #[derive(Debug)]
 pub enum OperationResolutionError<'a> {
     NoOperationWithName { name: &'a str },
+    AmbiguousOperationName { name: &'a str },
     AnonymousNotEligible,
 }
 
@@ -385,6 +373,7 @@ impl OperationResolutionError<'_> {
     pub fn message(&self) -> Cow<'static, str> {
         match self {
             Self::NoOperationWithName { name } => format!("No operation defined with name {}", name).into(),
+            Self::AmbiguousOperationName { name } => format!("Multiple operations defined with name {}", name).into(),
             Self::AnonymousNotEligible => "Anonymous operation can only be used when the document contains exactly one operation definition".into(),
         }
     }
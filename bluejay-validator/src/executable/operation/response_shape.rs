@@ -0,0 +1,536 @@
+use crate::executable::operation::{
+    resolve_operation, FieldCollector, GroupedFieldSet, ObjectTypeResolutionError,
+    OperationResolutionError, VariableValues, TYPENAME_FIELD_NAME,
+};
+use crate::Path;
+use bluejay_core::definition::{
+    BaseOutputTypeReference, EnumTypeDefinition, EnumValueDefinition, FieldDefinition,
+    FieldsDefinition, ObjectTypeDefinition, OutputType, OutputTypeReference, SchemaDefinition,
+};
+use bluejay_core::executable::{ExecutableDocument, Field, OperationDefinition};
+use bluejay_core::{AsIter, BuiltinScalarDefinition, OperationType};
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::borrow::Cow;
+
+/// A mismatch between the `data` of a response and the shape of the operation it responds to,
+/// found by [validate_response]
+#[derive(PartialEq, Debug)]
+pub enum ResponseShapeError<'a> {
+    MissingResponseKey {
+        path: Path<'a>,
+    },
+    UnexpectedResponseKey {
+        path: Path<'a>,
+    },
+    NullValueForRequiredType {
+        output_type_name: String,
+        path: Path<'a>,
+    },
+    InvalidValueForType {
+        value: &'a JsonValue,
+        output_type_name: String,
+        path: Path<'a>,
+    },
+    NoEnumMemberWithName {
+        name: &'a str,
+        enum_type_name: &'a str,
+        path: Path<'a>,
+    },
+    TypenameNotPossibleType {
+        typename: &'a str,
+        output_type_name: &'a str,
+        path: Path<'a>,
+    },
+    UnresolvedObjectType {
+        output_type_name: &'a str,
+        path: Path<'a>,
+    },
+}
+
+impl<'a> ResponseShapeError<'a> {
+    pub fn message(&self) -> Cow<'static, str> {
+        match self {
+            Self::MissingResponseKey { .. } => "Selected response key is missing".into(),
+            Self::UnexpectedResponseKey { .. } => "Response key was not selected".into(),
+            Self::NullValueForRequiredType {
+                output_type_name, ..
+            } => format!("Got null when non-null value of type {output_type_name} was expected")
+                .into(),
+            Self::InvalidValueForType {
+                value,
+                output_type_name,
+                ..
+            } => format!("Value {value} is not valid for type {output_type_name}").into(),
+            Self::NoEnumMemberWithName {
+                name,
+                enum_type_name,
+                ..
+            } => format!("No member `{name}` on enum {enum_type_name}").into(),
+            Self::TypenameNotPossibleType {
+                typename,
+                output_type_name,
+                ..
+            } => format!("Type {typename} is not a possible type of {output_type_name}").into(),
+            Self::UnresolvedObjectType {
+                output_type_name, ..
+            } => format!(
+                "Could not determine the object type of a value of abstract type {output_type_name}, select `__typename` to disambiguate"
+            )
+            .into(),
+        }
+    }
+
+    /// The response path of the mismatching value
+    pub fn path(&self) -> &Path<'a> {
+        match self {
+            Self::MissingResponseKey { path }
+            | Self::UnexpectedResponseKey { path }
+            | Self::NullValueForRequiredType { path, .. }
+            | Self::InvalidValueForType { path, .. }
+            | Self::NoEnumMemberWithName { path, .. }
+            | Self::TypenameNotPossibleType { path, .. }
+            | Self::UnresolvedObjectType { path, .. } => path,
+        }
+    }
+}
+
+/// Checks that the `data` of a response has the shape of the operation it responds to:
+/// every selected response key is present and no other keys are, values respect the
+/// nullability and list wrapping of their types, `__typename` is a possible type of the field,
+/// enum values are members of their enum and built-in scalars have the right JSON kind.
+///
+/// `@skip` and `@include` are evaluated against `variable_values`, which should be the coerced
+/// variable values of the operation. Objects of abstract types are resolved by their
+/// `__typename` if it was selected, otherwise by the first possible type selecting exactly the
+/// keys of the object. A `data` of `null` is valid, as it is the result of an error on a
+/// non-null root field.
+pub fn validate_response<
+    'a,
+    'b,
+    E: ExecutableDocument + 'a,
+    S: SchemaDefinition,
+    V: VariableValues,
+>(
+    executable_document: &'a E,
+    schema_definition: &'a S,
+    operation_name: Option<&'b str>,
+    variable_values: &'a V,
+    data: &'a JsonValue,
+) -> Result<Vec<ResponseShapeError<'a>>, OperationResolutionError<'b>> {
+    let operation_definition = resolve_operation(executable_document, operation_name)?.as_ref();
+    let mut validator = ResponseShapeValidator {
+        schema_definition,
+        field_collector: FieldCollector::new(
+            executable_document,
+            schema_definition,
+            variable_values,
+        ),
+        errors: Vec::new(),
+    };
+
+    let root_type = match operation_definition.operation_type() {
+        OperationType::Query => Some(schema_definition.query()),
+        OperationType::Mutation => schema_definition.mutation(),
+        OperationType::Subscription => schema_definition.subscription(),
+    };
+
+    match (root_type, data) {
+        (_, JsonValue::Null) | (None, _) => {}
+        (Some(root_type), JsonValue::Object(object)) => {
+            let grouped_field_set = validator
+                .field_collector
+                .collect_fields(operation_definition.selection_set(), root_type)
+                .clone();
+            validator.validate_object(root_type, &grouped_field_set, object, &Path::default());
+        }
+        (Some(root_type), _) => validator
+            .errors
+            .push(ResponseShapeError::InvalidValueForType {
+                value: data,
+                output_type_name: root_type.name().to_owned(),
+                path: Path::default(),
+            }),
+    }
+
+    Ok(validator.errors)
+}
+
+struct ResponseShapeValidator<
+    'a,
+    E: ExecutableDocument + 'a,
+    S: SchemaDefinition,
+    V: VariableValues,
+> {
+    schema_definition: &'a S,
+    field_collector: FieldCollector<'a, E, S, V>,
+    errors: Vec<ResponseShapeError<'a>>,
+}
+
+impl<'a, E: ExecutableDocument + 'a, S: SchemaDefinition, V: VariableValues>
+    ResponseShapeValidator<'a, E, S, V>
+{
+    fn validate_object(
+        &mut self,
+        object_type: &'a S::ObjectTypeDefinition,
+        grouped_field_set: &GroupedFieldSet<'a, E>,
+        object: &'a JsonMap<String, JsonValue>,
+        path: &Path<'a>,
+    ) {
+        grouped_field_set.iter().for_each(|(response_key, fields)| {
+            let path = path.push(response_key);
+            let Some(value) = object.get(response_key) else {
+                self.errors
+                    .push(ResponseShapeError::MissingResponseKey { path });
+                return;
+            };
+            let field_name = fields[0].name();
+            if field_name == TYPENAME_FIELD_NAME {
+                self.validate_typename(object_type, value, path);
+            } else if let Some(field_definition) = object_type.fields_definition().get(field_name) {
+                self.validate_value(field_definition.r#type(), fields, value, path);
+            }
+        });
+
+        self.errors.extend(
+            object
+                .keys()
+                .filter(|response_key| grouped_field_set.get(response_key).is_none())
+                .map(|response_key| ResponseShapeError::UnexpectedResponseKey {
+                    path: path.push(response_key.as_str()),
+                }),
+        );
+    }
+
+    fn validate_typename(
+        &mut self,
+        object_type: &'a S::ObjectTypeDefinition,
+        value: &'a JsonValue,
+        path: Path<'a>,
+    ) {
+        match value.as_str() {
+            Some(typename) if typename == object_type.name() => {}
+            Some(typename) => self
+                .errors
+                .push(ResponseShapeError::TypenameNotPossibleType {
+                    typename,
+                    output_type_name: object_type.name(),
+                    path,
+                }),
+            None => self.errors.push(ResponseShapeError::InvalidValueForType {
+                value,
+                output_type_name: BuiltinScalarDefinition::String.name().to_owned(),
+                path,
+            }),
+        }
+    }
+
+    fn validate_value(
+        &mut self,
+        output_type: &'a S::OutputType,
+        fields: &[&'a E::Field],
+        value: &'a JsonValue,
+        path: Path<'a>,
+    ) {
+        match (output_type.as_ref(self.schema_definition), value) {
+            (output_type_reference, JsonValue::Null) => {
+                if output_type_reference.is_required() {
+                    self.errors
+                        .push(ResponseShapeError::NullValueForRequiredType {
+                            output_type_name: output_type.display_name(),
+                            path,
+                        });
+                }
+            }
+            (OutputTypeReference::List(inner, _), JsonValue::Array(items)) => {
+                items.iter().enumerate().for_each(|(index, item)| {
+                    self.validate_value(inner, fields, item, path.push(index))
+                });
+            }
+            (OutputTypeReference::Base(base, _), value) if self.is_valid_leaf(base, value) => {
+                if let (BaseOutputTypeReference::Enum(etd), JsonValue::String(name)) = (base, value)
+                {
+                    if !etd
+                        .enum_value_definitions()
+                        .iter()
+                        .any(|evd| evd.name() == name)
+                    {
+                        self.errors.push(ResponseShapeError::NoEnumMemberWithName {
+                            name,
+                            enum_type_name: etd.name(),
+                            path,
+                        });
+                    }
+                }
+            }
+            (OutputTypeReference::Base(base, _), JsonValue::Object(object))
+                if base.is_composite() =>
+            {
+                if let Some((object_type, grouped_field_set)) =
+                    self.resolve_object_type(base, fields, object, &path)
+                {
+                    self.validate_object(object_type, &grouped_field_set, object, &path);
+                }
+            }
+            (_, value) => self.errors.push(ResponseShapeError::InvalidValueForType {
+                value,
+                output_type_name: output_type.display_name(),
+                path,
+            }),
+        }
+    }
+
+    /// Whether `value` has the right JSON kind for the scalar or enum type `base`
+    fn is_valid_leaf(
+        &self,
+        base: BaseOutputTypeReference<'a, S::OutputType>,
+        value: &JsonValue,
+    ) -> bool {
+        match base {
+            BaseOutputTypeReference::BuiltinScalar(bstd) => match bstd {
+                BuiltinScalarDefinition::Int => value
+                    .as_i64()
+                    .is_some_and(|value| i32::try_from(value).is_ok()),
+                BuiltinScalarDefinition::Float => value.is_number(),
+                BuiltinScalarDefinition::String | BuiltinScalarDefinition::ID => value.is_string(),
+                BuiltinScalarDefinition::Boolean => value.is_boolean(),
+            },
+            BaseOutputTypeReference::CustomScalar(_) => true,
+            BaseOutputTypeReference::Enum(_) => value.is_string(),
+            BaseOutputTypeReference::Object(_)
+            | BaseOutputTypeReference::Interface(_)
+            | BaseOutputTypeReference::Union(_) => false,
+        }
+    }
+
+    fn resolve_object_type(
+        &mut self,
+        base: BaseOutputTypeReference<'a, S::OutputType>,
+        fields: &[&'a E::Field],
+        object: &'a JsonMap<String, JsonValue>,
+        path: &Path<'a>,
+    ) -> Option<(&'a S::ObjectTypeDefinition, GroupedFieldSet<'a, E>)> {
+        self.field_collector
+            .resolve_object_type(base, fields, object)
+            .map_err(|error| {
+                self.errors.push(match error {
+                    ObjectTypeResolutionError::TypenameNotPossibleType(typename) => {
+                        ResponseShapeError::TypenameNotPossibleType {
+                            typename,
+                            output_type_name: base.name(),
+                            path: path.clone(),
+                        }
+                    }
+                    ObjectTypeResolutionError::Unresolved => {
+                        ResponseShapeError::UnresolvedObjectType {
+                            output_type_name: base.name(),
+                            path: path.clone(),
+                        }
+                    }
+                })
+            })
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_response;
+    use crate::PathElement;
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::{json, Map as JsonMap, Value as JsonValue};
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        type Query {
+            viewer: User!
+            pets(first: Int): [Pet]!
+            search: [SearchResult!]
+            count: Int
+        }
+
+        enum Status { ACTIVE INACTIVE }
+
+        scalar DateTime
+
+        interface Pet {
+            name: String!
+        }
+
+        type Dog implements Pet {
+            name: String!
+            barkVolume: Int
+        }
+
+        type Cat implements Pet {
+            name: String!
+            meowVolume: Int
+        }
+
+        type User {
+            id: ID!
+            name: String
+            status: Status!
+            createdAt: DateTime!
+            score: Float
+            admin: Boolean!
+        }
+
+        union SearchResult = User | Dog
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    /// Returns the dot-separated path and message of each error
+    fn errors(source: &str, variables: JsonValue, data: JsonValue) -> Vec<(String, String)> {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let variables: JsonMap<String, JsonValue> = variables.as_object().unwrap().clone();
+        validate_response(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            &variables,
+            &data,
+        )
+        .unwrap()
+        .iter()
+        .map(|error| {
+            (
+                error
+                    .path()
+                    .to_vec::<PathElement>()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("."),
+                error.message().into_owned(),
+            )
+        })
+        .collect()
+    }
+
+    const SOURCE: &str = r#"
+        query($withScore: Boolean!) {
+            viewer { id name status createdAt score @include(if: $withScore) admin }
+            pets(first: 2) { __typename name ... on Dog { barkVolume } }
+            search { ... on User { id } ... on Dog { name barkVolume } }
+            total: count
+        }
+    "#;
+
+    #[test]
+    fn test_valid_response() {
+        assert_eq!(
+            Vec::<(String, String)>::new(),
+            errors(
+                SOURCE,
+                json!({ "withScore": false }),
+                json!({
+                    "viewer": {
+                        "id": "1",
+                        "name": null,
+                        "status": "ACTIVE",
+                        "createdAt": "2024-01-01T00:00:00Z",
+                        "admin": false,
+                    },
+                    "pets": [
+                        { "__typename": "Dog", "name": "Rex", "barkVolume": 3 },
+                        null,
+                        { "__typename": "Cat", "name": "Tom" },
+                    ],
+                    "search": [{ "id": "1" }, { "name": "Rex", "barkVolume": null }],
+                    "total": null,
+                }),
+            ),
+        );
+        assert_eq!(
+            Vec::<(String, String)>::new(),
+            errors(SOURCE, json!({ "withScore": true }), JsonValue::Null),
+        );
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let errors = errors(
+            SOURCE,
+            json!({ "withScore": true }),
+            json!({
+                "viewer": {
+                    "id": 1,
+                    "name": "Ann",
+                    "status": "DELETED",
+                    "createdAt": null,
+                    "admin": false,
+                    "extra": true,
+                },
+                "pets": [
+                    { "__typename": "User", "name": "Rex" },
+                    { "__typename": "Cat", "name": "Tom", "barkVolume": 3 },
+                ],
+                "search": [{ "id": "1", "name": "Ann" }],
+                "total": 3000000000u64,
+            }),
+        );
+
+        assert_eq!(
+            vec![
+                ("viewer.id".to_owned(), "Value 1 is not valid for type ID!".to_owned()),
+                (
+                    "viewer.status".to_owned(),
+                    "No member `DELETED` on enum Status".to_owned(),
+                ),
+                (
+                    "viewer.createdAt".to_owned(),
+                    "Got null when non-null value of type DateTime! was expected".to_owned(),
+                ),
+                ("viewer.score".to_owned(), "Selected response key is missing".to_owned()),
+                ("viewer.extra".to_owned(), "Response key was not selected".to_owned()),
+                (
+                    "pets.0".to_owned(),
+                    "Type User is not a possible type of Pet".to_owned(),
+                ),
+                (
+                    "pets.1.barkVolume".to_owned(),
+                    "Response key was not selected".to_owned(),
+                ),
+                (
+                    "search.0".to_owned(),
+                    "Could not determine the object type of a value of abstract type SearchResult, select `__typename` to disambiguate".to_owned(),
+                ),
+                (
+                    "total".to_owned(),
+                    "Value 3000000000 is not valid for type Int".to_owned(),
+                ),
+            ],
+            errors,
+        );
+    }
+
+    #[test]
+    fn test_list_and_object_kinds() {
+        assert_eq!(
+            vec![
+                (
+                    "viewer".to_owned(),
+                    "Value [] is not valid for type User!".to_owned(),
+                ),
+                (
+                    "pets".to_owned(),
+                    "Value {} is not valid for type [Pet]!".to_owned(),
+                ),
+            ],
+            errors(
+                "{ viewer { id } pets { name } }",
+                json!({}),
+                json!({ "viewer": [], "pets": {} }),
+            ),
+        );
+    }
+}
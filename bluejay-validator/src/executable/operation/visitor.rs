@@ -1,5 +1,5 @@
 use crate::executable::{operation::VariableValues, Cache};
-use bluejay_core::definition::{SchemaDefinition, TypeDefinitionReference};
+use bluejay_core::definition::{DirectiveLocation, SchemaDefinition, TypeDefinitionReference};
 use bluejay_core::executable::ExecutableDocument;
 
 pub trait Visitor<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableValues> {
@@ -56,6 +56,31 @@ pub trait Visitor<'a, E: ExecutableDocument, S: SchemaDefinition, V: VariableVal
     /// - `variable_definition` is the variable definition being visited
     #[allow(unused_variables)]
     fn visit_variable_definition(&mut self, variable_definition: &'a E::VariableDefinition) {}
+
+    /// Visits the fragment spread. If a fragment spread is part of a fragment definition, it will
+    /// be visited every time the enclosing fragment is spread.
+    /// # Variables
+    /// - `fragment_spread` is the fragment spread being visited
+    /// - `included` is true when the fragment spread is known to be included in the response
+    ///   (based on the usage of `@include` and `@skip` directives and the variable values)
+    #[allow(unused_variables)]
+    fn visit_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, included: bool) {}
+
+    /// Visits a directive applied to the operation, a field, a fragment spread or an inline
+    /// fragment. Directives are visited before the selection they are applied to.
+    /// # Variables
+    /// - `directive` is the directive being visited
+    /// - `location` is the location the directive is applied to
+    /// - `included` is true when the selection the directive is applied to is known to be
+    ///   included in the response, and always true for operation directives
+    #[allow(unused_variables)]
+    fn visit_variable_directive(
+        &mut self,
+        directive: &'a E::Directive<false>,
+        location: DirectiveLocation,
+        included: bool,
+    ) {
+    }
 }
 
 macro_rules! impl_visitor {
@@ -111,6 +136,14 @@ macro_rules! impl_visitor {
                 fn visit_variable_argument(&mut self, argument: &'a E::Argument<false>, input_value_definition: &'a S::InputValueDefinition) {
                     #(self.N.visit_variable_argument(argument, input_value_definition);)*
                 }
+
+                fn visit_fragment_spread(&mut self, fragment_spread: &'a E::FragmentSpread, included: bool) {
+                    #(self.N.visit_fragment_spread(fragment_spread, included);)*
+                }
+
+                fn visit_variable_directive(&mut self, directive: &'a E::Directive<false>, location: DirectiveLocation, included: bool) {
+                    #(self.N.visit_variable_directive(directive, location, included);)*
+                }
             }
         });
     }
@@ -0,0 +1,67 @@
+use bluejay_core::definition::{
+    ObjectTypeDefinition, SchemaDefinition, TypeDefinitionReference, UnionMemberType,
+    UnionTypeDefinition,
+};
+use bluejay_core::AsIter;
+use std::collections::{HashMap, HashSet};
+
+/// Schema-derived data used during validation that does not depend on the executable document.
+///
+/// Building it once per schema and passing it to [`Cache::with_schema_cache`](super::Cache::with_schema_cache)
+/// avoids recomputing it for every document. It only holds names borrowed from the schema, so it
+/// is `Send + Sync` and can be shared across threads.
+///
+/// Only the possible types of composite types are cached, since computing them walks every
+/// interface implementation or union member. Type and directive definitions are left out on
+/// purpose: [`SchemaDefinition`] already looks them up by name (a hash map lookup for both the
+/// parser and `bluejay-visibility` schemas), and the locations of a directive definition are a
+/// short list read straight from it, so caching them would save nothing and would tie this
+/// cache to the schema type.
+#[derive(Clone, Debug)]
+pub struct SchemaCache<'a> {
+    possible_type_names: HashMap<&'a str, HashSet<&'a str>>,
+}
+
+impl<'a> SchemaCache<'a> {
+    pub fn new<S: SchemaDefinition>(schema_definition: &'a S) -> Self {
+        let possible_type_names = schema_definition
+            .type_definitions()
+            .filter_map(|type_definition| {
+                let possible_type_names: HashSet<&'a str> = match type_definition {
+                    TypeDefinitionReference::Object(otd) => HashSet::from([otd.name()]),
+                    TypeDefinitionReference::Interface(itd) => schema_definition
+                        .get_interface_implementors(itd)
+                        .map(ObjectTypeDefinition::name)
+                        .collect(),
+                    TypeDefinitionReference::Union(utd) => utd
+                        .union_member_types()
+                        .iter()
+                        .map(|union_member| union_member.name())
+                        .collect(),
+                    TypeDefinitionReference::BuiltinScalar(_)
+                    | TypeDefinitionReference::CustomScalar(_)
+                    | TypeDefinitionReference::Enum(_)
+                    | TypeDefinitionReference::InputObject(_) => return None,
+                };
+                Some((type_definition.name(), possible_type_names))
+            })
+            .collect();
+
+        Self {
+            possible_type_names,
+        }
+    }
+
+    /// The names of the object types that can be the runtime type of the composite type named
+    /// `type_name`, or `None` if there is no composite type with that name
+    pub fn possible_type_names(&self, type_name: &str) -> Option<&HashSet<&'a str>> {
+        self.possible_type_names.get(type_name)
+    }
+
+    /// Whether the object type named `object_type_name` is a possible type of the composite type
+    /// named `type_name`
+    pub fn is_possible_type(&self, type_name: &str, object_type_name: &str) -> bool {
+        self.possible_type_names(type_name)
+            .is_some_and(|possible_type_names| possible_type_names.contains(object_type_name))
+    }
+}
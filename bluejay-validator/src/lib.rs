@@ -1,3 +1,10 @@
+/// Expands to the URL of the section of the GraphQL specification with the given anchor
+macro_rules! spec_section {
+    ($anchor:literal) => {
+        concat!("https://spec.graphql.org/draft/#sec-", $anchor)
+    };
+}
+
 mod chain_iters;
 pub mod definition;
 pub mod executable;
@@ -1,4 +1,9 @@
+// paths end up in the errors of documents validated on other threads with the `rayon` feature,
+// and otherwise avoid the cost of atomic reference counting
+#[cfg(not(feature = "rayon"))]
 use std::rc::Rc;
+#[cfg(feature = "rayon")]
+use std::sync::Arc as Rc;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PathElement<'a> {
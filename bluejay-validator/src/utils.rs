@@ -1,3 +1,5 @@
+use bluejay_core::definition::{DirectiveDefinition, SchemaDefinition};
+use bluejay_core::{Argument, Arguments, AsIter, Directive, Directives, Value, ValueReference};
 use std::cmp::{Eq, Ord};
 use std::collections::BTreeMap;
 use std::hash::Hash;
@@ -16,3 +18,155 @@ pub fn duplicates<T: Copy, I: Iterator<Item = T>, K: Hash + Ord + Eq>(
 
     indexed.into_iter().filter(|(_, values)| values.len() > 1)
 }
+
+/// Returns the first directive named `name`, if any
+pub fn find_directive<'a, const CONST: bool, D: Directives<CONST>>(
+    directives: Option<&'a D>,
+    name: &str,
+) -> Option<&'a D::Directive> {
+    directives.and_then(|directives| directives.iter().find(|directive| directive.name() == name))
+}
+
+/// Returns the value of the argument named `name` on the directive, if specified
+pub fn directive_argument<'a, const CONST: bool, D: Directive<CONST>>(
+    directive: &'a D,
+    name: &str,
+) -> Option<&'a <D::Arguments as Arguments<CONST>>::Argument> {
+    directive
+        .arguments()
+        .and_then(|arguments| arguments.iter().find(|argument| argument.name() == name))
+}
+
+/// The name of the incremental delivery `@defer` directive
+pub const DEFER_DIRECTIVE_NAME: &str = "defer";
+/// The name of the incremental delivery `@stream` directive
+pub const STREAM_DIRECTIVE_NAME: &str = "stream";
+
+/// Whether the directive is statically disabled by an `if: false` argument, as `@defer` and
+/// `@stream` can be
+pub fn is_disabled_by_if_argument<const CONST: bool, D: Directive<CONST>>(directive: &D) -> bool {
+    directive_argument(directive, "if")
+        .is_some_and(|argument| matches!(argument.value().as_ref(), ValueReference::Boolean(false)))
+}
+
+/// Whether the schema defines the builtin directive named `name`, rather than no directive or
+/// one of its own with that name. The builtin `@defer` and `@stream` directives are only
+/// defined when the schema opts into incremental delivery.
+pub fn defines_builtin_directive<S: SchemaDefinition>(schema_definition: &S, name: &str) -> bool {
+    schema_definition
+        .get_directive_definition(name)
+        .is_some_and(DirectiveDefinition::is_builtin)
+}
+
+/// Returns the options that are close to `input` by edit distance, closest first,
+/// following the algorithm used by graphql-js for "Did you mean" suggestions.
+pub fn suggestion_list<'a>(input: &str, options: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let threshold = input.chars().count() * 2 / 5 + 1;
+    let lowercase_input = input.to_lowercase();
+    let mut suggestions: Vec<(usize, &'a str)> = options
+        .filter_map(|option| {
+            let distance = if option.to_lowercase() == lowercase_input {
+                (option != input).then_some(1)
+            } else {
+                Some(edit_distance(&lowercase_input, &option.to_lowercase()))
+            }?;
+            (distance <= threshold).then_some((distance, option))
+        })
+        .collect();
+    suggestions.sort_unstable();
+    suggestions.dedup();
+    suggestions.into_iter().map(|(_, option)| option).collect()
+}
+
+/// Formats up to five suggestions as a sentence, e.g. "Did you mean `a`, `b`, or `c`?"
+pub fn did_you_mean(suggestions: &[&str]) -> Option<String> {
+    const MAX_SUGGESTIONS: usize = 5;
+
+    let quoted: Vec<String> = suggestions
+        .iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|suggestion| format!("`{suggestion}`"))
+        .collect();
+    let joined = match quoted.as_slice() {
+        [] => return None,
+        [only] => only.clone(),
+        [first, second] => format!("{first} or {second}"),
+        [rest @ .., last] => format!("{}, or {last}", rest.join(", ")),
+    };
+    Some(format!("Did you mean {joined}?"))
+}
+
+/// Appends the "Did you mean" sentence for `suggestions` to `message`, if there are any
+pub fn with_did_you_mean(message: impl Into<String>, suggestions: &[&str]) -> String {
+    let message = message.into();
+    match did_you_mean(suggestions) {
+        Some(did_you_mean) => format!("{message}. {did_you_mean}"),
+        None => message,
+    }
+}
+
+/// Whether `name` starts with an ASCII lowercase letter and contains only ASCII letters and digits
+pub(crate) fn is_camel_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Optimal string alignment distance: Levenshtein distance where swapping two adjacent
+/// characters counts as a single edit
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![Vec::from_iter(0..=b.len())];
+
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (rows[i - 1][j] + 1)
+                .min(row[j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+        rows.push(row);
+    }
+
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{did_you_mean, suggestion_list};
+
+    #[test]
+    fn test_suggestion_list() {
+        assert_eq!(
+            vec!["name"],
+            suggestion_list("nmae", ["name", "id", "email"].into_iter())
+        );
+        assert_eq!(
+            vec!["FIRST"],
+            suggestion_list("first", ["FIRST", "SECOND"].into_iter())
+        );
+        assert_eq!(
+            vec!["abc", "ba"],
+            suggestion_list("ab", ["ba", "abcd", "abc", "xyz"].into_iter())
+        );
+        assert!(suggestion_list("name", ["name", "unrelated"].into_iter()).is_empty());
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        assert_eq!(None, did_you_mean(&[]));
+        assert_eq!(Some("Did you mean `a`?".to_owned()), did_you_mean(&["a"]));
+        assert_eq!(
+            Some("Did you mean `a` or `b`?".to_owned()),
+            did_you_mean(&["a", "b"])
+        );
+        assert_eq!(
+            Some("Did you mean `a`, `b`, or `c`?".to_owned()),
+            did_you_mean(&["a", "b", "c"])
+        );
+    }
+}
@@ -1 +1,6 @@
+pub mod argument_coercion;
+mod coerced_value;
 pub mod input_coercion;
+
+pub(crate) use coerced_value::coerce_validated_value;
+pub use coerced_value::CoercedValue;
@@ -0,0 +1,315 @@
+use crate::executable::operation::VariableValues;
+use crate::value::{coerce_validated_value, CoercedValue};
+use bluejay_core::definition::{
+    DirectiveDefinition, FieldDefinition, InputType, InputValueDefinition, SchemaDefinition,
+};
+use bluejay_core::executable::Field;
+use bluejay_core::{Argument, Arguments, AsIter, Directive, Value, ValueReference, Variable};
+
+/// The coerced value of each argument, in the order the arguments are defined
+pub type CoercedArgumentValues<'a> = Vec<(&'a str, CoercedValue)>;
+
+/// Implements the CoerceArgumentValues algorithm of the specification for the arguments of
+/// a field, returning the coerced value of each argument in the order the arguments are
+/// defined. Arguments without a value or default are omitted.
+///
+/// The field is expected to have passed document validation, and `variable_values` to hold
+/// coerced variable values, e.g. the output of
+/// [`CoerceVariableValues`](crate::executable::operation::analyzers::CoerceVariableValues).
+pub fn coerce_field_argument_values<'a, S: SchemaDefinition + 'a, F: Field>(
+    schema_definition: &'a S,
+    field: &'a F,
+    field_definition: &'a S::FieldDefinition,
+    variable_values: &impl VariableValues<Value = CoercedValue>,
+) -> Result<CoercedArgumentValues<'a>, Vec<Error<'a>>> {
+    coerce_argument_values(
+        schema_definition,
+        field_definition.arguments_definition(),
+        field.arguments(),
+        variable_values,
+    )
+}
+
+/// Same as [`coerce_field_argument_values`], for the arguments of a directive
+pub fn coerce_directive_argument_values<'a, S: SchemaDefinition + 'a, D: Directive<false>>(
+    schema_definition: &'a S,
+    directive: &'a D,
+    directive_definition: &'a S::DirectiveDefinition,
+    variable_values: &impl VariableValues<Value = CoercedValue>,
+) -> Result<CoercedArgumentValues<'a>, Vec<Error<'a>>> {
+    coerce_argument_values(
+        schema_definition,
+        directive_definition.arguments_definition(),
+        directive.arguments(),
+        variable_values,
+    )
+}
+
+fn coerce_argument_values<'a, S: SchemaDefinition + 'a, A: Arguments<false>>(
+    schema_definition: &'a S,
+    arguments_definition: Option<&'a S::ArgumentsDefinition>,
+    arguments: Option<&'a A>,
+    variable_values: &impl VariableValues<Value = CoercedValue>,
+) -> Result<CoercedArgumentValues<'a>, Vec<Error<'a>>> {
+    let variable_value = |name: &str| variable_values.get(name).cloned();
+    let mut coerced_values = Vec::new();
+    let mut errors = Vec::new();
+
+    arguments_definition
+        .into_iter()
+        .flat_map(|arguments_definition| arguments_definition.iter())
+        .for_each(|input_value_definition| {
+            let argument_name = input_value_definition.name();
+            let argument_type = input_value_definition.r#type();
+            let value = arguments.and_then(|arguments| {
+                arguments
+                    .iter()
+                    .find(|argument| argument.name() == argument_name)
+                    .map(Argument::value)
+            });
+            let provided_value = value.filter(|value| match value.as_ref() {
+                ValueReference::Variable(variable) => {
+                    variable_values.get(variable.name()).is_some()
+                }
+                _ => true,
+            });
+
+            let coerced_value = match provided_value {
+                Some(value) => Some(coerce_validated_value(
+                    schema_definition,
+                    argument_type,
+                    value,
+                    &variable_value,
+                )),
+                None => input_value_definition.default_value().map(|default_value| {
+                    coerce_validated_value(
+                        schema_definition,
+                        argument_type,
+                        default_value,
+                        &variable_value,
+                    )
+                }),
+            };
+
+            match coerced_value {
+                Some(CoercedValue::Null) if argument_type.is_required() => {
+                    errors.push(Error::NullValueForRequiredArgument {
+                        argument_name,
+                        input_type_name: argument_type.display_name(),
+                    })
+                }
+                Some(coerced_value) => coerced_values.push((argument_name, coerced_value)),
+                None if argument_type.is_required() => {
+                    errors.push(Error::NoValueForRequiredArgument {
+                        argument_name,
+                        input_type_name: argument_type.display_name(),
+                    })
+                }
+                None => {}
+            }
+        });
+
+    if errors.is_empty() {
+        Ok(coerced_values)
+    } else {
+        Err(errors)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Error<'a> {
+    NullValueForRequiredArgument {
+        argument_name: &'a str,
+        input_type_name: String,
+    },
+    NoValueForRequiredArgument {
+        argument_name: &'a str,
+        input_type_name: String,
+    },
+}
+
+impl Error<'_> {
+    pub fn message(&self) -> String {
+        match self {
+            Self::NullValueForRequiredArgument {
+                argument_name,
+                input_type_name,
+            } => format!(
+                "Got null for argument `{argument_name}` when non-null value of type {input_type_name} was expected"
+            ),
+            Self::NoValueForRequiredArgument {
+                argument_name,
+                input_type_name,
+            } => format!(
+                "No value for required argument `{argument_name}` of type {input_type_name}"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{coerce_field_argument_values, CoercedArgumentValues, Error};
+    use crate::executable::operation::{analyzers::CoerceVariableValues, Orchestrator};
+    use crate::executable::Cache;
+    use crate::value::CoercedValue;
+    use bluejay_core::definition::{
+        FieldsDefinition, ObjectTypeDefinition, SchemaDefinition as CoreSchemaDefinition,
+    };
+    use bluejay_core::executable::{OperationDefinition, Selection, SelectionReference};
+    use bluejay_core::AsIter;
+    use bluejay_parser::ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    };
+    use once_cell::sync::Lazy;
+    use serde_json::json;
+
+    const TEST_SCHEMA_SDL: &str = r#"
+        type Query {
+            dogs(
+                first: Int! = 10
+                after: String
+                commands: [DogCommand!]
+                filter: DogFilter
+                weight: Float
+                required: Boolean!
+            ): [String!]!
+        }
+
+        enum DogCommand {
+            SIT
+            DOWN
+        }
+
+        input DogFilter {
+            name: String
+            command: DogCommand! = SIT
+        }
+    "#;
+
+    static TEST_DEFINITION_DOCUMENT: Lazy<DefinitionDocument<'static>> =
+        Lazy::new(|| DefinitionDocument::parse(TEST_SCHEMA_SDL).result.unwrap());
+
+    static TEST_SCHEMA_DEFINITION: Lazy<SchemaDefinition<'static>> =
+        Lazy::new(|| SchemaDefinition::try_from(&*TEST_DEFINITION_DOCUMENT).unwrap());
+
+    fn coerce_dogs_arguments(
+        source: &str,
+        variable_values: serde_json::Value,
+        f: fn(Result<CoercedArgumentValues, Vec<Error>>),
+    ) {
+        let executable_document = ExecutableDocument::parse(source).result.unwrap();
+        let cache = Cache::new(&executable_document, &*TEST_SCHEMA_DEFINITION);
+        let coerced_variables = Orchestrator::<_, _, _, CoerceVariableValues<_, _, _>>::analyze(
+            &executable_document,
+            &*TEST_SCHEMA_DEFINITION,
+            None,
+            variable_values.as_object().unwrap(),
+            &cache,
+            (),
+        )
+        .unwrap()
+        .unwrap();
+        let operation_definition = executable_document.operation_definitions().first().unwrap();
+        let field = operation_definition
+            .as_ref()
+            .selection_set()
+            .iter()
+            .next()
+            .and_then(|selection| match selection.as_ref() {
+                SelectionReference::Field(field) => Some(field),
+                _ => None,
+            })
+            .unwrap();
+        let field_definition = TEST_SCHEMA_DEFINITION
+            .query()
+            .fields_definition()
+            .get("dogs")
+            .unwrap();
+
+        f(coerce_field_argument_values(
+            &*TEST_SCHEMA_DEFINITION,
+            field,
+            field_definition,
+            &coerced_variables,
+        ));
+    }
+
+    #[test]
+    fn test_literals_and_defaults() {
+        coerce_dogs_arguments(
+            r#"{ dogs(required: true, commands: SIT, weight: 2, filter: { name: "Fido" }) }"#,
+            json!({}),
+            |result| {
+                assert_eq!(
+                    Ok(vec![
+                        ("first", CoercedValue::Integer(10)),
+                        (
+                            "commands",
+                            CoercedValue::List(vec![CoercedValue::Enum("SIT".to_owned())])
+                        ),
+                        (
+                            "filter",
+                            CoercedValue::Object(vec![
+                                ("name".to_owned(), CoercedValue::String("Fido".to_owned())),
+                                ("command".to_owned(), CoercedValue::Enum("SIT".to_owned())),
+                            ])
+                        ),
+                        ("weight", CoercedValue::Float(2.0)),
+                        ("required", CoercedValue::Boolean(true)),
+                    ]),
+                    result,
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_variables() {
+        coerce_dogs_arguments(
+            r#"
+                query($first: Int, $after: String, $name: String, $required: Boolean!) {
+                    dogs(first: $first, after: $after, filter: { name: $name }, required: $required)
+                }
+            "#,
+            json!({ "after": null, "required": false }),
+            |result| {
+                assert_eq!(
+                    Ok(vec![
+                        ("first", CoercedValue::Integer(10)),
+                        ("after", CoercedValue::Null),
+                        (
+                            "filter",
+                            CoercedValue::Object(vec![(
+                                "command".to_owned(),
+                                CoercedValue::Enum("SIT".to_owned())
+                            )])
+                        ),
+                        ("required", CoercedValue::Boolean(false)),
+                    ]),
+                    result,
+                );
+            },
+        );
+    }
+
+    #[test]
+    fn test_null_for_required_argument() {
+        coerce_dogs_arguments(
+            r#"
+                query($first: Int, $required: Boolean!) {
+                    dogs(first: $first, required: $required)
+                }
+            "#,
+            json!({ "first": null, "required": true }),
+            |result| {
+                assert_eq!(
+                    Err(vec!["Got null for argument `first` when non-null value of type Int! was expected".to_owned()]),
+                    result.map_err(|errors| errors.iter().map(Error::message).collect::<Vec<_>>()),
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use bluejay_core::definition::{
+    BaseInputTypeReference, InputObjectTypeDefinition, InputType, InputTypeReference,
+    InputValueDefinition, SchemaDefinition,
+};
+use bluejay_core::{AsIter, BuiltinScalarDefinition, ObjectValue, Value, ValueReference, Variable};
+
+/// An owned input value that has been coerced to its input type, as produced by the
+/// CoerceVariableValues and CoerceArgumentValues algorithms of the specification.
+///
+/// Defaults have been applied, single values have been promoted to lists where a list is
+/// expected, `Int` values have been promoted where a `Float` is expected, and enum values
+/// have been resolved to their names. Input object fields are in definition order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoercedValue {
+    Null,
+    Integer(i32),
+    Float(f64),
+    String(String),
+    Boolean(bool),
+    Enum(String),
+    List(Vec<CoercedValue>),
+    Object(Vec<(String, CoercedValue)>),
+}
+
+/// Coerced values never contain variables
+#[derive(Debug)]
+pub enum Never {}
+
+impl Variable for Never {
+    fn name(&self) -> &str {
+        match *self {}
+    }
+}
+
+impl Value<true> for CoercedValue {
+    type List = Vec<CoercedValue>;
+    type Object = Vec<(String, CoercedValue)>;
+    type Variable = Never;
+
+    fn as_ref(&self) -> ValueReference<'_, true, Self> {
+        match self {
+            Self::Null => ValueReference::Null,
+            Self::Integer(i) => ValueReference::Integer(*i),
+            Self::Float(f) => ValueReference::Float(*f),
+            Self::String(s) => ValueReference::String(s),
+            Self::Boolean(b) => ValueReference::Boolean(*b),
+            Self::Enum(e) => ValueReference::Enum(e),
+            Self::List(l) => ValueReference::List(l),
+            Self::Object(o) => ValueReference::Object(o),
+        }
+    }
+}
+
+impl CoercedValue {
+    /// Returns the value of the field named `name` if this is an object value
+    pub fn get(&self, name: &str) -> Option<&Self> {
+        match self {
+            Self::Object(fields) => fields
+                .as_slice()
+                .iter()
+                .find_map(|(key, value)| (key == name).then_some(value)),
+            _ => None,
+        }
+    }
+
+    /// Copies a value as-is, without regard for any input type, resolving variables
+    /// with `variable_value`. Unresolved variables become `null`.
+    pub(crate) fn from_value<const CONST: bool, V: Value<CONST>>(
+        value: &V,
+        variable_value: &impl Fn(&str) -> Option<Self>,
+    ) -> Self {
+        match value.as_ref() {
+            ValueReference::Variable(variable) => {
+                variable_value(variable.name()).unwrap_or(Self::Null)
+            }
+            ValueReference::Integer(i) => Self::Integer(i),
+            ValueReference::Float(f) => Self::Float(f),
+            ValueReference::String(s) => Self::String(s.to_owned()),
+            ValueReference::Boolean(b) => Self::Boolean(b),
+            ValueReference::Null => Self::Null,
+            ValueReference::Enum(e) => Self::Enum(e.to_owned()),
+            ValueReference::List(l) => Self::List(
+                l.iter()
+                    .map(|value| Self::from_value(value, variable_value))
+                    .collect(),
+            ),
+            ValueReference::Object(o) => Self::Object(
+                o.iter()
+                    .map(|(key, value)| {
+                        (
+                            key.as_ref().to_owned(),
+                            Self::from_value(value, variable_value),
+                        )
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Produces the coerced form of a value that has already been validated against `input_type`,
+/// e.g. by [`CoerceInput`](crate::value::input_coercion::CoerceInput). Variables are resolved
+/// with `variable_value`, which should return `None` for variables that were not provided.
+pub(crate) fn coerce_validated_value<
+    const CONST: bool,
+    S: SchemaDefinition,
+    I: InputType<
+        CustomScalarTypeDefinition = S::CustomScalarTypeDefinition,
+        InputObjectTypeDefinition = S::InputObjectTypeDefinition,
+        EnumTypeDefinition = S::EnumTypeDefinition,
+    >,
+    V: Value<CONST>,
+>(
+    schema_definition: &S,
+    input_type: &I,
+    value: &V,
+    variable_value: &impl Fn(&str) -> Option<CoercedValue>,
+) -> CoercedValue {
+    match value.as_ref() {
+        ValueReference::Null => CoercedValue::Null,
+        ValueReference::Variable(variable) => {
+            variable_value(variable.name()).unwrap_or(CoercedValue::Null)
+        }
+        core_value => match input_type.as_ref(schema_definition) {
+            InputTypeReference::List(inner, _) => match core_value {
+                ValueReference::List(values) => CoercedValue::List(
+                    values
+                        .iter()
+                        .map(|value| {
+                            coerce_validated_value(schema_definition, inner, value, variable_value)
+                        })
+                        .collect(),
+                ),
+                _ => CoercedValue::List(vec![coerce_validated_value(
+                    schema_definition,
+                    inner,
+                    value,
+                    variable_value,
+                )]),
+            },
+            InputTypeReference::Base(_, _) => match input_type.base(schema_definition) {
+                BaseInputTypeReference::BuiltinScalar(bstd) => match (bstd, core_value) {
+                    (BuiltinScalarDefinition::Float, ValueReference::Integer(i)) => {
+                        CoercedValue::Float(i.into())
+                    }
+                    (BuiltinScalarDefinition::ID, ValueReference::Integer(i)) => {
+                        CoercedValue::String(i.to_string())
+                    }
+                    _ => CoercedValue::from_value(value, variable_value),
+                },
+                BaseInputTypeReference::CustomScalar(_) => {
+                    CoercedValue::from_value(value, variable_value)
+                }
+                BaseInputTypeReference::Enum(_) => match core_value {
+                    ValueReference::Enum(name) | ValueReference::String(name) => {
+                        CoercedValue::Enum(name.to_owned())
+                    }
+                    _ => CoercedValue::from_value(value, variable_value),
+                },
+                BaseInputTypeReference::InputObject(iotd) => match core_value {
+                    ValueReference::Object(object) => CoercedValue::Object(
+                        iotd.input_field_definitions()
+                            .iter()
+                            .filter_map(|ivd| {
+                                coerce_input_field_value(
+                                    schema_definition,
+                                    ivd,
+                                    object
+                                        .iter()
+                                        .find(|(key, _)| key.as_ref() == ivd.name())
+                                        .map(|(_, value)| value),
+                                    variable_value,
+                                )
+                                .map(|value| (ivd.name().to_owned(), value))
+                            })
+                            .collect(),
+                    ),
+                    _ => CoercedValue::from_value(value, variable_value),
+                },
+            },
+        },
+    }
+}
+
+/// Coerces the value for an input field or argument, falling back to its default value when
+/// no value, or an unprovided variable, was given. Returns `None` when the field should be
+/// omitted from the coerced result.
+fn coerce_input_field_value<const CONST: bool, S: SchemaDefinition, V: Value<CONST>>(
+    schema_definition: &S,
+    input_value_definition: &S::InputValueDefinition,
+    value: Option<&V>,
+    variable_value: &impl Fn(&str) -> Option<CoercedValue>,
+) -> Option<CoercedValue> {
+    let provided_value = value.filter(|value| match value.as_ref() {
+        ValueReference::Variable(variable) => variable_value(variable.name()).is_some(),
+        _ => true,
+    });
+
+    match provided_value {
+        Some(value) => Some(coerce_validated_value(
+            schema_definition,
+            input_value_definition.r#type(),
+            value,
+            variable_value,
+        )),
+        None => input_value_definition.default_value().map(|default_value| {
+            coerce_validated_value(
+                schema_definition,
+                input_value_definition.r#type(),
+                default_value,
+                variable_value,
+            )
+        }),
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<CoercedValue> for serde_json::Value {
+    fn from(value: CoercedValue) -> Self {
+        match value {
+            CoercedValue::Null => Self::Null,
+            CoercedValue::Integer(i) => Self::from(i),
+            CoercedValue::Float(f) => Self::from(f),
+            CoercedValue::String(s) | CoercedValue::Enum(s) => Self::String(s),
+            CoercedValue::Boolean(b) => Self::Bool(b),
+            CoercedValue::List(l) => Self::Array(l.into_iter().map(Self::from).collect()),
+            CoercedValue::Object(o) => Self::Object(
+                o.into_iter()
+                    .map(|(key, value)| (key, Self::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
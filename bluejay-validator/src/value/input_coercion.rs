@@ -1,3 +1,4 @@
+use crate::utils::suggestion_list;
 use crate::Path;
 use bluejay_core::definition::{
     BaseInputTypeReference, EnumTypeDefinition, EnumValueDefinition, InputFieldsDefinition,
@@ -241,6 +242,13 @@ fn coerce_enum_value_from_name<'a, const CONST: bool, V: Value<CONST>>(
             name,
             value,
             enum_type_name: enum_type_definition.name(),
+            suggestions: suggestion_list(
+                name,
+                enum_type_definition
+                    .enum_value_definitions()
+                    .iter()
+                    .map(EnumValueDefinition::name),
+            ),
             path,
         }])
     }
@@ -303,6 +311,13 @@ fn coerce_input_object_value<
                 .map(|(field, _)| Error::NoInputFieldWithName {
                     field,
                     input_object_type_name: input_object_type_definition.name(),
+                    suggestions: suggestion_list(
+                        field.as_ref(),
+                        input_object_type_definition
+                            .input_field_definitions()
+                            .iter()
+                            .map(InputValueDefinition::name),
+                    ),
                     path: path.push(field.as_ref()),
                 }),
         );
@@ -768,6 +783,7 @@ mod tests {
                 name: "first",
                 value: &json!("first"),
                 enum_type_name: "Choices",
+                suggestions: vec!["FIRST"],
                 path: Default::default(),
             }]),
             SCHEMA_DEFINITION.coerce_const_value(it, &json!("first"), Default::default()),
@@ -814,6 +830,7 @@ mod tests {
             Err(vec![Error::NoInputFieldWithName {
                 field: &"notDefined".to_owned(),
                 input_object_type_name: "CustomInput",
+                suggestions: Vec::new(),
                 path: Path::new("notDefined"),
             }]),
             SCHEMA_DEFINITION.coerce_const_value(
@@ -1,3 +1,4 @@
+use crate::utils::with_did_you_mean;
 use crate::Path;
 use bluejay_core::{ObjectValue, Value};
 #[cfg(feature = "parser-integration")]
@@ -26,6 +27,7 @@ pub enum Error<'a, const CONST: bool, V: Value<CONST>> {
         name: &'a str,
         value: &'a V,
         enum_type_name: &'a str,
+        suggestions: Vec<&'a str>,
         path: Path<'a>,
     },
     NoValueForRequiredFields {
@@ -43,6 +45,7 @@ pub enum Error<'a, const CONST: bool, V: Value<CONST>> {
     NoInputFieldWithName {
         field: &'a <V::Object as ObjectValue<CONST>>::Key,
         input_object_type_name: &'a str,
+        suggestions: Vec<&'a str>,
         path: Path<'a>,
     },
     CustomScalarInvalidValue {
@@ -77,8 +80,9 @@ impl<const CONST: bool, V: Value<CONST>> Error<'_, CONST, V> {
             Self::NoImplicitConversion { input_type_name, value, .. } => {
                 format!("No implicit conversion of {} to {input_type_name}", value.as_ref().variant()).into()
             }
-            Self::NoEnumMemberWithName { name, enum_type_name, .. } => {
-                format!("No member `{name}` on enum {enum_type_name}").into()
+            Self::NoEnumMemberWithName { name, enum_type_name, suggestions, .. } => {
+                with_did_you_mean(format!("No member `{name}` on enum {enum_type_name}"), suggestions)
+                    .into()
             }
             Self::NoValueForRequiredFields {
                 field_names, input_object_type_name, ..
@@ -92,10 +96,13 @@ impl<const CONST: bool, V: Value<CONST>> Error<'_, CONST, V> {
             Self::NonUniqueFieldNames { field_name, .. } => {
                 format!("Object with multiple entries for field {field_name}").into()
             }
-            Self::NoInputFieldWithName { field, input_object_type_name, .. } => {
-                format!(
-                    "No field with name {} on input type {input_object_type_name}",
-                    field.as_ref()
+            Self::NoInputFieldWithName { field, input_object_type_name, suggestions, .. } => {
+                with_did_you_mean(
+                    format!(
+                        "No field with name {} on input type {input_object_type_name}",
+                        field.as_ref()
+                    ),
+                    suggestions,
                 )
                 .into()
             }
@@ -117,10 +124,103 @@ impl<const CONST: bool, V: Value<CONST>> Error<'_, CONST, V> {
     }
 }
 
+impl<'a, const CONST: bool, V: Value<CONST>> Error<'a, CONST, V> {
+    /// A stable, machine-readable code identifying the kind of error, e.g. `ENUM_VALUE_NOT_DEFINED`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NullValueForRequiredType { .. } => "VALUE_NULL_FOR_NON_NULL_TYPE",
+            Self::NoImplicitConversion { .. } => "VALUE_TYPE_MISMATCH",
+            Self::NoEnumMemberWithName { .. } => "ENUM_VALUE_NOT_DEFINED",
+            Self::NoValueForRequiredFields { .. } => "REQUIRED_INPUT_FIELD_MISSING",
+            Self::NonUniqueFieldNames { .. } => "INPUT_FIELD_NAME_NOT_UNIQUE",
+            Self::NoInputFieldWithName { .. } => "INPUT_FIELD_NOT_DEFINED",
+            Self::CustomScalarInvalidValue { .. } => "CUSTOM_SCALAR_VALUE_INVALID",
+            #[cfg(feature = "one-of-input-objects")]
+            Self::OneOfInputNullValues { .. } => "ONE_OF_INPUT_NULL_VALUES",
+            #[cfg(feature = "one-of-input-objects")]
+            Self::OneOfInputNotSingleNonNullValue { .. } => {
+                "ONE_OF_INPUT_NOT_SINGLE_NON_NULL_VALUE"
+            }
+        }
+    }
+
+    /// Link to the validation rule in the GraphQL specification that the error violates
+    pub fn spec_section(&self) -> &'static str {
+        match self {
+            Self::NoValueForRequiredFields { .. } => spec_section!("Input-Object-Required-Fields"),
+            Self::NonUniqueFieldNames { .. } => spec_section!("Input-Object-Field-Uniqueness"),
+            Self::NoInputFieldWithName { .. } => spec_section!("Input-Object-Field-Names"),
+            _ => spec_section!("Values-of-Correct-Type"),
+        }
+    }
+
+    /// Structured details about the error, such as the names of the type and fields involved
+    pub fn extensions(&self) -> Vec<(&'static str, Cow<'a, str>)> {
+        match self {
+            Self::NullValueForRequiredType {
+                input_type_name, ..
+            }
+            | Self::NoImplicitConversion {
+                input_type_name, ..
+            } => vec![("typeName", Cow::Owned(input_type_name.clone()))],
+            Self::NoEnumMemberWithName {
+                name,
+                enum_type_name,
+                ..
+            } => vec![
+                ("typeName", Cow::Borrowed(*enum_type_name)),
+                ("enumValue", Cow::Borrowed(*name)),
+            ],
+            Self::NoValueForRequiredFields {
+                field_names,
+                input_object_type_name,
+                ..
+            } => vec![
+                ("typeName", Cow::Borrowed(*input_object_type_name)),
+                ("inputFieldNames", Cow::Owned(field_names.join(", "))),
+            ],
+            Self::NonUniqueFieldNames { field_name, .. } => {
+                vec![("inputFieldName", Cow::Borrowed(*field_name))]
+            }
+            Self::NoInputFieldWithName {
+                field,
+                input_object_type_name,
+                ..
+            } => vec![
+                ("typeName", Cow::Borrowed(*input_object_type_name)),
+                (
+                    "inputFieldName",
+                    Cow::Borrowed(AsRef::<str>::as_ref(*field)),
+                ),
+            ],
+            Self::CustomScalarInvalidValue {
+                custom_scalar_type_name,
+                ..
+            } => vec![("typeName", Cow::Borrowed(*custom_scalar_type_name))],
+            #[cfg(feature = "one-of-input-objects")]
+            Self::OneOfInputNullValues {
+                input_object_type_name,
+                ..
+            }
+            | Self::OneOfInputNotSingleNonNullValue {
+                input_object_type_name,
+                ..
+            } => vec![("typeName", Cow::Borrowed(*input_object_type_name))],
+        }
+    }
+}
+
 #[cfg(feature = "parser-integration")]
 impl<'a, const CONST: bool> From<Error<'a, CONST, ParserValue<'a, CONST>>> for ParserError {
     fn from(error: Error<'a, CONST, ParserValue<'a, CONST>>) -> Self {
-        match &error {
+        let code = error.code();
+        let spec_section = error.spec_section();
+        let extensions: Vec<_> = error
+            .extensions()
+            .into_iter()
+            .map(|(key, value)| (key, value.into_owned()))
+            .collect();
+        let parser_error = match &error {
             Error::NullValueForRequiredType { value, .. } => Self::new(
                 error.message(),
                 Some(Annotation::new(
@@ -144,11 +244,15 @@ impl<'a, const CONST: bool> From<Error<'a, CONST, ParserValue<'a, CONST>>> for P
             Error::NoEnumMemberWithName {
                 value,
                 enum_type_name,
+                suggestions,
                 ..
             } => Self::new(
                 error.message(),
                 Some(Annotation::new(
-                    format!("No such member on enum {enum_type_name}"),
+                    with_did_you_mean(
+                        format!("No such member on enum {enum_type_name}"),
+                        suggestions,
+                    ),
                     value.span().clone(),
                 )),
                 Vec::new(),
@@ -177,11 +281,15 @@ impl<'a, const CONST: bool> From<Error<'a, CONST, ParserValue<'a, CONST>>> for P
             Error::NoInputFieldWithName {
                 field,
                 input_object_type_name,
+                suggestions,
                 ..
             } => Self::new(
                 error.message(),
                 Some(Annotation::new(
-                    format!("No field with this name on input type {input_object_type_name}"),
+                    with_did_you_mean(
+                        format!("No field with this name on input type {input_object_type_name}"),
+                        suggestions,
+                    ),
                     field.span().clone(),
                 )),
                 Vec::new(),
@@ -227,6 +335,10 @@ impl<'a, const CONST: bool> From<Error<'a, CONST, ParserValue<'a, CONST>>> for P
                     })
                     .collect(),
             ),
-        }
+        };
+        parser_error
+            .with_code(code)
+            .with_spec_section(spec_section)
+            .with_extensions(extensions)
     }
 }
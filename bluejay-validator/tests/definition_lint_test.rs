@@ -0,0 +1,96 @@
+use bluejay_parser::{
+    ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        Parse,
+    },
+    Error,
+};
+use bluejay_validator::definition::lint::{BuiltinLintRulesLinter, LintConfig, Severity};
+
+const RULE_NAMES: [&str; 5] = [
+    "deprecation-reasons",
+    "field-descriptions",
+    "mutation-input-payload",
+    "naming-conventions",
+    "relay-connections",
+];
+
+/// Runs only the rule matching the file name, or all rules for other files
+fn config_for(file_stem: &str) -> LintConfig {
+    if RULE_NAMES.contains(&file_stem) {
+        RULE_NAMES
+            .into_iter()
+            .filter(|rule| *rule != file_stem)
+            .fold(LintConfig::default(), LintConfig::without_rule)
+    } else {
+        LintConfig::default()
+    }
+}
+
+#[test]
+fn test_lint() {
+    insta::glob!("test_data/definition/lint/*.graphql", |path| {
+        let input = std::fs::read_to_string(path).unwrap();
+        let definition_document: DefinitionDocument = DefinitionDocument::parse(&input)
+            .result
+            .unwrap_or_else(|_| panic!("Schema `{}` had parse errors", path.display()));
+        let schema_definition = SchemaDefinition::try_from(&definition_document)
+            .unwrap_or_else(|_| panic!("Schema `{}` had coercion errors", path.display()));
+
+        let config = config_for(path.file_stem().and_then(|f| f.to_str()).unwrap());
+        let violations = BuiltinLintRulesLinter::lint(&schema_definition, &config);
+
+        let formatted_violations = Error::format_errors(
+            &input,
+            path.file_name().and_then(|f| f.to_str()),
+            violations,
+        );
+        insta::assert_snapshot!(formatted_violations);
+    });
+}
+
+#[test]
+fn test_severity_overrides() {
+    let s = r#"
+        type Query {
+          Foo: String @deprecated
+        }
+    "#;
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(s)
+        .result
+        .expect("Document had parse errors");
+    let schema_definition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+
+    let violations = BuiltinLintRulesLinter::lint(&schema_definition, &LintConfig::default());
+    let mut rules: Vec<_> = violations
+        .iter()
+        .map(|violation| (violation.rule(), violation.severity()))
+        .collect();
+    rules.sort();
+    assert_eq!(
+        vec![
+            ("deprecation-reasons", Severity::Warning),
+            ("field-descriptions", Severity::Warning),
+            ("naming-conventions", Severity::Warning),
+        ],
+        rules,
+    );
+
+    let config = LintConfig::default()
+        .with_severity("naming-conventions", Severity::Error)
+        .without_rule("field-descriptions");
+    let violations = BuiltinLintRulesLinter::lint(&schema_definition, &config);
+    let mut rules: Vec<_> = violations
+        .iter()
+        .map(|violation| (violation.rule(), violation.severity()))
+        .collect();
+    rules.sort();
+    assert_eq!(
+        vec![
+            ("deprecation-reasons", Severity::Warning),
+            ("naming-conventions", Severity::Error),
+        ],
+        rules,
+    );
+}
@@ -0,0 +1,249 @@
+use bluejay_core::definition::{
+    DirectiveDefinition, EnumTypeDefinition, EnumValueDefinition, FieldDefinition,
+    InputObjectTypeDefinition, InputValueDefinition, InterfaceTypeDefinition, ObjectTypeDefinition,
+    ScalarTypeDefinition, SchemaDefinition, TypeDefinitionReference, UnionTypeDefinition,
+};
+use bluejay_core::Directive;
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition as ParserSchemaDefinition},
+    Parse,
+};
+use bluejay_validator::definition::{ArgumentDefinitionParent, Rule, Validator, Visitor};
+
+/// Records every node it visits, skipping builtin definitions.
+/// Arguments are visited right after their field or directive definition.
+struct NodeRecorder<'a, S: SchemaDefinition> {
+    schema_definition: &'a S,
+    visited: Vec<String>,
+    in_builtin_definition: bool,
+}
+
+impl<'a, S: SchemaDefinition> Visitor<'a, S> for NodeRecorder<'a, S> {
+    fn visit_custom_scalar_type_definition(
+        &mut self,
+        custom_scalar_type_definition: &'a S::CustomScalarTypeDefinition,
+    ) {
+        self.visited
+            .push(format!("scalar {}", custom_scalar_type_definition.name()));
+    }
+
+    fn visit_object_type_definition(
+        &mut self,
+        object_type_definition: &'a S::ObjectTypeDefinition,
+    ) {
+        if !object_type_definition.is_builtin() {
+            self.visited
+                .push(format!("type {}", object_type_definition.name()));
+        }
+    }
+
+    fn visit_interface_type_definition(
+        &mut self,
+        interface_type_definition: &'a S::InterfaceTypeDefinition,
+    ) {
+        self.visited
+            .push(format!("interface {}", interface_type_definition.name()));
+    }
+
+    fn visit_union_type_definition(&mut self, union_type_definition: &'a S::UnionTypeDefinition) {
+        self.visited
+            .push(format!("union {}", union_type_definition.name()));
+    }
+
+    fn visit_input_object_type_definition(
+        &mut self,
+        input_object_type_definition: &'a S::InputObjectTypeDefinition,
+    ) {
+        self.visited
+            .push(format!("input {}", input_object_type_definition.name()));
+    }
+
+    fn visit_enum_type_definition(&mut self, enum_type_definition: &'a S::EnumTypeDefinition) {
+        if !enum_type_definition.is_builtin() {
+            self.visited
+                .push(format!("enum {}", enum_type_definition.name()));
+        }
+    }
+
+    fn visit_directive_definition(&mut self, directive_definition: &'a S::DirectiveDefinition) {
+        self.in_builtin_definition = directive_definition.is_builtin();
+        if !self.in_builtin_definition {
+            self.visited
+                .push(format!("directive @{}", directive_definition.name()));
+        }
+    }
+
+    fn visit_field_definition(
+        &mut self,
+        field_definition: &'a S::FieldDefinition,
+        parent_type: TypeDefinitionReference<'a, S::TypeDefinition>,
+    ) {
+        self.in_builtin_definition = field_definition.is_builtin() || parent_type.is_builtin();
+        if !self.in_builtin_definition {
+            self.visited.push(format!(
+                "field {}.{}",
+                parent_type.name(),
+                field_definition.name()
+            ));
+        }
+    }
+
+    fn visit_argument_definition(
+        &mut self,
+        argument_definition: &'a S::InputValueDefinition,
+        parent: ArgumentDefinitionParent<'a, S>,
+    ) {
+        if !self.in_builtin_definition {
+            let parent_name = match parent {
+                ArgumentDefinitionParent::FieldDefinition {
+                    field_definition,
+                    parent_type,
+                } => format!("{}.{}", parent_type.name(), field_definition.name()),
+                ArgumentDefinitionParent::DirectiveDefinition(directive_definition) => {
+                    format!("@{}", directive_definition.name())
+                }
+            };
+            self.visited.push(format!(
+                "argument {}({}:)",
+                parent_name,
+                argument_definition.name()
+            ));
+        }
+    }
+
+    fn visit_input_field_definition(
+        &mut self,
+        input_field_definition: &'a S::InputValueDefinition,
+        input_object_type_definition: &'a S::InputObjectTypeDefinition,
+    ) {
+        self.visited.push(format!(
+            "input field {}.{}",
+            input_object_type_definition.name(),
+            input_field_definition.name()
+        ));
+    }
+
+    fn visit_enum_value_definition(
+        &mut self,
+        enum_value_definition: &'a S::EnumValueDefinition,
+        enum_type_definition: &'a S::EnumTypeDefinition,
+    ) {
+        if !enum_type_definition.is_builtin() {
+            self.visited.push(format!(
+                "enum value {}.{}",
+                enum_type_definition.name(),
+                enum_value_definition.name()
+            ));
+        }
+    }
+
+    fn visit_directive(&mut self, directive: &'a S::Directive) {
+        if !self
+            .schema_definition
+            .get_directive_definition(directive.name())
+            .is_some_and(|directive_definition| {
+                directive_definition.is_builtin() && directive.name() != "deprecated"
+            })
+        {
+            self.visited.push(format!("@{}", directive.name()));
+        }
+    }
+}
+
+impl<S: SchemaDefinition> IntoIterator for NodeRecorder<'_, S> {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.visited.into_iter()
+    }
+}
+
+impl<'a, S: SchemaDefinition> Rule<'a, S> for NodeRecorder<'a, S> {
+    type Error = String;
+
+    fn new(schema_definition: &'a S) -> Self {
+        Self {
+            schema_definition,
+            visited: Vec::new(),
+            in_builtin_definition: false,
+        }
+    }
+}
+
+#[test]
+fn test_visits_every_node() {
+    let s = r#"
+        directive @tag(name: String! @tag(name: "argument")) repeatable on SCHEMA | SCALAR | OBJECT | FIELD_DEFINITION | ARGUMENT_DEFINITION | INTERFACE | UNION | ENUM | ENUM_VALUE | INPUT_OBJECT | INPUT_FIELD_DEFINITION
+
+        schema @tag(name: "schema") {
+          query: Query
+        }
+
+        scalar Url @tag(name: "scalar")
+
+        interface Node @tag(name: "interface") {
+          id: ID! @tag(name: "interface field")
+        }
+
+        type Query implements Node @tag(name: "object") {
+          id: ID!
+          search(filter: Filter, order: Order = ASC @tag(name: "argument")): Result @deprecated
+        }
+
+        union Result @tag(name: "union") = Query
+
+        input Filter @tag(name: "input") {
+          url: Url @tag(name: "input field")
+        }
+
+        enum Order @tag(name: "enum") {
+          ASC @tag(name: "enum value")
+          DESC
+        }
+    "#;
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(s)
+        .result
+        .expect("Document had parse errors");
+    let schema_definition =
+        ParserSchemaDefinition::try_from(&definition_document).expect("Schema had coercion errors");
+
+    let mut visited: Vec<String> =
+        Validator::<_, NodeRecorder<_>>::validate(&schema_definition).collect();
+    visited.sort();
+
+    let mut expected = vec![
+        "@deprecated",
+        "@tag",
+        "@tag",
+        "@tag",
+        "@tag",
+        "@tag",
+        "@tag",
+        "@tag",
+        "@tag",
+        "@tag",
+        "@tag",
+        "@tag",
+        "@tag",
+        "argument @tag(name:)",
+        "argument Query.search(filter:)",
+        "argument Query.search(order:)",
+        "directive @tag",
+        "enum Order",
+        "enum value Order.ASC",
+        "enum value Order.DESC",
+        "field Node.id",
+        "field Query.id",
+        "field Query.search",
+        "input Filter",
+        "input field Filter.url",
+        "interface Node",
+        "scalar Url",
+        "type Query",
+        "union Result",
+    ];
+    expected.sort();
+
+    assert_eq!(expected, visited);
+}
@@ -0,0 +1,77 @@
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use bluejay_validator::executable::{
+    document::{BuiltinRules, BuiltinRulesValidator, DocumentBatch},
+    Cache, SchemaCache,
+};
+
+const SCHEMA: &str = r#"
+    type Query {
+        foo: String!
+        bar(baz: Int!): String!
+    }
+"#;
+
+#[test]
+fn test_document_batch_matches_individual_validation() {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let schema_cache = SchemaCache::new(&schema_definition);
+    let sources: Vec<String> = (0..50).map(|i| match i % 3 {
+        0 => format!("query Q{i} {{ foo }}"),
+        1 => format!("query Q{i} {{ unknown{i} bar }}"),
+        _ => format!("query Q{i} {{ ...F }} fragment F on Query {{ foo }} fragment G on Query {{ bar(baz: {i}) }}"),
+    }).collect();
+    let executable_documents: Vec<ExecutableDocument> = sources
+        .iter()
+        .map(|source| {
+            ExecutableDocument::parse(source)
+                .result
+                .expect("Document had parse errors")
+        })
+        .collect();
+
+    let batch = DocumentBatch::new(&executable_documents, &schema_definition, &schema_cache);
+    assert_eq!(50, batch.len());
+    let batch_errors: Vec<Vec<&str>> = batch
+        .validate::<BuiltinRules<_, _>>()
+        .iter()
+        .map(|errors| errors.iter().map(|error| error.code()).collect())
+        .collect();
+
+    let individual_errors: Vec<Vec<&str>> = executable_documents
+        .iter()
+        .map(|executable_document| {
+            let cache = Cache::new(executable_document, &schema_definition);
+            BuiltinRulesValidator::validate(executable_document, &schema_definition, &cache)
+                .map(|error| error.code())
+                .collect()
+        })
+        .collect();
+
+    assert_eq!(individual_errors, batch_errors);
+
+    #[cfg(feature = "rayon")]
+    {
+        let parallel_batch =
+            DocumentBatch::par_new(&executable_documents, &schema_definition, &schema_cache);
+        let parallel_batch_errors: Vec<Vec<&str>> = parallel_batch
+            .par_validate::<BuiltinRules<_, _>>()
+            .iter()
+            .map(|errors| errors.iter().map(|error| error.code()).collect())
+            .collect();
+        assert_eq!(batch_errors, parallel_batch_errors);
+    }
+    assert_eq!(Vec::<&str>::new(), batch_errors[0]);
+    assert_eq!(
+        vec!["FIELD_NOT_DEFINED", "REQUIRED_ARGUMENT_MISSING"],
+        batch_errors[1],
+    );
+    assert_eq!(vec!["FRAGMENT_UNUSED"], batch_errors[2]);
+}
@@ -1,12 +1,17 @@
+use bluejay_core::Value;
 use bluejay_parser::{
     ast::{
-        definition::{DefinitionDocument, SchemaDefinition},
+        definition::{Context, CustomScalarTypeDefinition, DefinitionDocument, SchemaDefinition},
         executable::ExecutableDocument,
         Parse,
     },
     Error,
 };
-use bluejay_validator::executable::{document::BuiltinRulesValidator, Cache};
+use bluejay_validator::executable::{
+    document::{BuiltinRule, BuiltinRulesValidator, RuleSet},
+    Cache,
+};
+use std::borrow::Cow;
 
 #[test]
 fn test_error() {
@@ -55,7 +60,141 @@ fn test_valid() {
     });
 }
 
-fn with_schema(f: fn(SchemaDefinition) -> ()) {
+#[test]
+fn test_graphql_error_extensions() {
+    with_schema(|schema_definition| {
+        let input = "query { dog { nmae } }";
+        let executable_document = ExecutableDocument::parse(input)
+            .result
+            .expect("Document had parse errors");
+        let cache = Cache::new(&executable_document, &schema_definition);
+        let errors =
+            BuiltinRulesValidator::validate(&executable_document, &schema_definition, &cache);
+        let graphql_errors = Error::into_graphql_errors(input, errors);
+
+        assert_eq!(1, graphql_errors.len());
+        assert_eq!(
+            vec![
+                ("code", "FIELD_NOT_DEFINED"),
+                ("fieldName", "nmae"),
+                (
+                    "specSection",
+                    "https://spec.graphql.org/draft/#sec-Field-Selections"
+                ),
+                ("typeName", "Dog"),
+            ],
+            graphql_errors[0]
+                .extensions
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str().unwrap()))
+                .collect::<Vec<_>>(),
+        );
+    });
+}
+
+#[test]
+fn test_schema_defined_stream_directive() {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(
+        r#"
+        directive @stream(label: String) on FIELD
+
+        type Query {
+            name: String
+            nickname: String
+        }
+        "#,
+    )
+    .result
+    .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let input = r#"query { name @stream(label: "a") nickname @stream(label: "a") }"#;
+    let executable_document = ExecutableDocument::parse(input)
+        .result
+        .expect("Document had parse errors");
+    let cache = Cache::new(&executable_document, &schema_definition);
+    assert_eq!(
+        0,
+        BuiltinRulesValidator::validate(&executable_document, &schema_definition, &cache).count()
+    );
+}
+
+#[test]
+fn test_rule_set_matches_builtin_rules() {
+    with_schema(|schema_definition| {
+        insta::glob!("test_data/executable/error/*.graphql", |path| {
+            let input = std::fs::read_to_string(path).unwrap();
+            let executable_document = ExecutableDocument::parse(input.as_str())
+                .result
+                .expect("Document had parse errors");
+            let cache = Cache::new(&executable_document, &schema_definition);
+            let format = |errors: Vec<_>| Error::format_errors(input.as_str(), None, errors);
+            assert_eq!(
+                format(
+                    BuiltinRulesValidator::validate(
+                        &executable_document,
+                        &schema_definition,
+                        &cache
+                    )
+                    .collect()
+                ),
+                format(
+                    RuleSet::all()
+                        .validate(&executable_document, &schema_definition, &cache)
+                        .collect()
+                ),
+            );
+        });
+    });
+}
+
+#[test]
+fn test_rule_set_from_names() {
+    with_schema(|schema_definition| {
+        let input = "query { dog { name } } fragment unused on Dog { name }";
+        let executable_document = ExecutableDocument::parse(input)
+            .result
+            .expect("Document had parse errors");
+        let cache = Cache::new(&executable_document, &schema_definition);
+
+        let rule_set = RuleSet::from_names(["FieldSelections", "FragmentsMustBeUsed"]).unwrap();
+        assert!(rule_set.contains(BuiltinRule::FragmentsMustBeUsed));
+        let codes: Vec<_> = rule_set
+            .validate(&executable_document, &schema_definition, &cache)
+            .map(|error| error.code())
+            .collect();
+        assert_eq!(vec!["FRAGMENT_UNUSED"], codes);
+
+        let rule_set = rule_set.without_rule(BuiltinRule::FragmentsMustBeUsed);
+        assert_eq!(
+            0,
+            rule_set
+                .validate(&executable_document, &schema_definition, &cache)
+                .count()
+        );
+
+        let error = RuleSet::from_names(["FieldSelections", "NotARule"]).unwrap_err();
+        assert_eq!("NotARule", error.name());
+    });
+}
+
+#[derive(Debug)]
+struct IncrementalDeliveryContext;
+
+impl Context for IncrementalDeliveryContext {
+    fn coerce_custom_scalar_input<const CONST: bool>(
+        _cstd: &CustomScalarTypeDefinition<Self>,
+        _value: &impl Value<CONST>,
+    ) -> Result<(), Cow<'static, str>> {
+        Ok(())
+    }
+
+    fn incremental_delivery_directives() -> bool {
+        true
+    }
+}
+
+fn with_schema(f: fn(SchemaDefinition<IncrementalDeliveryContext>) -> ()) {
     let s = std::fs::read_to_string("tests/test_data/executable/schema.graphql").unwrap();
     let definition_document = DefinitionDocument::parse(s.as_str())
         .result
@@ -0,0 +1,234 @@
+use bluejay_parser::{
+    ast::{
+        definition::{DefinitionDocument, SchemaDefinition},
+        executable::ExecutableDocument,
+        Parse,
+    },
+    Error,
+};
+use bluejay_validator::executable::{
+    document::{
+        lint::{BuiltinLintRulesLinter, LintConfig, Linter, MaxFragmentSelections, Severity},
+        DocumentBatch,
+    },
+    Cache, SchemaCache,
+};
+
+const SCHEMA: &str = r#"
+    interface Node {
+        id: ID!
+        legacyHandle: String
+    }
+
+    type User implements Node {
+        id: ID!
+        name: String!
+        email: String
+        legacyHandle: String
+        friends: [User!]!
+    }
+
+    type Query {
+        node(id: ID!): Node
+        viewer: User!
+    }
+"#;
+
+const RULE_NAMES: [&str; 7] = [
+    "forbidden-fields",
+    "max-fragment-selections",
+    "named-operations",
+    "no-anonymous-inline-fragments",
+    "no-duplicate-fields",
+    "typename-with-interface-id",
+    "variable-naming-conventions",
+];
+
+/// Runs only the rule matching the file name
+fn config_for(file_stem: &str) -> LintConfig {
+    assert!(
+        RULE_NAMES.contains(&file_stem),
+        "Unknown rule `{file_stem}`"
+    );
+    RULE_NAMES
+        .into_iter()
+        .filter(|rule| *rule != file_stem)
+        .fold(
+            LintConfig::default()
+                .with_forbidden_field("User", "legacyHandle")
+                .with_forbidden_field("User", "email"),
+            LintConfig::without_rule,
+        )
+}
+
+fn with_schema(f: impl FnOnce(&SchemaDefinition)) {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    f(&schema_definition)
+}
+
+fn parse(source: &str) -> ExecutableDocument {
+    ExecutableDocument::parse(source)
+        .result
+        .expect("Document had parse errors")
+}
+
+#[test]
+fn test_lint() {
+    with_schema(|schema_definition| {
+        insta::glob!("test_data/executable/lint/*.graphql", |path| {
+            let input = std::fs::read_to_string(path).unwrap();
+            let executable_document = parse(&input);
+            let cache = Cache::new(&executable_document, schema_definition);
+
+            let config = config_for(path.file_stem().and_then(|f| f.to_str()).unwrap());
+            let violations = BuiltinLintRulesLinter::lint(
+                &executable_document,
+                schema_definition,
+                &cache,
+                &config,
+            );
+
+            let formatted_violations = Error::format_errors(
+                &input,
+                path.file_name().and_then(|f| f.to_str()),
+                violations,
+            );
+            insta::assert_snapshot!(formatted_violations);
+        });
+    });
+}
+
+#[test]
+fn test_severity_overrides() {
+    with_schema(|schema_definition| {
+        let executable_document = parse("query($user_id: ID!) { node(id: $user_id) { id } }");
+        let cache = Cache::new(&executable_document, schema_definition);
+        let lint = |config: &LintConfig| -> Vec<(&str, Severity)> {
+            let mut rules: Vec<_> = BuiltinLintRulesLinter::lint(
+                &executable_document,
+                schema_definition,
+                &cache,
+                config,
+            )
+            .iter()
+            .map(|violation| (violation.rule(), violation.severity()))
+            .collect();
+            rules.sort();
+            rules
+        };
+
+        assert_eq!(
+            vec![
+                ("named-operations", Severity::Warning),
+                ("typename-with-interface-id", Severity::Warning),
+                ("variable-naming-conventions", Severity::Warning),
+            ],
+            lint(&LintConfig::default()),
+        );
+        assert_eq!(
+            vec![
+                ("named-operations", Severity::Error),
+                ("variable-naming-conventions", Severity::Warning),
+            ],
+            lint(
+                &LintConfig::default()
+                    .with_severity("named-operations", Severity::Error)
+                    .without_rule("typename-with-interface-id")
+            ),
+        );
+    });
+}
+
+#[test]
+fn test_configured_rules() {
+    with_schema(|schema_definition| {
+        let executable_document = parse(
+            r#"
+            query ViewerQuery { viewer { ...UserFields legacyHandle } }
+            fragment UserFields on User { name friends { legacyHandle } }
+            "#,
+        );
+        let cache = Cache::new(&executable_document, schema_definition);
+        let config = LintConfig::default()
+            .with_forbidden_field("User", "legacyHandle")
+            .with_forbidden_field("Query", "node");
+        let violations = Linter::<_, _, MaxFragmentSelections<_, 2>>::lint(
+            &executable_document,
+            schema_definition,
+            &cache,
+            &config,
+        );
+        let messages: Vec<(&str, Severity, &str)> = violations
+            .iter()
+            .map(|violation| (violation.rule(), violation.severity(), violation.message()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (
+                    "max-fragment-selections",
+                    Severity::Warning,
+                    "Fragment `UserFields` selects 3 fields, more than the maximum of 2",
+                ),
+                (
+                    "forbidden-fields",
+                    Severity::Error,
+                    "Field `User.legacyHandle` is forbidden",
+                ),
+                (
+                    "forbidden-fields",
+                    Severity::Error,
+                    "Field `User.legacyHandle` is forbidden",
+                ),
+            ],
+            messages,
+        );
+    });
+}
+
+#[test]
+fn test_lint_batch_operation_name_uniqueness() {
+    with_schema(|schema_definition| {
+        let schema_cache = SchemaCache::new(schema_definition);
+        let executable_documents = [
+            parse("query ViewerQuery { viewer { name } }"),
+            parse("query OtherQuery { viewer { name } }"),
+            parse("query ViewerQuery { viewer { email } }"),
+        ];
+        let batch = DocumentBatch::new(&executable_documents, schema_definition, &schema_cache);
+
+        let violations = BuiltinLintRulesLinter::lint_batch(&batch, &LintConfig::default());
+        let rules: Vec<Vec<(&str, &str)>> = violations
+            .iter()
+            .map(|violations| {
+                violations
+                    .iter()
+                    .map(|violation| (violation.rule(), violation.message()))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                vec![(
+                    "unique-operation-names",
+                    "Operation name `ViewerQuery` is used by 2 operations",
+                )],
+                vec![],
+                vec![(
+                    "unique-operation-names",
+                    "Operation name `ViewerQuery` is used by 2 operations",
+                )],
+            ],
+            rules,
+        );
+
+        let config = LintConfig::default().without_rule("unique-operation-names");
+        assert!(BuiltinLintRulesLinter::lint_batch(&batch, &config)
+            .iter()
+            .all(Vec::is_empty));
+    });
+}
@@ -0,0 +1,184 @@
+use bluejay_core::executable::{Field, FragmentDefinition};
+use bluejay_core::Variable;
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use bluejay_validator::executable::{
+    document::{BuiltinRules, BuiltinRulesValidator, DocumentBatch, Error, Project},
+    Cache, SchemaCache,
+};
+
+const SCHEMA: &str = r#"
+    type User {
+        id: ID!
+        name: String!
+        avatar(size: Int): String
+    }
+
+    type Query {
+        viewer: User!
+    }
+"#;
+
+const SOURCES: [&str; 3] = [
+    "query ViewerQuery($size: Int) { viewer { ...UserFields } } query OtherQuery { viewer { ...UserFields } }",
+    "fragment UserFields on User { name ...AvatarFields } fragment UnusedFields on User { id nickname }",
+    "fragment AvatarFields on User { avatarUrl avatar(size: $size) } fragment UserFields on User { id }",
+];
+
+fn with_documents(f: impl FnOnce(&SchemaDefinition, &[ExecutableDocument])) {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let executable_documents: Vec<ExecutableDocument> = SOURCES
+        .iter()
+        .map(|source| {
+            ExecutableDocument::parse(source)
+                .result
+                .expect("Document had parse errors")
+        })
+        .collect();
+    f(&schema_definition, &executable_documents)
+}
+
+#[test]
+fn test_fragments_resolve_across_documents() {
+    with_documents(|schema_definition, executable_documents| {
+        let cache = Cache::new(&executable_documents[0], schema_definition);
+        let codes: Vec<&str> =
+            BuiltinRulesValidator::validate(&executable_documents[0], schema_definition, &cache)
+                .map(|error| error.code())
+                .collect();
+        assert_eq!(
+            vec![
+                "FRAGMENT_NOT_DEFINED",
+                "FRAGMENT_NOT_DEFINED",
+                "VARIABLE_UNUSED"
+            ],
+            codes
+        );
+
+        let project = Project::new(executable_documents);
+        let fragment_names: Vec<Vec<&str>> = project
+            .documents()
+            .iter()
+            .map(|document| {
+                bluejay_core::executable::ExecutableDocument::fragment_definitions(document)
+                    .map(FragmentDefinition::name)
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                vec!["UserFields", "AvatarFields"],
+                vec!["UserFields", "UnusedFields", "AvatarFields"],
+                vec!["AvatarFields", "UserFields"],
+            ],
+            fragment_names,
+        );
+
+        let schema_cache = SchemaCache::new(schema_definition);
+        let batch = DocumentBatch::new(project.documents(), schema_definition, &schema_cache);
+        let errors: Vec<Vec<(&str, Option<&str>)>> = batch
+            .validate_project::<BuiltinRules<_, _>>()
+            .iter()
+            .map(|errors| {
+                errors
+                    .iter()
+                    .map(|error| {
+                        let field_name = match error {
+                            Error::FieldDoesNotExistOnType { field, .. } => {
+                                Some(Field::name(*field))
+                            }
+                            Error::VariableNotDefined { variable, .. } => {
+                                Some(Variable::name(*variable))
+                            }
+                            _ => None,
+                        };
+                        (error.code(), field_name)
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                vec![
+                    ("FIELD_NOT_DEFINED", Some("avatarUrl")),
+                    ("FIELD_NOT_DEFINED", Some("avatarUrl")),
+                    ("VARIABLE_NOT_DEFINED", Some("size")),
+                ],
+                vec![("FIELD_NOT_DEFINED", Some("nickname"))],
+                vec![("FIELD_NOT_DEFINED", Some("avatarUrl"))],
+            ],
+            errors
+        );
+        #[cfg(feature = "rayon")]
+        {
+            let codes = |errors: Vec<Vec<Error<_, _>>>| -> Vec<Vec<&str>> {
+                errors
+                    .iter()
+                    .map(|errors| errors.iter().map(Error::code).collect())
+                    .collect()
+            };
+            assert_eq!(
+                codes(batch.validate_project::<BuiltinRules<_, _>>()),
+                codes(batch.par_validate_project::<BuiltinRules<_, _>>()),
+            );
+        }
+    });
+}
+
+#[test]
+fn test_duplicate_fragment_definitions() {
+    with_documents(|_, executable_documents| {
+        let project = Project::new(executable_documents);
+        let duplicates: Vec<(&str, Vec<usize>)> = project
+            .duplicate_fragment_definitions()
+            .into_iter()
+            .map(|(name, fragment_definitions)| {
+                (
+                    name,
+                    fragment_definitions
+                        .into_iter()
+                        .map(|(document_index, _)| document_index)
+                        .collect(),
+                )
+            })
+            .collect();
+        assert_eq!(vec![("UserFields", vec![1, 2])], duplicates);
+        assert!(std::ptr::eq(
+            &executable_documents[2].fragment_definitions()[1],
+            project
+                .resolve_fragment_definition(2, "UserFields")
+                .unwrap(),
+        ));
+        assert!(std::ptr::eq(
+            &executable_documents[1].fragment_definitions()[0],
+            project
+                .resolve_fragment_definition(0, "UserFields")
+                .unwrap(),
+        ));
+    });
+}
+
+#[test]
+fn test_unused_fragment_definitions() {
+    with_documents(|_, executable_documents| {
+        let project = Project::new(executable_documents);
+        let unused: Vec<(usize, &str)> = project
+            .unused_fragment_definitions()
+            .into_iter()
+            .map(|(document_index, fragment_definition)| {
+                (
+                    document_index,
+                    FragmentDefinition::name(fragment_definition),
+                )
+            })
+            .collect();
+        assert_eq!(vec![(1, "UnusedFields"), (2, "UserFields")], unused);
+    });
+}
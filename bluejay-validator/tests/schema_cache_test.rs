@@ -0,0 +1,105 @@
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use bluejay_validator::executable::{document::BuiltinRulesValidator, Cache, SchemaCache};
+
+const SCHEMA: &str = r#"
+    type Query {
+        node: Node
+        search: SearchResult
+    }
+
+    interface Node {
+        id: ID!
+    }
+
+    type User implements Node {
+        id: ID!
+        name: String!
+    }
+
+    type Post implements Node {
+        id: ID!
+        title: String!
+    }
+
+    type Comment {
+        body: String!
+    }
+
+    union SearchResult = User | Comment
+"#;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_schema_cache_is_send_and_sync() {
+    assert_send_sync::<SchemaCache>();
+}
+
+#[test]
+fn test_possible_type_names() {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let schema_cache = SchemaCache::new(&schema_definition);
+
+    assert!(schema_cache.is_possible_type("Node", "User"));
+    assert!(schema_cache.is_possible_type("Node", "Post"));
+    assert!(!schema_cache.is_possible_type("Node", "Comment"));
+    assert!(schema_cache.is_possible_type("SearchResult", "Comment"));
+    assert!(schema_cache.is_possible_type("User", "User"));
+    assert!(!schema_cache.is_possible_type("User", "Post"));
+    assert_eq!(None, schema_cache.possible_type_names("String"));
+}
+
+#[test]
+fn test_schema_cache_shared_across_threads() {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let schema_cache = SchemaCache::new(&schema_definition);
+
+    let documents = [
+        ("{ node { id ... on User { name } } }", 0),
+        ("{ search { ... on Post { title } } }", 1),
+        ("{ search { ... on Node { id } } }", 0),
+        ("{ node { ... on Comment { body } } }", 1),
+    ];
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = documents
+            .iter()
+            .map(|&(source, expected_error_count)| {
+                let schema_definition = &schema_definition;
+                let schema_cache = &schema_cache;
+                scope.spawn(move || {
+                    let executable_document = ExecutableDocument::parse(source)
+                        .result
+                        .expect("Document had parse errors");
+                    let cache = Cache::with_schema_cache(
+                        &executable_document,
+                        schema_definition,
+                        schema_cache,
+                    );
+                    let error_count = BuiltinRulesValidator::validate(
+                        &executable_document,
+                        schema_definition,
+                        &cache,
+                    )
+                    .count();
+                    assert_eq!(expected_error_count, error_count, "for {source}");
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .for_each(|handle| handle.join().unwrap());
+    });
+}
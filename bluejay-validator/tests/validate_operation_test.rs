@@ -0,0 +1,106 @@
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+    Parse,
+};
+use bluejay_validator::executable::{
+    document::BuiltinRulesValidator, operation::OperationResolutionError, Cache,
+};
+
+const SCHEMA: &str = r#"
+    type Query {
+        foo: String!
+        user: User
+    }
+
+    type User {
+        name: String!
+        friend: User
+    }
+"#;
+
+const DOCUMENT: &str = r#"
+    query Valid { user { ...UserFields } }
+
+    query Invalid { user { unknownField } }
+
+    query Cyclic { user { ...CyclicA } }
+
+    query UnusedVariable($unused: String) { foo }
+
+    fragment UserFields on User { name ...FriendFields }
+
+    fragment FriendFields on User { friend { name } }
+
+    fragment Unused on User { alsoUnknown }
+
+    fragment CyclicA on User { friend { ...CyclicB } }
+
+    fragment CyclicB on User { friend { ...CyclicA } }
+"#;
+
+fn validate_operation<'b>(
+    document: &str,
+    operation_name: Option<&'b str>,
+) -> Result<Vec<&'static str>, OperationResolutionError<'b>> {
+    let definition_document: DefinitionDocument = DefinitionDocument::parse(SCHEMA)
+        .result
+        .expect("Schema had parse errors");
+    let schema_definition: SchemaDefinition =
+        SchemaDefinition::try_from(&definition_document).expect("Schema had errors");
+    let executable_document = ExecutableDocument::parse(document)
+        .result
+        .expect("Document had parse errors");
+    let cache = Cache::new(&executable_document, &schema_definition);
+    BuiltinRulesValidator::validate_operation(
+        &executable_document,
+        &schema_definition,
+        operation_name,
+        &cache,
+    )
+    .map(|errors| errors.map(|error| error.code()).collect())
+}
+
+#[test]
+fn test_sibling_operations_and_unused_fragments_are_ignored() {
+    assert_eq!(
+        Vec::<&str>::new(),
+        validate_operation(DOCUMENT, Some("Valid")).unwrap()
+    );
+}
+
+#[test]
+fn test_errors_in_selected_operation_and_reachable_fragments() {
+    assert_eq!(
+        vec!["FIELD_NOT_DEFINED"],
+        validate_operation(DOCUMENT, Some("Invalid")).unwrap(),
+    );
+    assert_eq!(
+        vec!["FRAGMENT_SPREAD_CYCLE", "FRAGMENT_SPREAD_CYCLE"],
+        validate_operation(DOCUMENT, Some("Cyclic")).unwrap(),
+    );
+    assert_eq!(
+        vec!["VARIABLE_UNUSED"],
+        validate_operation(DOCUMENT, Some("UnusedVariable")).unwrap(),
+    );
+}
+
+#[test]
+fn test_operation_resolution_errors() {
+    assert!(matches!(
+        validate_operation(DOCUMENT, Some("Missing")),
+        Err(OperationResolutionError::NoOperationWithName { name: "Missing" })
+    ));
+    assert!(matches!(
+        validate_operation(DOCUMENT, None),
+        Err(OperationResolutionError::AnonymousNotEligible)
+    ));
+    assert!(matches!(
+        validate_operation("query Dup { foo } query Dup { user { name } }", Some("Dup")),
+        Err(OperationResolutionError::AmbiguousOperationName { name: "Dup" })
+    ));
+    assert_eq!(
+        Vec::<&str>::new(),
+        validate_operation("{ foo }", None).unwrap()
+    );
+}